@@ -1,54 +1,265 @@
-use infer::Type;
+use std::collections::HashMap;
 use std::io;
-use std::io::{BufReader, Cursor, Read};
+use std::io::{BufRead, BufReader, Read};
+use std::path::Path;
+use std::process::{Child, ChildStdout, Command, Stdio};
+use std::thread;
 
 use crate::input::CompressionDetected;
 use ion_rs::IonResult;
 
+/// User-registered overrides for the external commands this CLI spawns to decompress input,
+/// parsed from repeated `--preprocessor <codec>=<program>[,<arg>...]` flags. A value with no
+/// command after the `=` (e.g. `--preprocessor gz=`) disables that codec instead of overriding
+/// it, so its magic bytes/extension are no longer auto-decompressed.
+#[derive(Debug, Clone, Default)]
+pub struct Preprocessors {
+    overrides: HashMap<String, Option<(String, Vec<String>)>>,
+}
+
+impl Preprocessors {
+    /// Parses a `--preprocessor` flag's repeated values. Returns an error message (suitable for
+    /// a clap `value_parser`) if a value isn't shaped like `<codec>=<program>[,<arg>...]`.
+    pub fn parse<'a>(values: impl IntoIterator<Item = &'a str>) -> Result<Self, String> {
+        let mut overrides = HashMap::new();
+        for value in values {
+            let (codec, command) = value.split_once('=').ok_or_else(|| {
+                format!(
+                    "invalid --preprocessor value '{value}'; expected <codec>=<program>[,<arg>...] \
+                    (an empty <program> disables that codec)"
+                )
+            })?;
+            let command = if command.is_empty() {
+                None
+            } else {
+                let mut parts = command.split(',');
+                let program = parts.next().expect("split always yields at least one item");
+                Some((program.to_string(), parts.map(str::to_string).collect()))
+            };
+            overrides.insert(codec.to_string(), command);
+        }
+        Ok(Preprocessors { overrides })
+    }
+
+    /// Looks up the override (if any) registered for `codec`. `Some(None)` means the codec has
+    /// been disabled; `None` means there's no override and the built-in behavior applies.
+    fn get(&self, codec: &str) -> Option<&Option<(String, Vec<String>)>> {
+        self.overrides.get(codec)
+    }
+}
+
 /// Auto-detects a compressed byte stream and wraps the original reader
 /// into a reader that transparently decompresses.
 pub type AutoDecompressingReader = BufReader<Box<dyn Read>>;
 
+/// Magic byte prefixes for the compressed formats this CLI auto-detects, checked in this order
+/// against the head of the input stream. Sourced from each format's own spec rather than a
+/// third-party sniffing library, so the set of codecs we detect is exactly the set we can decode.
+const MAGIC_BYTES: &[(CompressionDetected, &[u8])] = &[
+    (CompressionDetected::Gzip, &[0x1F, 0x8B]),
+    (CompressionDetected::Zstd, &[0x28, 0xB5, 0x2F, 0xFD]),
+    (
+        CompressionDetected::Xz,
+        &[0xFD, 0x37, 0x7A, 0x58, 0x5A, 0x00],
+    ),
+    (CompressionDetected::Bzip2, &[0x42, 0x5A, 0x68]),
+    (CompressionDetected::Lz4, &[0x04, 0x22, 0x4D, 0x18]),
+    (CompressionDetected::Compress, &[0x1F, 0x9D]),
+    // Legacy `.lzma` has no fixed magic sequence -- its first byte is a properties byte that
+    // varies with the encoder's lc/lp/pb settings. 0x5D is what every common encoder emits with
+    // default settings, so it's used as a best-effort sniff; a non-default encoder falls back to
+    // the `.lzma` extension check below.
+    (CompressionDetected::Lzma, &[0x5D, 0x00, 0x00]),
+    // Raw Brotli streams have no magic bytes at all, so detection relies entirely on the `.br`
+    // extension (see `extension_of`).
+];
+
+/// Matches `prefix` (the as-yet-unconsumed head of the input stream) against `MAGIC_BYTES`,
+/// returning `CompressionDetected::None` if it looks like raw Ion or JSON instead.
+fn sniff_magic_bytes(prefix: &[u8]) -> CompressionDetected {
+    MAGIC_BYTES
+        .iter()
+        .find(|(_, magic)| prefix.starts_with(magic))
+        .map(|(detected, _)| *detected)
+        .unwrap_or(CompressionDetected::None)
+}
+
 pub fn decompress<R>(
-    mut reader: R,
-    header_len: usize,
+    name: &str,
+    reader: R,
+    preprocessors: &Preprocessors,
 ) -> IonResult<(CompressionDetected, AutoDecompressingReader)>
 where
-    R: Read + 'static,
+    R: Read + Send + 'static,
 {
-    // read header
-    let mut header_bytes = vec![0; header_len];
-    let nread = read_reliably(&mut reader, &mut header_bytes)?;
-    header_bytes.truncate(nread);
+    // Buffer the reader and peek at whatever's already sitting in its buffer without consuming
+    // it, so the decoder we pick below can still read the stream from the very first byte.
+    let mut buffered = BufReader::new(reader);
+    let detected = sniff_magic_bytes(buffered.fill_buf()?);
 
-    let detected_type = infer::get(&header_bytes);
-    let header = Cursor::new(header_bytes);
-    let stream = header.chain(reader);
+    // The magic bytes are a content sniff and work identically for STDIN and named files; the
+    // file extension is only consulted as a fallback, e.g. a truncated input shorter than its
+    // format's magic prefix.
+    let detected = if detected == CompressionDetected::None {
+        extension_of(name).unwrap_or(CompressionDetected::None)
+    } else {
+        detected
+    };
+
+    // A user-registered override takes precedence over the built-in handling below: `None` means
+    // the codec has been disabled (fall through to a plain passthrough reader), `Some` swaps in
+    // the user's own command in place of whatever we'd normally spawn.
+    if let Some(codec) = detected.codec_name() {
+        if let Some(overridden) = preprocessors.get(codec) {
+            return Ok(match overridden {
+                Some((program, args)) => {
+                    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+                    (
+                        detected,
+                        BufReader::new(spawn_external_decompressor(program, &args, buffered)?),
+                    )
+                }
+                None => (
+                    CompressionDetected::None,
+                    BufReader::new(Box::new(buffered)),
+                ),
+            });
+        }
+    }
 
     // detect compression type and wrap reader in a decompressor
-    match detected_type.as_ref().map(Type::extension) {
-        Some("gz") => {
-            // "rewind" to let the decompressor read magic bytes again
-            let zreader = Box::new(flate2::read::MultiGzDecoder::new(stream));
+    match detected {
+        CompressionDetected::Gzip => {
+            let zreader = Box::new(flate2::read::MultiGzDecoder::new(buffered));
             Ok((CompressionDetected::Gzip, BufReader::new(zreader)))
         }
-        Some("zst") => {
-            let zreader = Box::new(zstd::stream::read::Decoder::new(stream)?);
+        CompressionDetected::Zstd => {
+            let zreader = Box::new(zstd::stream::read::Decoder::new(buffered)?);
             Ok((CompressionDetected::Zstd, BufReader::new(zreader)))
         }
-        _ => Ok((CompressionDetected::None, BufReader::new(Box::new(stream)))),
+        CompressionDetected::Xz => Ok((
+            CompressionDetected::Xz,
+            BufReader::new(spawn_external_decompressor("xz", &["-dc"], buffered)?),
+        )),
+        CompressionDetected::Bzip2 => Ok((
+            CompressionDetected::Bzip2,
+            BufReader::new(spawn_external_decompressor("bzip2", &["-dc"], buffered)?),
+        )),
+        CompressionDetected::Lz4 => Ok((
+            CompressionDetected::Lz4,
+            BufReader::new(spawn_external_decompressor("lz4", &["-dc"], buffered)?),
+        )),
+        CompressionDetected::Brotli => Ok((
+            CompressionDetected::Brotli,
+            BufReader::new(spawn_external_decompressor(
+                "brotli",
+                &["-d", "-c"],
+                buffered,
+            )?),
+        )),
+        CompressionDetected::Lzma => Ok((
+            CompressionDetected::Lzma,
+            BufReader::new(spawn_external_decompressor(
+                "xz",
+                &["--format=lzma", "-dc"],
+                buffered,
+            )?),
+        )),
+        CompressionDetected::Compress => Ok((
+            CompressionDetected::Compress,
+            BufReader::new(spawn_external_decompressor(
+                "uncompress",
+                &["-c"],
+                buffered,
+            )?),
+        )),
+        CompressionDetected::None => Ok((
+            CompressionDetected::None,
+            BufReader::new(Box::new(buffered)),
+        )),
     }
 }
 
-/// Similar to [`Read::read()`], but loops in case of fragmented reads.
-pub fn read_reliably<R: Read>(reader: &mut R, buf: &mut [u8]) -> io::Result<usize> {
-    let mut nread = 0;
-    while nread < buf.len() {
-        match reader.read(&mut buf[nread..]) {
-            Ok(0) => break,
-            Ok(n) => nread += n,
-            Err(e) => return Err(e),
+/// Maps a file name's extension (`.gz`, `.zst`, `.xz`, `.bz2`, `.lz4`, `.br`, `.lzma`, `.Z`) to the codec it corresponds
+/// to, for use when the input stream's magic bytes didn't match any supported codec (e.g. a
+/// truncated file shorter than its format's magic prefix).
+fn extension_of(name: &str) -> Option<CompressionDetected> {
+    match Path::new(name).extension()?.to_str()? {
+        "gz" => Some(CompressionDetected::Gzip),
+        "zst" => Some(CompressionDetected::Zstd),
+        "xz" => Some(CompressionDetected::Xz),
+        "bz2" => Some(CompressionDetected::Bzip2),
+        "lz4" => Some(CompressionDetected::Lz4),
+        "br" => Some(CompressionDetected::Brotli),
+        "lzma" => Some(CompressionDetected::Lzma),
+        "Z" => Some(CompressionDetected::Compress),
+        _ => None,
+    }
+}
+
+/// Spawns an external decompressor command, feeding it `stream` on its STDIN and returning a
+/// `Read` over its STDOUT. STDERR is drained on a dedicated thread so a chatty decompressor can't
+/// fill its pipe buffer and deadlock the pipeline; anything it writes there is echoed to our own
+/// STDERR once the child exits.
+fn spawn_external_decompressor<R>(
+    program: &str,
+    args: &[&str],
+    mut stream: R,
+) -> io::Result<Box<dyn Read>>
+where
+    R: Read + Send + 'static,
+{
+    let mut child = Command::new(program)
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| {
+            io::Error::new(
+                e.kind(),
+                format!(
+                    "could not find a '{program}' executable on PATH to decompress this input: {e}"
+                ),
+            )
+        })?;
+
+    let mut stdin = child.stdin.take().expect("child stdin was piped");
+    thread::spawn(move || {
+        // The child may exit (and close its stdin) before we're done writing, e.g. if the input
+        // is malformed; its own error message reaches our STDERR via the drain thread below, so
+        // ignore write errors.
+        let _ = io::copy(&mut stream, &mut stdin);
+    });
+
+    let mut stderr = child.stderr.take().expect("child stderr was piped");
+    thread::spawn(move || {
+        let mut message = String::new();
+        let _ = stderr.read_to_string(&mut message);
+        if !message.trim().is_empty() {
+            eprint!("{message}");
         }
+    });
+
+    let stdout = child.stdout.take().expect("child stdout was piped");
+    Ok(Box::new(ChildStdoutReader { child, stdout }))
+}
+
+/// Wraps a decompressor child process's STDOUT so the child is reaped when the reader is dropped.
+/// The child's exit status is not checked.
+struct ChildStdoutReader {
+    child: Child,
+    stdout: ChildStdout,
+}
+
+impl Read for ChildStdoutReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.stdout.read(buf)
+    }
+}
+
+impl Drop for ChildStdoutReader {
+    fn drop(&mut self) {
+        let _ = self.child.wait();
     }
-    Ok(nread)
 }
@@ -1,6 +1,5 @@
-use crate::commands::{IonCliCommand, WithIonCliArgument, UNSTABLE_FLAG};
+use crate::commands::{check_unstable_feature_opt_in, IonCliCommand, WithIonCliArgument};
 use clap::{ArgMatches, Command as ClapCommand};
-use std::process;
 
 /// A trait that handles the implementation of [IonCliCommand] for command namespaces.
 pub trait IonCliNamespace {
@@ -61,22 +60,7 @@ impl<T: IonCliNamespace> IonCliCommand for T {
             .unwrap()
             .as_ref();
 
-        match (subcommand.is_stable(), args.get_flag(UNSTABLE_FLAG)) {
-            // Warn if using an unnecessary `-X`
-            (true, true) => eprintln!(
-                "'{}' is stable and does not require opt-in",
-                subcommand_name
-            ),
-            // Error if missing a required `-X`
-            (false, false) => {
-                eprintln!(
-                    "'{}' is unstable and requires explicit opt-in",
-                    subcommand_name
-                );
-                process::exit(1)
-            }
-            _ => {}
-        }
+        check_unstable_feature_opt_in(subcommand_name, subcommand.unstable_features(), args);
 
         command_path.push(subcommand_name.to_owned());
         subcommand.run(command_path, subcommand_args)
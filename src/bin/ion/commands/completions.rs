@@ -0,0 +1,56 @@
+use crate::commands::IonCliCommand;
+use crate::RootCommand;
+use anyhow::{Context, Result};
+use clap::{value_parser, Arg, ArgMatches, Command};
+use clap_complete::{generate, generate_to, Shell};
+use std::io;
+
+/// Generates shell completion scripts by walking the full `ion` command/namespace tree, so every
+/// subcommand each namespace exposes (`ion schema validate`, `ion to json`, ...) completes too.
+pub struct CompletionsCommand;
+
+impl IonCliCommand for CompletionsCommand {
+    fn name(&self) -> &'static str {
+        "completions"
+    }
+
+    fn about(&self) -> &'static str {
+        "Generates a shell completion script for the entire ion-cli command tree."
+    }
+
+    fn is_porcelain(&self) -> bool {
+        true
+    }
+
+    fn configure_args(&self, command: Command) -> Command {
+        command
+            .arg(
+                Arg::new("shell")
+                    .long("shell")
+                    .required(true)
+                    .value_parser(value_parser!(Shell))
+                    .help("The shell to generate a completion script for"),
+            )
+            .arg(
+                Arg::new("output-dir")
+                    .long("output-dir")
+                    .help("Directory to write the completion script into [default: STDOUT]"),
+            )
+    }
+
+    fn run(&self, _command_path: &mut Vec<String>, args: &ArgMatches) -> Result<()> {
+        let shell = *args
+            .get_one::<Shell>("shell")
+            .expect("clap ensures that --shell is present");
+        let mut root = RootCommand.clap_command();
+
+        match args.get_one::<String>("output-dir") {
+            Some(dir) => {
+                generate_to(shell, &mut root, "ion", dir)
+                    .with_context(|| format!("could not write completions to '{dir}'"))?;
+            }
+            None => generate(shell, &mut root, "ion", &mut io::stdout()),
+        }
+        Ok(())
+    }
+}
@@ -15,12 +15,12 @@ impl IonCliCommand for CountCommand {
         "Prints the number of top-level values found in the input stream."
     }
 
-    fn is_stable(&self) -> bool {
-        false
+    fn unstable_features(&self) -> &'static [&'static str] {
+        &["count"]
     }
 
     fn configure_args(&self, command: Command) -> Command {
-        command.with_input()
+        command.with_input().with_limit()
     }
 
     fn run(&self, _command_path: &mut Vec<String>, args: &ArgMatches) -> Result<()> {
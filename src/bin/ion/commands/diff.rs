@@ -0,0 +1,322 @@
+use crate::commands::IonCliCommand;
+use anyhow::{Context, Result};
+use clap::{Arg, ArgMatches, Command};
+use ion_rs::{AnyEncoding, Element, ElementReader, Reader, Struct, Value};
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::process;
+
+pub struct DiffCommand;
+
+impl IonCliCommand for DiffCommand {
+    fn name(&self) -> &'static str {
+        "diff"
+    }
+
+    fn about(&self) -> &'static str {
+        "Compares two Ion streams structurally (by value, not by byte layout) and reports any \
+         differences by value path."
+    }
+
+    fn unstable_features(&self) -> &'static [&'static str] {
+        &["diff"]
+    }
+
+    fn configure_args(&self, command: Command) -> Command {
+        command
+            .arg(
+                Arg::new("first")
+                    .index(1)
+                    .required(true)
+                    .help("First input file to compare"),
+            )
+            .arg(
+                Arg::new("second")
+                    .index(2)
+                    .required(true)
+                    .help("Second input file to compare"),
+            )
+    }
+
+    fn run(&self, _command_path: &mut Vec<String>, args: &ArgMatches) -> Result<()> {
+        let first_name = args.get_one::<String>("first").unwrap();
+        let second_name = args.get_one::<String>("second").unwrap();
+
+        let first = read_all_elements(first_name)?;
+        let second = read_all_elements(second_name)?;
+
+        let edits = diff_top_level(&first, &second);
+        for edit in &edits {
+            println!("{edit}");
+        }
+
+        if edits.is_empty() {
+            Ok(())
+        } else {
+            // Matches the convention of a non-zero exit for a non-empty report, so `ion diff` can
+            // gate CI the same way `diff(1)` does.
+            process::exit(1);
+        }
+    }
+}
+
+fn read_all_elements(file_name: &str) -> Result<Vec<Element>> {
+    let bytes =
+        std::fs::read(file_name).with_context(|| format!("could not read file '{file_name}'"))?;
+    let mut reader = Reader::new(AnyEncoding, bytes.as_slice())
+        .with_context(|| format!("'{file_name}' was not valid Ion"))?;
+    Ok(reader.read_all_elements()?)
+}
+
+/// A single structural difference found between the two streams.
+struct Edit {
+    path: String,
+    op: EditOp,
+    old: Option<String>,
+    new: Option<String>,
+}
+
+enum EditOp {
+    Added,
+    Removed,
+    Changed,
+}
+
+impl Edit {
+    fn added(path: &[PathSegment], new: &Element) -> Self {
+        Edit {
+            path: render_path(path),
+            op: EditOp::Added,
+            old: None,
+            new: Some(new.to_string()),
+        }
+    }
+
+    fn removed(path: &[PathSegment], old: &Element) -> Self {
+        Edit {
+            path: render_path(path),
+            op: EditOp::Removed,
+            old: Some(old.to_string()),
+            new: None,
+        }
+    }
+
+    fn changed(path: &[PathSegment], old: &Element, new: &Element) -> Self {
+        Edit {
+            path: render_path(path),
+            op: EditOp::Changed,
+            old: Some(old.to_string()),
+            new: Some(new.to_string()),
+        }
+    }
+}
+
+impl Display for EditOp {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            EditOp::Added => "added",
+            EditOp::Removed => "removed",
+            EditOp::Changed => "changed",
+        })
+    }
+}
+
+impl Display for Edit {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match (&self.old, &self.new) {
+            (Some(old), Some(new)) => write!(f, "{} {}: {old} -> {new}", self.op, self.path),
+            (Some(old), None) => write!(f, "{} {}: {old}", self.op, self.path),
+            (None, Some(new)) => write!(f, "{} {}: {new}", self.op, self.path),
+            (None, None) => write!(f, "{} {}", self.op, self.path),
+        }
+    }
+}
+
+/// A single step (struct field or sequence index) of a value path like `.orders[2].total`.
+enum PathSegment {
+    Field(String),
+    Index(usize),
+}
+
+fn render_path(path: &[PathSegment]) -> String {
+    let mut rendered = String::new();
+    for segment in path {
+        match segment {
+            PathSegment::Field(name) => {
+                rendered.push('.');
+                rendered.push_str(name);
+            }
+            PathSegment::Index(index) => {
+                rendered.push('[');
+                rendered.push_str(&index.to_string());
+                rendered.push(']');
+            }
+        }
+    }
+    if rendered.is_empty() {
+        ".".to_owned()
+    } else {
+        rendered
+    }
+}
+
+/// Diffs the top-level values of the two streams. A file holding exactly one top-level value on
+/// each side (the common case) is compared directly, so its path starts at the document root
+/// (e.g. `.orders[2].total`) rather than behind a `[0]` prefix; a stream of several top-level
+/// values is instead treated as a sequence and aligned the same way a `list`'s children are.
+fn diff_top_level(old: &[Element], new: &[Element]) -> Vec<Edit> {
+    let mut edits = Vec::new();
+    let mut path = Vec::new();
+    if old.len() == 1 && new.len() == 1 {
+        diff_elements(&mut path, &old[0], &new[0], &mut edits);
+    } else {
+        let old_refs: Vec<&Element> = old.iter().collect();
+        let new_refs: Vec<&Element> = new.iter().collect();
+        diff_sequences(&mut path, &old_refs, &new_refs, &mut edits);
+    }
+    edits
+}
+
+/// Compares two values at `path`, recursing into structs and sequences so a single changed leaf
+/// produces one precise edit instead of a whole-container replacement.
+fn diff_elements(path: &mut Vec<PathSegment>, old: &Element, new: &Element, edits: &mut Vec<Edit>) {
+    if old.ion_type() != new.ion_type() || old.annotations() != new.annotations() {
+        edits.push(Edit::changed(path, old, new));
+        return;
+    }
+
+    match (old.value(), new.value()) {
+        (Value::Struct(a), Value::Struct(b)) => diff_structs(path, a, b, edits),
+        (Value::List(a), Value::List(b)) => {
+            let old_refs: Vec<&Element> = a.iter().collect();
+            let new_refs: Vec<&Element> = b.iter().collect();
+            diff_sequences(path, &old_refs, &new_refs, edits);
+        }
+        (Value::SExp(a), Value::SExp(b)) => {
+            let old_refs: Vec<&Element> = a.iter().collect();
+            let new_refs: Vec<&Element> = b.iter().collect();
+            diff_sequences(path, &old_refs, &new_refs, edits);
+        }
+        _ => {
+            if old != new {
+                edits.push(Edit::changed(path, old, new));
+            }
+        }
+    }
+}
+
+/// Groups each struct's fields by name and recurses per name, reporting a field present on only
+/// one side as `added`/`removed`. A field repeated under the same name on both sides (Ion structs
+/// allow duplicate field names) falls back to the same sequence alignment used for lists.
+fn diff_structs(path: &mut Vec<PathSegment>, old: &Struct, new: &Struct, edits: &mut Vec<Edit>) {
+    let mut by_name: BTreeMap<String, (Vec<&Element>, Vec<&Element>)> = BTreeMap::new();
+    for (name, value) in old.fields() {
+        // A field name without known text (e.g. an unresolved symbol ID) collapses to "?"; see
+        // the similar gap noted for jq's `annotations` helper.
+        by_name
+            .entry(name.text().unwrap_or("?").to_owned())
+            .or_default()
+            .0
+            .push(value);
+    }
+    for (name, value) in new.fields() {
+        by_name
+            .entry(name.text().unwrap_or("?").to_owned())
+            .or_default()
+            .1
+            .push(value);
+    }
+
+    for (name, (olds, news)) in by_name {
+        path.push(PathSegment::Field(name));
+        match (olds.as_slice(), news.as_slice()) {
+            ([], [new_value, ..]) => edits.push(Edit::added(path, new_value)),
+            ([old_value, ..], []) => edits.push(Edit::removed(path, old_value)),
+            ([old_value], [new_value]) => diff_elements(path, old_value, new_value, edits),
+            _ => diff_sequences(path, &olds, &news, edits),
+        }
+        path.pop();
+    }
+}
+
+/// Aligns two sequences of values by their longest common subsequence (under Ion value equality),
+/// then reports the leftover spans as `added`/`removed`/`changed` edits keyed by their position in
+/// `new`. A deleted value immediately followed by an inserted value at the same position is
+/// treated as `changed` and diffed recursively, rather than as a separate remove and add.
+fn diff_sequences(
+    path: &mut Vec<PathSegment>,
+    old: &[&Element],
+    new: &[&Element],
+    edits: &mut Vec<Edit>,
+) {
+    let table = lcs_table(old, new);
+    let (mut i, mut j) = (0usize, 0usize);
+    let mut index = 0usize;
+
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            i += 1;
+            j += 1;
+            index += 1;
+            continue;
+        }
+
+        // Gather the contiguous run of deletes/inserts starting here and pair them up as
+        // replacements, in LCS-traversal order.
+        let (mut deletes, mut inserts) = (Vec::new(), Vec::new());
+        while i < old.len() && j < new.len() && old[i] != new[j] {
+            if table[i + 1][j] >= table[i][j + 1] {
+                deletes.push(old[i]);
+                i += 1;
+            } else {
+                inserts.push(new[j]);
+                j += 1;
+            }
+        }
+        while i < old.len() && j == new.len() {
+            deletes.push(old[i]);
+            i += 1;
+        }
+        while j < new.len() && i == old.len() {
+            inserts.push(new[j]);
+            j += 1;
+        }
+
+        let pair_count = deletes.len().min(inserts.len());
+        for k in 0..pair_count {
+            path.push(PathSegment::Index(index));
+            diff_elements(path, deletes[k], inserts[k], edits);
+            path.pop();
+            index += 1;
+        }
+        for old_value in &deletes[pair_count..] {
+            path.push(PathSegment::Index(index));
+            edits.push(Edit::removed(path, old_value));
+            path.pop();
+        }
+        for new_value in &inserts[pair_count..] {
+            path.push(PathSegment::Index(index));
+            edits.push(Edit::added(path, new_value));
+            path.pop();
+            index += 1;
+        }
+    }
+}
+
+/// Classic `O(n*m)` LCS length table (`table[i][j]` = length of the longest common subsequence of
+/// `old[i..]` and `new[j..]`), used to decide which side to advance while backtracking in
+/// [`diff_sequences`].
+fn lcs_table(old: &[&Element], new: &[&Element]) -> Vec<Vec<usize>> {
+    let (n, m) = (old.len(), new.len());
+    let mut table = vec![vec![0usize; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            table[i][j] = if old[i] == new[j] {
+                table[i + 1][j + 1] + 1
+            } else {
+                table[i + 1][j].max(table[i][j + 1])
+            };
+        }
+    }
+    table
+}
@@ -1,8 +1,10 @@
 use anyhow::Result;
-use clap::{arg, ArgMatches, Command};
-use ion_rs::{AnyEncoding, Reader};
+use clap::{arg, Arg, ArgMatches, Command};
+use ion_rs::{AnyEncoding, Element, Reader};
 
+use crate::commands::promotion::{convert_decimals, KeyConvention};
 use crate::commands::timestamp_conversion::convert_timestamps;
+use crate::commands::typed_json::detypify;
 use crate::commands::{CommandIo, IonCliCommand, WithIonCliArgument};
 use crate::transcribe::write_all_as;
 
@@ -17,8 +19,8 @@ impl IonCliCommand for FromJsonCommand {
         "Converts data from JSON to Ion."
     }
 
-    fn is_stable(&self) -> bool {
-        false // TODO: Should this be true?
+    fn unstable_features(&self) -> &'static [&'static str] {
+        &["from-json"] // TODO: Should this be stable?
     }
 
     fn is_porcelain(&self) -> bool {
@@ -28,6 +30,30 @@ impl IonCliCommand for FromJsonCommand {
     fn configure_args(&self, command: Command) -> Command {
         command
             .arg(arg!(-t --"detect-timestamps" "Preserve Ion timestamps when going from Ion to JSON to Ion"))
+            .arg(arg!(--"promote" "Infer richer Ion types from JSON strings: ISO-8601/RFC-3339 timestamps, \
+                exact decimal literals, and symbol/blob values marked by a key prefix"))
+            .arg(
+                Arg::new("symbol-key-prefix")
+                    .long("symbol-key-prefix")
+                    .default_value("$")
+                    .requires("promote")
+                    .help(
+                        "With --promote, a struct field name prefix (stripped from the output \
+                         field name) marking that field's value to be emitted as an Ion symbol \
+                         instead of a string",
+                    ),
+            )
+            .arg(
+                Arg::new("blob-key-prefix")
+                    .long("blob-key-prefix")
+                    .default_value("$$")
+                    .requires("promote")
+                    .help(
+                        "With --promote, a struct field name prefix (stripped from the output \
+                         field name) marking that field's value to be base64-decoded and emitted \
+                         as an Ion blob instead of a string",
+                    ),
+            )
             .with_input()
             .with_output()
             .with_format()
@@ -37,10 +63,35 @@ impl IonCliCommand for FromJsonCommand {
     fn run(&self, _command_path: &mut Vec<String>, args: &ArgMatches) -> Result<()> {
         // Because JSON data is valid Ion, the `cat` command may be reused for converting JSON.
         let detect_timestamps = args.get_flag("detect-timestamps");
+        let promote = args.get_flag("promote");
+        let key_convention = promote.then(|| KeyConvention {
+            symbol_prefix: args.get_one::<String>("symbol-key-prefix").unwrap().clone(),
+            blob_prefix: args.get_one::<String>("blob-key-prefix").unwrap().clone(),
+        });
 
         CommandIo::new(args)?.for_each_input(|output, input| {
             let mut reader = Reader::new(AnyEncoding, input.into_source())?;
-            let mapper = detect_timestamps.then_some(convert_timestamps);
+            let mapper = |element: Element| -> Result<Element> {
+                // Reverses `to json --mode typed`'s `$ionType`-tagged wrapping, if present, before
+                // any of the opt-in heuristics below get a chance to look at the (now-correctly-
+                // typed) result.
+                let element = detypify(element)?;
+                let element = if detect_timestamps || promote {
+                    convert_timestamps(element)?
+                } else {
+                    element
+                };
+                let element = if promote {
+                    convert_decimals(element)?
+                } else {
+                    element
+                };
+                let element = match &key_convention {
+                    Some(key_convention) => key_convention.promote(element),
+                    None => element,
+                };
+                Ok(element)
+            };
             write_all_as(
                 &mut reader,
                 output,
@@ -0,0 +1,587 @@
+use crate::commands::generate::config::CodeGenConfig;
+use crate::commands::generate::generator::CodeGenerator;
+use crate::commands::generate::model::{DataModelIrDocument, NamespaceNode};
+use crate::commands::generate::namer::Case;
+use crate::commands::generate::result::CodeGenDiagnostic;
+use crate::commands::generate::utils::{
+    DigestAlgorithm, Format, JavaLanguage, KotlinLanguage, Language, PythonLanguage, RustLanguage,
+    TypeScriptLanguage,
+};
+use crate::commands::IonCliCommand;
+use anyhow::{bail, Result};
+use clap::{Arg, ArgAction, ArgMatches, Command, ValueHint};
+use colored::Colorize;
+use ion_schema::authority::{DocumentAuthority, FileSystemDocumentAuthority};
+use ion_schema::system::SchemaSystem;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub struct CodeCommand;
+
+impl IonCliCommand for CodeCommand {
+    fn name(&self) -> &'static str {
+        "code"
+    }
+
+    fn about(&self) -> &'static str {
+        "Generates code using given schema file."
+    }
+
+    fn unstable_features(&self) -> &'static [&'static str] {
+        &["generate"]
+    }
+
+    fn is_porcelain(&self) -> bool {
+        false
+    }
+
+    fn configure_args(&self, command: Command) -> Command {
+        command
+            .arg(
+                Arg::new("output")
+                    .long("output")
+                    .short('o')
+                    .help("Output directory [default: current directory]"),
+            )
+            // `--namespace` is required when Java or Kotlin language is specified for code
+            // generation, since both root generated types under a package.
+            .arg(
+                Arg::new("namespace")
+                    .long("namespace")
+                    .short('n')
+                    .required_if_eq_any([("language", "java"), ("language", "kotlin")])
+                    .help("Provide namespace for generated Java/Kotlin code (e.g. `org.example`)"),
+            )
+            .arg(
+                Arg::new("language")
+                    .long("language")
+                    .short('l')
+                    .required(true)
+                    .value_parser(["java", "rust", "python", "typescript", "kotlin", "json"])
+                    .help(
+                        "Programming language for the generated code. 'json' is a pseudo-target \
+                         that resolves the schema and prints the data model IR as JSON instead \
+                         of (or in addition to, with --emit-ir) writing source files",
+                    ),
+            )
+            .arg(
+                Arg::new("authority")
+                    .long("authority")
+                    .short('A')
+                    .required(true)
+                    .action(ArgAction::Append)
+                    .value_name("directory")
+                    .value_hint(ValueHint::DirPath)
+                    .help("The root(s) of the file system authority(s)"),
+            )
+            .arg(
+                Arg::new("emit-ir")
+                    .long("emit-ir")
+                    .value_name("file")
+                    .value_hint(ValueHint::FilePath)
+                    .help("Also write the resolved data model as a versioned JSON IR document to this file"),
+            )
+            .arg(
+                Arg::new("format")
+                    .long("format")
+                    .value_parser(["pretty", "compact", "binary"])
+                    .default_value("pretty")
+                    .help("The Ion encoding generated types' read/write methods should target"),
+            )
+            .arg(
+                Arg::new("ion-hash")
+                    .long("ion-hash")
+                    .value_parser(["sha256", "sha1", "sha512"])
+                    .help("Also generate an Ion Hash digest method for each type, using the given digest algorithm"),
+            )
+            .arg(
+                Arg::new("with-validation")
+                    .long("with-validation")
+                    .num_args(0)
+                    .help(
+                        "Give each generated type a constructor/reader path that checks incoming \
+                         Ion against the originating ISL type's constraints (min/max occurrences, \
+                         type refinements, valid_values, ranges) before materializing the object, \
+                         returning a validation error instead of silently accepting malformed data",
+                    ),
+            )
+            .arg(
+                Arg::new("config")
+                    .long("config")
+                    .value_name("file")
+                    .value_hint(ValueHint::FilePath)
+                    .help(
+                        "Ion file overriding generated type/field names and adding extra \
+                         derives/annotations to generated types",
+                    ),
+            )
+            .arg(
+                Arg::new("templates")
+                    .long("templates")
+                    .value_name("directory")
+                    .value_hint(ValueHint::DirPath)
+                    .help(
+                        "Directory of `*.templ` files overriding the built-in templates of the \
+                         same name (e.g. a `struct.templ` here replaces the default one)",
+                    ),
+            )
+            .arg(
+                Arg::new("naming")
+                    .long("naming")
+                    .value_parser([
+                        "lowercase",
+                        "UPPERCASE",
+                        "PascalCase",
+                        "camelCase",
+                        "snake_case",
+                        "SCREAMING_SNAKE_CASE",
+                    ])
+                    .help(
+                        "Case convention applied to generated type and field names, overriding \
+                         the target language's own default (types otherwise default to \
+                         PascalCase, fields to the target language's default). A `--config` \
+                         document's own `namer.type_case`/`namer.field_case` still wins over this \
+                         for whichever category it sets explicitly.",
+                    ),
+            )
+            .arg(
+                Arg::new("sort")
+                    .long("sort")
+                    .num_args(0)
+                    .help(
+                        "Stable-sort each type's nested types by name in the `--emit-ir` output, \
+                         so regenerating from an unchanged schema produces a minimal diff",
+                    ),
+            )
+            .arg(
+                Arg::new("dedup-nested")
+                    .long("dedup-nested")
+                    .num_args(0)
+                    .help(
+                        "Hoist structurally identical nested types that appear under more than \
+                         one parent into a single shared top-level type in the `--emit-ir` output",
+                    ),
+            )
+            .arg(
+                Arg::new("break-cycles")
+                    .long("break-cycles")
+                    .num_args(0)
+                    .help(
+                        "Box fields that form a reference cycle (directly or transitively) in \
+                         the `--emit-ir` output, so a recursive type has finite size",
+                    ),
+            )
+            .arg(
+                Arg::new("emit-build-file")
+                    .long("emit-build-file")
+                    .num_args(0)
+                    .help(
+                        "Also write a build manifest (Cargo.toml + lib.rs for Rust, build.gradle \
+                         for Java) next to the generated code so it compiles standalone",
+                    ),
+            )
+    }
+
+    fn run(&self, _command_path: &mut Vec<String>, args: &ArgMatches) -> Result<()> {
+        // Extract programming language for code generation
+        let language: &str = args.get_one::<String>("language").unwrap().as_str();
+
+        // Extract namespace for code generation
+        let namespace = args.get_one::<String>("namespace");
+
+        // Extract output path information where the generated code will be saved
+        // Create a module `ion_data_model` for storing all the generated code in the output directory
+        let binding = match args.get_one::<String>("output") {
+            Some(output_path) => PathBuf::from(output_path),
+            None => PathBuf::from("./"),
+        };
+
+        let output = binding.as_path();
+
+        // Extract the user provided document authorities/ directories
+        let authorities: Vec<&String> = args.get_many("authority").unwrap().collect();
+
+        // Set up document authorities vector
+        let mut document_authorities: Vec<Box<dyn DocumentAuthority>> = vec![];
+        args.get_many::<String>("authority")
+            .unwrap_or_default()
+            .map(Path::new)
+            .map(FileSystemDocumentAuthority::new)
+            .for_each(|a| document_authorities.push(Box::new(a)));
+
+        // Create a new schema system from given document authorities
+        let mut schema_system = SchemaSystem::new(document_authorities);
+
+        // Generate directories in the output path if the path doesn't exist
+        if !output.exists() {
+            fs::create_dir_all(output).unwrap();
+        }
+
+        println!("Started generating code...");
+
+        // Path to write the resolved data model out to as a versioned JSON IR document, if requested
+        let emit_ir_path = args.get_one::<String>("emit-ir");
+
+        // The Ion encoding generated types' `read`/`write` methods should target
+        let format = match args.get_one::<String>("format").map(String::as_str) {
+            Some("compact") => Format::CompactText,
+            Some("binary") => Format::Binary,
+            _ => Format::PrettyText,
+        };
+
+        // The digest algorithm to use for the opt-in Ion Hash method, if the user requested one
+        let ion_hash_algorithm = match args.get_one::<String>("ion-hash").map(String::as_str) {
+            Some("sha1") => Some(DigestAlgorithm::Sha1),
+            Some("sha512") => Some(DigestAlgorithm::Sha512),
+            Some("sha256") => Some(DigestAlgorithm::Sha256),
+            _ => None,
+        };
+
+        // Whether generated types should check incoming Ion against the originating ISL type's
+        // constraints before materializing the object; see `CodeGenerator::with_validation`.
+        let with_validation = args.get_flag("with-validation");
+
+        // Directory of user-supplied `*.templ` files overriding the built-in default templates
+        let templates_dir = args.get_one::<String>("templates");
+
+        // User-supplied overrides for generated names, derives, and annotations, if provided
+        let mut config = args
+            .get_one::<String>("config")
+            .map(CodeGenConfig::from_file)
+            .transpose()?;
+
+        // `--naming` fills in whichever of `type_case`/`field_case` a loaded `--config` document
+        // (or the lack of one) didn't already set explicitly, rather than overriding it.
+        if let Some(naming) = args.get_one::<String>("naming") {
+            let case = Case::parse_cli_name(naming).expect("validated by clap's value_parser");
+            let namer = config
+                .get_or_insert_with(CodeGenConfig::default)
+                .namer_mut();
+            namer.type_case.get_or_insert(case);
+            namer.field_case.get_or_insert(case);
+        }
+
+        // Deterministic post-processing passes applied to the `--emit-ir` output
+        let sort = args.get_flag("sort");
+        let dedup_nested = args.get_flag("dedup-nested");
+        let break_cycles = args.get_flag("break-cycles");
+
+        // Whether to also scaffold a build manifest next to the generated code
+        let emit_build_file = args.get_flag("emit-build-file");
+
+        // generate code based on schema and programming language
+        match language {
+            "java" => {
+                Self::print_java_code_gen_warnings();
+                let mut code_generator = CodeGenerator::<JavaLanguage>::new(output, namespace.unwrap().split('.').map(|s| NamespaceNode::Package(s.to_string())).collect()).with_format(format);
+                if let Some(dir) = templates_dir {
+                    code_generator = code_generator.with_templates(dir)?;
+                }
+                if let Some(algorithm) = ion_hash_algorithm {
+                    code_generator = code_generator.with_ion_hash_algorithm(algorithm);
+                }
+                code_generator = code_generator.with_validation(with_validation);
+                if let Some(config) = config.clone() {
+                    code_generator = code_generator.with_config(config);
+                }
+                Self::print_code_gen_diagnostics(&code_generator.generate_code_for_authorities(&authorities, &mut schema_system)?);
+                if let Some(ir_path) = emit_ir_path {
+                    Self::write_data_model_ir::<JavaLanguage>(ir_path, code_generator.data_model_ir_document(), sort, dedup_nested, break_cycles)?;
+                }
+                if emit_build_file {
+                    Self::write_java_build_manifest(output, namespace.unwrap())?;
+                }
+            },
+            "rust" => {
+                Self::print_rust_code_gen_warnings();
+                let mut code_generator = CodeGenerator::<RustLanguage>::new(output).with_format(format);
+                if let Some(dir) = templates_dir {
+                    code_generator = code_generator.with_templates(dir)?;
+                }
+                if let Some(algorithm) = ion_hash_algorithm {
+                    code_generator = code_generator.with_ion_hash_algorithm(algorithm);
+                }
+                code_generator = code_generator.with_validation(with_validation);
+                if let Some(config) = config.clone() {
+                    code_generator = code_generator.with_config(config);
+                }
+                Self::print_code_gen_diagnostics(&code_generator.generate_code_for_authorities(&authorities, &mut schema_system)?);
+                if let Some(ir_path) = emit_ir_path {
+                    Self::write_data_model_ir::<RustLanguage>(ir_path, code_generator.data_model_ir_document(), sort, dedup_nested, break_cycles)?;
+                }
+                if emit_build_file {
+                    Self::write_rust_build_manifest(output)?;
+                }
+            }
+            "python" => {
+                Self::print_python_code_gen_warnings();
+                let mut code_generator = CodeGenerator::<PythonLanguage>::new(output).with_format(format);
+                if let Some(dir) = templates_dir {
+                    code_generator = code_generator.with_templates(dir)?;
+                }
+                if let Some(algorithm) = ion_hash_algorithm {
+                    code_generator = code_generator.with_ion_hash_algorithm(algorithm);
+                }
+                code_generator = code_generator.with_validation(with_validation);
+                if let Some(config) = config.clone() {
+                    code_generator = code_generator.with_config(config);
+                }
+                Self::print_code_gen_diagnostics(&code_generator.generate_code_for_authorities(&authorities, &mut schema_system)?);
+                if let Some(ir_path) = emit_ir_path {
+                    Self::write_data_model_ir::<PythonLanguage>(ir_path, code_generator.data_model_ir_document(), sort, dedup_nested, break_cycles)?;
+                }
+                if emit_build_file {
+                    println!("{}", "WARNING: --emit-build-file is only supported for 'rust' and 'java'; no build manifest was written for 'python'.".yellow().bold());
+                }
+            }
+            "typescript" => {
+                Self::print_typescript_code_gen_warnings();
+                let mut code_generator = CodeGenerator::<TypeScriptLanguage>::new(output).with_format(format);
+                if let Some(dir) = templates_dir {
+                    code_generator = code_generator.with_templates(dir)?;
+                }
+                if let Some(algorithm) = ion_hash_algorithm {
+                    code_generator = code_generator.with_ion_hash_algorithm(algorithm);
+                }
+                code_generator = code_generator.with_validation(with_validation);
+                if let Some(config) = config.clone() {
+                    code_generator = code_generator.with_config(config);
+                }
+                Self::print_code_gen_diagnostics(&code_generator.generate_code_for_authorities(&authorities, &mut schema_system)?);
+                if let Some(ir_path) = emit_ir_path {
+                    Self::write_data_model_ir::<TypeScriptLanguage>(ir_path, code_generator.data_model_ir_document(), sort, dedup_nested, break_cycles)?;
+                }
+                if emit_build_file {
+                    println!("{}", "WARNING: --emit-build-file is only supported for 'rust' and 'java'; no build manifest was written for 'typescript'.".yellow().bold());
+                }
+            }
+            "kotlin" => {
+                Self::print_kotlin_code_gen_warnings();
+                let mut code_generator = CodeGenerator::<KotlinLanguage>::new(output, namespace.unwrap().split('.').map(|s| NamespaceNode::Package(s.to_string())).collect()).with_format(format);
+                if let Some(dir) = templates_dir {
+                    code_generator = code_generator.with_templates(dir)?;
+                }
+                if let Some(algorithm) = ion_hash_algorithm {
+                    code_generator = code_generator.with_ion_hash_algorithm(algorithm);
+                }
+                code_generator = code_generator.with_validation(with_validation);
+                if let Some(config) = config.clone() {
+                    code_generator = code_generator.with_config(config);
+                }
+                Self::print_code_gen_diagnostics(&code_generator.generate_code_for_authorities(&authorities, &mut schema_system)?);
+                if let Some(ir_path) = emit_ir_path {
+                    Self::write_data_model_ir::<KotlinLanguage>(ir_path, code_generator.data_model_ir_document(), sort, dedup_nested, break_cycles)?;
+                }
+                if emit_build_file {
+                    println!("{}", "WARNING: --emit-build-file is only supported for 'rust' and 'java'; no build manifest was written for 'kotlin'.".yellow().bold());
+                }
+            }
+            "json" => {
+                // `json` isn't a real rendering target: it resolves the schema the same way
+                // `rust` does (for its `target_type` mapping), but reports the resolved data
+                // model tree itself rather than asking anyone to read generated source.
+                // `without_rendering` skips Tera rendering, per-type file writes, and plugins, so
+                // this is a true dry run -- other than the single shared `ion_generated_code.rs`
+                // boilerplate file `CodeGenerator::new` writes up front before any builder method
+                // gets a chance to run; templates aren't loaded either, since nothing here renders.
+                let mut code_generator = CodeGenerator::<RustLanguage>::new(output)
+                    .with_format(format)
+                    .without_rendering();
+                if let Some(config) = config.clone() {
+                    code_generator = code_generator.with_config(config);
+                }
+                Self::print_code_gen_diagnostics(&code_generator.generate_code_for_authorities(&authorities, &mut schema_system)?);
+                let mut ir_document = code_generator.data_model_ir_document();
+                if dedup_nested {
+                    ir_document.dedup_nested();
+                }
+                if break_cycles {
+                    ir_document.break_cycles::<RustLanguage>();
+                }
+                if sort {
+                    ir_document.sort();
+                }
+                match emit_ir_path {
+                    Some(ir_path) => Self::write_data_model_ir::<RustLanguage>(ir_path, ir_document, false, false, false)?,
+                    None => println!("{}", serde_json::to_string_pretty(&ir_document)?),
+                }
+            }
+            _ => bail!(
+                "Programming language '{}' is not yet supported. Currently supported targets: 'java', 'rust', 'python', 'typescript', 'kotlin', 'json'",
+                language
+            )
+        }
+
+        println!("Code generation complete successfully!");
+        println!("All the schema files in authority(s) are generated into a flattened namespace, path to generated code: {}", output.display());
+        Ok(())
+    }
+}
+
+impl CodeCommand {
+    // Prints every unsupported-constraint diagnostic collected while resolving the authorities'
+    // schemas, so a user sees every type code generation had to skip in one pass instead of just
+    // the first one. No-op when `diagnostics` is empty.
+    fn print_code_gen_diagnostics(diagnostics: &[CodeGenDiagnostic]) {
+        if diagnostics.is_empty() {
+            return;
+        }
+        println!(
+            "{}",
+            format!(
+                "WARNING: {} type(s) use constraints that are not yet supported by code generation and were skipped:",
+                diagnostics.len()
+            )
+            .yellow()
+            .bold()
+        );
+        for diagnostic in diagnostics {
+            println!("{}", format!("  {diagnostic}").yellow());
+        }
+    }
+
+    // Writes the given data model IR document out to `path` as pretty-printed JSON, applying the
+    // `--sort`/`--dedup-nested`/`--break-cycles` post-processing passes first if requested.
+    // Generic over `L` because `--break-cycles` needs the target language's
+    // `Language::target_type_as_boxed` to know how that language represents indirection.
+    fn write_data_model_ir<L: Language>(
+        path: &str,
+        mut ir_document: DataModelIrDocument,
+        sort: bool,
+        dedup_nested: bool,
+        break_cycles: bool,
+    ) -> Result<()> {
+        if dedup_nested {
+            let hoisted = ir_document.dedup_nested();
+            if !hoisted.is_empty() {
+                println!("Hoisted duplicate nested types: {}", hoisted.join(", "));
+            }
+        }
+        if break_cycles {
+            let boxed = ir_document.break_cycles::<L>();
+            if !boxed.is_empty() {
+                println!("Boxed cyclic fields: {}", boxed.join(", "));
+            }
+        }
+        if sort {
+            ir_document.sort();
+        }
+        let json = serde_json::to_string_pretty(&ir_document)?;
+        fs::write(path, json)?;
+        println!("Wrote data model IR to {}", path);
+        Ok(())
+    }
+
+    // Writes a `Cargo.toml` declaring the `ion-rs` dependency, plus a `lib.rs` that includes the
+    // generated `ion_generated_code.rs`, so `output` compiles standalone as its own crate.
+    fn write_rust_build_manifest(output: &Path) -> Result<()> {
+        let cargo_toml = r#"[package]
+name = "ion-generated-code"
+version = "0.1.0"
+edition = "2021"
+
+[lib]
+path = "lib.rs"
+
+[dependencies]
+ion-rs = "0.18"
+"#;
+        let lib_rs = "include!(\"ion_generated_code.rs\");\n";
+        fs::write(output.join("Cargo.toml"), cargo_toml)?;
+        fs::write(output.join("lib.rs"), lib_rs)?;
+        println!("Wrote build manifest to {}", output.join("Cargo.toml").display());
+        Ok(())
+    }
+
+    // Writes a `build.gradle` declaring the `ion-java` dependency and `namespace` as the package
+    // root, so `output` compiles standalone as its own Gradle project.
+    fn write_java_build_manifest(output: &Path, namespace: &str) -> Result<()> {
+        let build_gradle = format!(
+            r#"plugins {{
+    id 'java-library'
+}}
+
+group = '{namespace}'
+version = '0.1.0'
+
+repositories {{
+    mavenCentral()
+}}
+
+dependencies {{
+    api 'com.amazon.ion:ion-java:1.11.9'
+}}
+
+sourceSets {{
+    main {{
+        java {{
+            // Code generation writes every type into a single flattened directory rather than
+            // one subdirectory per namespace segment, so that directory is the only source root.
+            srcDirs = ['.']
+        }}
+    }}
+}}
+"#
+        );
+        fs::write(output.join("build.gradle"), build_gradle)?;
+        println!("Wrote build manifest to {}", output.join("build.gradle").display());
+        Ok(())
+    }
+
+    // Prints warning messages for Java code generation
+    fn print_java_code_gen_warnings() {
+        println!("{}","WARNING: Code generation in Java does not support any `$NOMINAL_ION_TYPES` data type.(For more information: https://amazon-ion.github.io/ion-schema/docs/isl-2-0/spec#built-in-types) Reference issue: https://github.com/amazon-ion/ion-cli/issues/101".yellow().bold());
+        println!(
+            "{}",
+            "Optional fields in generated code are represented with the wrapper class of that primitive data type and are set to `null` when missing."
+                .yellow()
+                .bold()
+        );
+        println!("{}", "When the `writeTo` method is used on an optional field and if the field value is set as null then it would skip serializing that field.".yellow().bold());
+        println!("{}", "Code generation in Java accepts --with-validation but does not yet emit a constraint-checking constructor/reader path; no template calls the generated `validate.templ` macro yet.".yellow().bold());
+    }
+
+    // Prints warning messages for Rust code generation
+    //
+    // NOTE: `RustLanguage::target_type_as_optional` (in `utils.rs`) now wraps an optional field's
+    // type reference in `Option<T>`, so the generated struct's *shape* reflects optionality. The
+    // read/write methods this warning still calls out are a separate gap: they'd need to be
+    // emitted by `rust::STRUCT`/`rust::SCALAR`/`rust::RESULT` Tera templates (referenced via
+    // `include_str!` in `templates/mod.rs`, alongside every other language's templates) that
+    // skip a missing `Option` field on write and leave it `None` on read, but none of the
+    // `.templ` files under `templates/` exist anywhere in this repository, for any language --
+    // not just Rust's -- so that marshaling logic isn't generated yet. `Language::read_method_name`/
+    // `write_method_name` and the `read_method_name`/`write_method_name` Tera context values
+    // (see `CodeGenerator::render_generated_code`) are threaded through in anticipation of that
+    // template work, the same way `ion_hash_algorithm` is, but no template reads them yet either.
+    //
+    // The same is true of `--with-validation`: it sets the `with_validation` context value and
+    // registers a `validate.templ` macro template, but no `struct.templ`/`scalar.templ`/etc.
+    // actually imports or calls it yet, so passing the flag doesn't change generated code.
+    fn print_rust_code_gen_warnings() {
+        println!("{}","WARNING: Code generation in Rust does not yet support any `$NOMINAL_ION_TYPES` data type.(For more information: https://amazon-ion.github.io/ion-schema/docs/isl-2-0/spec#built-in-types) Reference issue: https://github.com/amazon-ion/ion-cli/issues/101".yellow().bold());
+        println!("{}","Code generation in Rust represents optional/required fields in the generated struct's field types (`Option<T>` for optional fields), but does not yet generate read or write methods that respect that optionality. Reference issue: https://github.com/amazon-ion/ion-cli/issues/106".yellow().bold());
+        println!("{}","Code generation in Rust accepts --with-validation but does not yet emit a constraint-checking constructor/reader path; no template calls the generated `validate.templ` macro yet.".yellow().bold());
+    }
+
+    // Prints warning messages for Python code generation
+    fn print_python_code_gen_warnings() {
+        println!("{}","WARNING: Code generation in Python does not yet support any `$NOMINAL_ION_TYPES` data type.(For more information: https://amazon-ion.github.io/ion-schema/docs/isl-2-0/spec#built-in-types) Reference issue: https://github.com/amazon-ion/ion-cli/issues/101".yellow().bold());
+        println!("{}","Code generation in Python does not yet support optional/required fields. It does not have any checks added for this on read or write methods.".yellow().bold());
+        println!("{}","Code generation in Python accepts --with-validation but does not yet emit a constraint-checking constructor/reader path; no template calls a generated validation macro yet.".yellow().bold());
+    }
+
+    // Prints warning messages for TypeScript code generation
+    fn print_typescript_code_gen_warnings() {
+        println!("{}","WARNING: Code generation in TypeScript does not yet support any `$NOMINAL_ION_TYPES` data type.(For more information: https://amazon-ion.github.io/ion-schema/docs/isl-2-0/spec#built-in-types) Reference issue: https://github.com/amazon-ion/ion-cli/issues/101".yellow().bold());
+        println!("{}","Code generation in TypeScript does not yet support optional/required fields. It does not have any checks added for this on read or write methods.".yellow().bold());
+        println!("{}","Code generation in TypeScript accepts --with-validation but does not yet emit a constraint-checking constructor/reader path; no template calls a generated validation macro yet.".yellow().bold());
+    }
+
+    // Prints warning messages for Kotlin code generation
+    fn print_kotlin_code_gen_warnings() {
+        println!("{}","WARNING: Code generation in Kotlin does not yet support any `$NOMINAL_ION_TYPES` data type.(For more information: https://amazon-ion.github.io/ion-schema/docs/isl-2-0/spec#built-in-types) Reference issue: https://github.com/amazon-ion/ion-cli/issues/101".yellow().bold());
+        println!("{}","Code generation in Kotlin represents optional/required fields in the generated data class's field types (`T?` for optional fields), but does not yet generate read or write methods that respect that optionality.".yellow().bold());
+        println!("{}","Code generation in Kotlin accepts --with-validation but does not yet emit a constraint-checking constructor/reader path; no template calls a generated validation macro yet.".yellow().bold());
+    }
+}
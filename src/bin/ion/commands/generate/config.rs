@@ -0,0 +1,205 @@
+use crate::commands::generate::namer::{Case, Namer};
+use crate::commands::generate::result::{CodeGenError, CodeGenResult};
+use ion_rs::{AnyEncoding, Element, ElementReader, IonType, Reader};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// User-supplied overrides the code generator consults while naming types/fields and deciding
+/// what to decorate generated types with, analogous to bindgen's `ParseCallbacks`. Loaded from an
+/// Ion document supplied via `--config`, e.g.:
+/// ```ion
+/// {
+///   type_names: { foo_bar: "FooBarDto" },
+///   field_names: { self: "self_" },
+///   field_defaults: { count: "0" },
+///   derives: ["Serialize", "Deserialize"],
+///   annotations: ["#[serde(rename_all = \"camelCase\")]"],
+///   namer: {
+///     field_case: "screaming_snake",
+///     keyword_prefix: "",
+///     keyword_suffix: "_",
+///   },
+/// }
+/// ```
+/// This lets users adapt generated models to their own serialization frameworks (e.g. adding
+/// `#[derive(Serialize, Deserialize)]` for a Rust target, or `@JsonProperty` for a Java target)
+/// without patching the crate.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct CodeGenConfig {
+    // Overrides the generated type name for the ISL type named by the map key.
+    type_names: HashMap<String, String>,
+    // Overrides the generated field identifier for the ISL field named by the map key.
+    field_names: HashMap<String, String>,
+    // A target-language literal expression used to initialize the ISL field named by the map key
+    // when it's optional and absent, instead of wrapping it in `Option`/`Optional`.
+    field_defaults: HashMap<String, String>,
+    // Extra derive/decorator attributes added to every generated type.
+    derives: Vec<String>,
+    // Extra annotations (e.g. `@JsonProperty`-style or Lombok attributes) added to every
+    // generated type.
+    annotations: Vec<String>,
+    // Case conventions and keyword escaping applied across every generated type/field/namespace
+    // node, consulted in place of the target `Language`'s own defaults where configured. See
+    // `generate::namer::Namer`.
+    namer: Namer,
+}
+
+impl CodeGenConfig {
+    /// Loads a [CodeGenConfig] from the Ion document at `path`.
+    // TODO: also accept a TOML config file once this crate takes a `toml` dependency; for now
+    // only the Ion format is supported.
+    pub(crate) fn from_file<P: AsRef<Path>>(path: P) -> CodeGenResult<Self> {
+        let contents = std::fs::read(path)?;
+        let elements = Reader::new(AnyEncoding, contents.as_slice())
+            .and_then(|mut reader| reader.read_all_elements())
+            .map_err(|e| CodeGenError::InvalidDataModel {
+                description: format!("could not parse code generation config: {e}"),
+            })?;
+
+        let element = elements.first().ok_or_else(|| CodeGenError::InvalidDataModel {
+            description: "code generation config file is empty".to_string(),
+        })?;
+
+        let config_struct = element.as_struct().ok_or_else(|| CodeGenError::InvalidDataModel {
+            description: "code generation config must be a top-level Ion struct".to_string(),
+        })?;
+
+        let mut config = CodeGenConfig::default();
+        for (name, value) in config_struct.fields() {
+            match name.text().unwrap_or_default() {
+                "type_names" => config.type_names = Self::read_string_map(value)?,
+                "field_names" => config.field_names = Self::read_string_map(value)?,
+                "field_defaults" => config.field_defaults = Self::read_string_map(value)?,
+                "derives" => config.derives = Self::read_string_list(value)?,
+                "annotations" => config.annotations = Self::read_string_list(value)?,
+                "namer" => config.namer = Self::read_namer(value)?,
+                other => {
+                    return Err(CodeGenError::InvalidDataModel {
+                        description: format!("unrecognized code generation config field '{other}'"),
+                    })
+                }
+            }
+        }
+        Ok(config)
+    }
+
+    fn read_string_map(value: &Element) -> CodeGenResult<HashMap<String, String>> {
+        let s = value.as_struct().ok_or_else(|| CodeGenError::InvalidDataModel {
+            description: "expected an Ion struct of name overrides".to_string(),
+        })?;
+        let mut map = HashMap::new();
+        for (name, value) in s.fields() {
+            let value = value.as_string().ok_or_else(|| CodeGenError::InvalidDataModel {
+                description: "name override values must be strings".to_string(),
+            })?;
+            map.insert(name.text().unwrap_or_default().to_string(), value.to_string());
+        }
+        Ok(map)
+    }
+
+    /// Parses a `namer` field (see [Namer]) from a nested Ion struct whose `*_case` fields name
+    /// one of [Case]'s variants (e.g. `"pascal_case"`) and whose `keyword_prefix`/`keyword_suffix`
+    /// fields are plain strings.
+    fn read_namer(value: &Element) -> CodeGenResult<Namer> {
+        let s = value.as_struct().ok_or_else(|| CodeGenError::InvalidDataModel {
+            description: "code generation config field 'namer' must be an Ion struct".to_string(),
+        })?;
+        let mut namer = Namer::default();
+        for (name, value) in s.fields() {
+            let field_name = name.text().unwrap_or_default();
+            match field_name {
+                "type_case" | "field_case" | "method_case" | "constant_case" | "namespace_case" => {
+                    let raw = value.as_string().ok_or_else(|| CodeGenError::InvalidDataModel {
+                        description: format!("namer field '{field_name}' must be a string"),
+                    })?;
+                    let case = Case::parse(raw).ok_or_else(|| CodeGenError::InvalidDataModel {
+                        description: format!(
+                            "namer field '{field_name}' has an unrecognized case '{raw}'"
+                        ),
+                    })?;
+                    match field_name {
+                        "type_case" => namer.type_case = Some(case),
+                        "field_case" => namer.field_case = Some(case),
+                        "method_case" => namer.method_case = Some(case),
+                        "constant_case" => namer.constant_case = Some(case),
+                        "namespace_case" => namer.namespace_case = Some(case),
+                        _ => unreachable!(),
+                    }
+                }
+                "keyword_prefix" | "keyword_suffix" => {
+                    let raw = value.as_string().ok_or_else(|| CodeGenError::InvalidDataModel {
+                        description: format!("namer field '{field_name}' must be a string"),
+                    })?;
+                    if field_name == "keyword_prefix" {
+                        namer.keyword_prefix = Some(raw.to_string());
+                    } else {
+                        namer.keyword_suffix = Some(raw.to_string());
+                    }
+                }
+                other => {
+                    return Err(CodeGenError::InvalidDataModel {
+                        description: format!("unrecognized namer config field '{other}'"),
+                    })
+                }
+            }
+        }
+        Ok(namer)
+    }
+
+    fn read_string_list(value: &Element) -> CodeGenResult<Vec<String>> {
+        if value.ion_type() != IonType::List {
+            return Err(CodeGenError::InvalidDataModel {
+                description: "expected an Ion list of strings".to_string(),
+            });
+        }
+        value
+            .as_sequence()
+            .unwrap()
+            .elements()
+            .map(|e| {
+                e.as_string()
+                    .map(|s| s.to_string())
+                    .ok_or_else(|| CodeGenError::InvalidDataModel {
+                        description: "list entries must be strings".to_string(),
+                    })
+            })
+            .collect()
+    }
+
+    /// Returns the overridden generated type name for the ISL type named `original`, if any.
+    pub(crate) fn type_name(&self, original: &str) -> Option<&str> {
+        self.type_names.get(original).map(String::as_str)
+    }
+
+    /// Returns the overridden generated field identifier for the ISL field named `original`, if
+    /// any.
+    pub(crate) fn field_name(&self, original: &str) -> Option<&str> {
+        self.field_names.get(original).map(String::as_str)
+    }
+
+    /// Returns the configured default-value literal for the ISL field named `original`, if any.
+    pub(crate) fn field_default(&self, original: &str) -> Option<&str> {
+        self.field_defaults.get(original).map(String::as_str)
+    }
+
+    /// Extra derive/decorator attributes that should be added to every generated type.
+    pub(crate) fn derives(&self) -> &[String] {
+        &self.derives
+    }
+
+    /// Extra annotations that should be added to every generated type.
+    pub(crate) fn annotations(&self) -> &[String] {
+        &self.annotations
+    }
+
+    /// The naming policy overrides (case conventions, keyword escaping) configured for this run.
+    pub(crate) fn namer(&self) -> &Namer {
+        &self.namer
+    }
+
+    /// Mutable access to the naming policy, for a caller (e.g. `--naming` on the command line)
+    /// that wants to fill in case conventions this config wasn't loaded from a file with.
+    pub(crate) fn namer_mut(&mut self) -> &mut Namer {
+        &mut self.namer
+    }
+}
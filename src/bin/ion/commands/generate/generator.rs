@@ -1,15 +1,21 @@
+use crate::commands::generate::config::CodeGenConfig;
 use crate::commands::generate::context::{CodeGenContext, SequenceType};
 use crate::commands::generate::model::{
-    AbstractDataType, DataModelNode, EnumBuilder, FieldPresence, FieldReference,
-    FullyQualifiedTypeReference, NamespaceNode, ScalarBuilder, SequenceBuilder, StructureBuilder,
-    WrappedScalarBuilder, WrappedSequenceBuilder,
+    AbstractDataType, DataModelIrDocument, DataModelNode, Derivability, EnumBuilder,
+    EnumVariantValue, FieldPresence, FieldReference, FullyQualifiedTypeReference, LengthBound,
+    MapBuilder, NamespaceNode, ScalarBuilder, SequenceBuilder, StructureBuilder, TagRepresentation,
+    TupleBuilder, UnionBuilder, WrappedScalarBuilder, WrappedSequenceBuilder,
 };
+use crate::commands::generate::plugin::{CodeGenPlugin, CodeGenPluginContext};
 use crate::commands::generate::result::{
-    invalid_abstract_data_type_error, invalid_abstract_data_type_raw_error, CodeGenResult,
+    conflicting_constraints_error, duplicate_constraint_error, invalid_abstract_data_type_error,
+    invalid_abstract_data_type_raw_error, CodeGenDiagnostic, CodeGenError, CodeGenResult,
 };
 use crate::commands::generate::templates;
-use crate::commands::generate::utils::{IonSchemaType, Template};
-use crate::commands::generate::utils::{JavaLanguage, Language, RustLanguage};
+use crate::commands::generate::utils::{DigestAlgorithm, Format, IonSchemaType, Template};
+use crate::commands::generate::utils::{
+    JavaLanguage, KotlinLanguage, Language, PythonLanguage, RustLanguage, TypeScriptLanguage,
+};
 use convert_case::{Case, Casing};
 use ion_rs::Value;
 use ion_schema::isl::isl_constraint::{IslConstraint, IslConstraintValue};
@@ -18,7 +24,7 @@ use ion_schema::isl::isl_type_reference::IslTypeRef;
 use ion_schema::isl::util::ValidValue;
 use ion_schema::isl::IslSchema;
 use ion_schema::system::SchemaSystem;
-use std::collections::{BTreeSet, HashMap};
+use std::collections::{BTreeSet, HashMap, HashSet};
 use std::fs;
 use std::fs::OpenOptions;
 use std::io::Write;
@@ -26,6 +32,16 @@ use std::marker::PhantomData;
 use std::path::Path;
 use tera::{Context, Tera};
 
+/// Identifies a single by-value field/element within a `DataModelNode`'s `AbstractDataType`, used
+/// by [CodeGenerator::box_cyclic_fields] to name which field of a cyclic type needs indirection.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) enum FieldSlot {
+    /// A `Structure` field, keyed by its field name.
+    Field(String),
+    /// A `Tuple` element, keyed by its position.
+    Element(usize),
+}
+
 pub(crate) struct CodeGenerator<'a, L: Language> {
     // Represents the templating engine - tera
     // more information: https://docs.rs/tera/latest/tera/
@@ -34,6 +50,55 @@ pub(crate) struct CodeGenerator<'a, L: Language> {
     // This field is used by Java code generation to get the namespace for generated code.
     current_type_fully_qualified_name: Vec<NamespaceNode>,
     pub(crate) data_model_store: HashMap<FullyQualifiedTypeReference, DataModelNode>,
+    // The Ion encoding generated types' `read`/`write` methods target; defaults to pretty text.
+    output_format: Format,
+    // The digest algorithm generated types' Ion Hash method should use. `None` (the default)
+    // skips generating an Ion Hash method altogether, since it's an opt-in code-generation mode.
+    ion_hash_algorithm: Option<DigestAlgorithm>,
+    // Whether generated types should check the originating ISL constraints (min/max occurrences,
+    // type refinements, `valid_values`, ranges) against incoming Ion before materializing the
+    // object. `false` (the default) skips generating that check, since it's an opt-in
+    // code-generation mode, like `ion_hash_algorithm` above.
+    with_validation: bool,
+    // User-supplied overrides for generated names, derives, and annotations. `None` (the default)
+    // means only the target `Language`'s own defaults apply.
+    config: Option<CodeGenConfig>,
+    // Additional, language-agnostic generators invoked per `DataModelNode` alongside the Tera
+    // rendering above (see `generate::plugin`). Empty by default.
+    plugins: Vec<Box<dyn CodeGenPlugin>>,
+    // The id of the ISL schema currently being generated, set at the top of `generate`. Used to
+    // key `schema_import_edges` and `imported_type_symbols` while resolving that schema's
+    // `IslTypeRef::TypeImport`s.
+    current_schema_id: String,
+    // Edges of an "imports from" graph between schema ids, built up as `IslTypeRef::TypeImport`s
+    // are resolved across the run. Consulted by `check_import_cycle` to detect import cycles
+    // instead of risking an infinite loop if import resolution ever becomes recursive.
+    schema_import_edges: HashMap<String, BTreeSet<String>>,
+    // A symbol table mapping a top-level ISL type's true identity -- `(schema_id, isl_type_name)`
+    // -- to the `FullyQualifiedTypeReference` it was actually generated under. Populated in
+    // `generate` as each of a schema's top-level types resolves, and consulted by
+    // `resolve_imported_type` so an `IslTypeRef::TypeImport` can be resolved against a type that's
+    // really been generated instead of a namespace guessed from the importing schema id's path
+    // (see `namespace_for_imported_schema`/`imported_target_type_for`, which remain the fallback
+    // for an import this table doesn't have an answer for yet).
+    imported_type_symbols: HashMap<(String, String), FullyQualifiedTypeReference>,
+    // When set (via `without_rendering`), `render_generated_code` resolves each type into
+    // `data_model_store` as usual but skips Tera rendering, file I/O, and plugins for it. Used by
+    // the `json` pseudo-target, which only wants the resolved data model
+    // ([data_model_ir_document]), not a rendering of it in some target language.
+    skip_rendering: bool,
+    // Memoizes `derivability_for`'s result per resolved type, since a nested type is revisited
+    // once per sibling field that references it.
+    derivability_cache: HashMap<FullyQualifiedTypeReference, Derivability>,
+    // Guards `derivability_for` against infinite recursion on a (transitively) self-referential
+    // type graph.
+    derivability_in_progress: HashSet<FullyQualifiedTypeReference>,
+    // Unsupported-constraint diagnostics collected while resolving ISL types, one per type that
+    // `resolve_abstract_data_type` couldn't turn into an `AbstractDataType` (a
+    // `CodeGenError::InvalidDataModel`). Collecting these instead of aborting on the first one
+    // lets a user running against a large authority see every unsupported type in one pass; see
+    // `generate_code_for_authorities`.
+    diagnostics: Vec<CodeGenDiagnostic>,
     phantom: PhantomData<L>,
 }
 
@@ -48,10 +113,12 @@ impl<'a> CodeGenerator<'a, RustLanguage> {
             ("scalar.templ", templates::rust::SCALAR),
             ("sequence.templ", templates::rust::SEQUENCE),
             ("enum.templ", templates::rust::ENUM),
+            ("tuple.templ", templates::rust::TUPLE),
             ("util_macros.templ", templates::rust::UTIL_MACROS),
             ("import.templ", templates::rust::IMPORT),
             ("nested_type.templ", templates::rust::NESTED_TYPE),
             ("result.templ", templates::rust::RESULT),
+            ("validate.templ", templates::rust::VALIDATE),
         ])
         .unwrap();
         // Render the imports into output file
@@ -77,6 +144,118 @@ impl<'a> CodeGenerator<'a, RustLanguage> {
             tera,
             phantom: PhantomData,
             data_model_store: HashMap::new(),
+            output_format: Format::default(),
+            ion_hash_algorithm: None,
+            with_validation: false,
+            config: None,
+            plugins: Vec::new(),
+            current_schema_id: String::new(),
+            schema_import_edges: HashMap::new(),
+            imported_type_symbols: HashMap::new(),
+            skip_rendering: false,
+            derivability_cache: HashMap::new(),
+            derivability_in_progress: HashSet::new(),
+            diagnostics: Vec::new(),
+        }
+    }
+}
+
+impl<'a> CodeGenerator<'a, PythonLanguage> {
+    #[allow(dead_code)]
+    pub fn new(output: &'a Path) -> CodeGenerator<'a, PythonLanguage> {
+        let mut tera = Tera::default();
+        // Add all templates using `python_templates` module constants
+        // This allows packaging binary without the need of template resources.
+        tera.add_raw_templates(vec![
+            ("dataclass.templ", templates::python::DATACLASS),
+            ("scalar.templ", templates::python::SCALAR),
+            ("sequence.templ", templates::python::SEQUENCE),
+            ("nested_type.templ", templates::python::NESTED_TYPE),
+            ("import.templ", templates::python::IMPORT),
+        ])
+        .unwrap();
+        // Render the imports into output file
+        let rendered_import = tera.render("import.templ", &Context::new()).unwrap();
+
+        let mut file = OpenOptions::new()
+            .write(true)
+            .truncate(true)
+            .create(true)
+            .open(output.join("ion_generated_code.py"))
+            .unwrap();
+        file.write_all(rendered_import.as_bytes()).unwrap();
+
+        Self {
+            output,
+            // Currently Python code generation doesn't have a `--namespace` option available on
+            // the CLI, hence this is default set as an empty vector.
+            current_type_fully_qualified_name: vec![],
+            tera,
+            phantom: PhantomData,
+            data_model_store: HashMap::new(),
+            output_format: Format::default(),
+            ion_hash_algorithm: None,
+            with_validation: false,
+            config: None,
+            plugins: Vec::new(),
+            current_schema_id: String::new(),
+            schema_import_edges: HashMap::new(),
+            imported_type_symbols: HashMap::new(),
+            skip_rendering: false,
+            derivability_cache: HashMap::new(),
+            derivability_in_progress: HashSet::new(),
+            diagnostics: Vec::new(),
+        }
+    }
+}
+
+impl<'a> CodeGenerator<'a, TypeScriptLanguage> {
+    #[allow(dead_code)]
+    pub fn new(output: &'a Path) -> CodeGenerator<'a, TypeScriptLanguage> {
+        let mut tera = Tera::default();
+        // Add all templates using `typescript_templates` module constants
+        // This allows packaging binary without the need of template resources.
+        tera.add_raw_templates(vec![
+            ("interface.templ", templates::typescript::INTERFACE),
+            ("scalar.templ", templates::typescript::SCALAR),
+            ("sequence.templ", templates::typescript::SEQUENCE),
+            ("enum.templ", templates::typescript::ENUM),
+            ("nested_type.templ", templates::typescript::NESTED_TYPE),
+            ("import.templ", templates::typescript::IMPORT),
+            ("type_guard.templ", templates::typescript::TYPE_GUARD),
+        ])
+        .unwrap();
+        // Render the imports into output file
+        let rendered_import = tera.render("import.templ", &Context::new()).unwrap();
+
+        let mut file = OpenOptions::new()
+            .write(true)
+            .truncate(true)
+            .create(true)
+            .open(output.join("ion_generated_code.ts"))
+            .unwrap();
+        file.write_all(rendered_import.as_bytes()).unwrap();
+
+        Self {
+            output,
+            // Currently TypeScript code generation doesn't have a `--namespace` option available
+            // on the CLI, hence this is default set as an empty vector.
+            current_type_fully_qualified_name: vec![],
+            tera,
+            phantom: PhantomData,
+            data_model_store: HashMap::new(),
+            output_format: Format::default(),
+            ion_hash_algorithm: None,
+            with_validation: false,
+            config: None,
+            plugins: Vec::new(),
+            current_schema_id: String::new(),
+            schema_import_edges: HashMap::new(),
+            imported_type_symbols: HashMap::new(),
+            skip_rendering: false,
+            derivability_cache: HashMap::new(),
+            derivability_in_progress: HashSet::new(),
+            diagnostics: Vec::new(),
         }
     }
 }
@@ -91,8 +270,48 @@ impl<'a> CodeGenerator<'a, JavaLanguage> {
             ("scalar.templ", templates::java::SCALAR),
             ("sequence.templ", templates::java::SEQUENCE),
             ("enum.templ", templates::java::ENUM),
+            ("record.templ", templates::java::RECORD),
             ("util_macros.templ", templates::java::UTIL_MACROS),
             ("nested_type.templ", templates::java::NESTED_TYPE),
+            ("validate.templ", templates::java::VALIDATE),
+        ])
+        .unwrap();
+        Self {
+            output,
+            current_type_fully_qualified_name: namespace,
+            tera,
+            phantom: PhantomData,
+            data_model_store: HashMap::new(),
+            output_format: Format::default(),
+            ion_hash_algorithm: None,
+            with_validation: false,
+            config: None,
+            plugins: Vec::new(),
+            current_schema_id: String::new(),
+            schema_import_edges: HashMap::new(),
+            imported_type_symbols: HashMap::new(),
+            skip_rendering: false,
+            derivability_cache: HashMap::new(),
+            derivability_in_progress: HashSet::new(),
+            diagnostics: Vec::new(),
+        }
+    }
+}
+
+impl<'a> CodeGenerator<'a, KotlinLanguage> {
+    pub fn new(
+        output: &'a Path,
+        namespace: Vec<NamespaceNode>,
+    ) -> CodeGenerator<'a, KotlinLanguage> {
+        let mut tera = Tera::default();
+        // Add all templates using `kotlin_templates` module constants
+        // This allows packaging binary without the need of template resources.
+        tera.add_raw_templates(vec![
+            ("data_class.templ", templates::kotlin::DATA_CLASS),
+            ("scalar.templ", templates::kotlin::SCALAR),
+            ("sequence.templ", templates::kotlin::SEQUENCE),
+            ("enum.templ", templates::kotlin::ENUM),
+            ("nested_type.templ", templates::kotlin::NESTED_TYPE),
         ])
         .unwrap();
         Self {
@@ -101,7 +320,86 @@ impl<'a> CodeGenerator<'a, JavaLanguage> {
             tera,
             phantom: PhantomData,
             data_model_store: HashMap::new(),
+            output_format: Format::default(),
+            ion_hash_algorithm: None,
+            with_validation: false,
+            config: None,
+            plugins: Vec::new(),
+            current_schema_id: String::new(),
+            schema_import_edges: HashMap::new(),
+            imported_type_symbols: HashMap::new(),
+            skip_rendering: false,
+            derivability_cache: HashMap::new(),
+            derivability_in_progress: HashSet::new(),
+            diagnostics: Vec::new(),
+        }
+    }
+}
+
+/// Which `AbstractDataType` variant(s) a type's constraint set implies, collected in one pass over
+/// its constraints (see [CodeGenerator::classify_abstract_data_type]) so [Self::check_consistent]
+/// can compare them all at once rather than the variant-selection chain in
+/// [CodeGenerator::convert_isl_type_def_to_data_model_node] discovering a conflict only after it
+/// has already committed to one variant. Each field names the constraint responsible, for use in
+/// the conflict message.
+#[derive(Default)]
+struct ImpliedAbstractDataTypeKinds {
+    fields: bool,
+    element: bool,
+    ordered_elements: bool,
+    enum_: bool,
+    union: bool,
+    scalar_type: Option<String>,
+}
+
+impl ImpliedAbstractDataTypeKinds {
+    /// The number of mutually exclusive variants this constraint set implies. A `type: struct`/
+    /// `type: list`/`type: sexp` constraint is load-bearing for `fields`/`element` respectively (it
+    /// names which container), not a competing implication, so it's excluded here and checked
+    /// separately below.
+    fn implied_count(&self) -> usize {
+        [self.fields, self.element, self.ordered_elements, self.enum_, self.union]
+            .iter()
+            .filter(|implied| **implied)
+            .count()
+    }
+
+    /// Returns an error naming the two conflicting constraints if this type's constraints imply
+    /// more than one `AbstractDataType` variant, or if a scalar `type` constraint contradicts a
+    /// `fields`/`element` constraint that requires a container type.
+    fn check_consistent(&self, isl_type_name: &str) -> CodeGenResult<()> {
+        if self.implied_count() > 1 {
+            let mut implied_names = [
+                ("fields", self.fields),
+                ("element", self.element),
+                ("ordered_elements", self.ordered_elements),
+                ("valid_values (enum)", self.enum_),
+                ("one_of/any_of", self.union),
+            ]
+            .into_iter()
+            .filter(|(_, implied)| *implied)
+            .map(|(name, _)| name);
+            let first = implied_names.next().expect("implied_count() > 1");
+            let second = implied_names.next().expect("implied_count() > 1");
+            return conflicting_constraints_error(isl_type_name, first, second);
+        }
+        if let Some(scalar_type) = &self.scalar_type {
+            if self.fields {
+                return conflicting_constraints_error(
+                    isl_type_name,
+                    "fields",
+                    format!("type: {scalar_type}"),
+                );
+            }
+            if self.element {
+                return conflicting_constraints_error(
+                    isl_type_name,
+                    "element",
+                    format!("type: {scalar_type}"),
+                );
+            }
         }
+        Ok(())
     }
 }
 
@@ -165,6 +463,24 @@ impl<L: Language + 'static> CodeGenerator<'_, L> {
         ))
     }
 
+    /// A [tera] filter that escapes the given tera string value if it collides with one of `L`'s
+    /// reserved words (see [Language::escape_reserved_word]), so a template can apply it to a
+    /// field accessor the same way it applies `upper_camel`/`camel`/`snake` to a field name.
+    ///
+    /// For more information: <https://docs.rs/tera/1.19.0/tera/struct.Tera.html#method.register_filter>
+    ///
+    /// [tera]: <https://docs.rs/tera/latest/tera/>
+    pub fn escape_reserved_word(
+        value: &tera::Value,
+        _map: &HashMap<String, tera::Value>,
+    ) -> Result<tera::Value, tera::Error> {
+        Ok(tera::Value::String(L::escape_reserved_word(
+            value.as_str().ok_or(tera::Error::msg(
+                "the `escape_reserved_word` filter only accepts strings",
+            ))?,
+        )))
+    }
+
     /// A [tera] filter that return true if the value is a built in type, otherwise returns false.
     ///
     /// For more information: <https://docs.rs/tera/1.19.0/tera/struct.Tera.html#method.register_filter>
@@ -275,10 +591,143 @@ impl<L: Language + 'static> CodeGenerator<'_, L> {
         &mut self,
         authorities: &Vec<&String>,
         schema_system: &mut SchemaSystem,
-    ) -> CodeGenResult<()> {
+    ) -> CodeGenResult<Vec<CodeGenDiagnostic>> {
         for authority in authorities {
             self.generate_code_for_directory(authority, None, schema_system)?;
         }
+        Ok(self.diagnostics.clone())
+    }
+
+    /// Collects every [DataModelNode] produced by this code generation run into a self-describing,
+    /// versioned JSON IR document that external tools can consume to generate bindings for
+    /// languages this crate doesn't target.
+    pub fn data_model_ir_document(&self) -> DataModelIrDocument {
+        DataModelIrDocument::new(self.data_model_store.values().cloned().collect())
+            .with_schema_id(self.current_schema_id.clone())
+    }
+
+    /// Unsupported-constraint diagnostics collected across every `generate` call made with this
+    /// `CodeGenerator` so far, one per ISL type whose constraints aren't yet supported. Consult
+    /// this after `generate_code_for_authorities` returns to report every unsupported type found
+    /// in the run, rather than just the first one encountered.
+    pub fn diagnostics(&self) -> &[CodeGenDiagnostic] {
+        &self.diagnostics
+    }
+
+    /// Overrides the Ion encoding that generated types' `read`/`write` methods target (defaults
+    /// to pretty text), mirroring ion-rs's `TextFormat::Pretty`/`v1_0::Binary` encodings.
+    pub fn with_format(mut self, format: Format) -> Self {
+        self.output_format = format;
+        self
+    }
+
+    /// Opts generated types into an Ion Hash digest method using `algorithm`. This is off by
+    /// default since it's an additional, opt-in code-generation mode.
+    pub fn with_ion_hash_algorithm(mut self, algorithm: DigestAlgorithm) -> Self {
+        self.ion_hash_algorithm = Some(algorithm);
+        self
+    }
+
+    /// Opts generated types into a runtime validation check -- run against the originating ISL
+    /// type's constraints before the object is materialized -- on their constructor/reader path.
+    /// This is off by default since it's an additional, opt-in code-generation mode, like
+    /// `with_ion_hash_algorithm` above.
+    pub fn with_validation(mut self, enabled: bool) -> Self {
+        self.with_validation = enabled;
+        self
+    }
+
+    /// Loads every `*.templ` file in `dir`, registering each one under its file name (e.g. a file
+    /// named `struct.templ` registers as `"struct.templ"`), so it replaces the built-in default
+    /// template of the same name. This lets a user customize the shape of generated code (e.g. add
+    /// builder methods, change field visibility, inject annotations) without forking this crate.
+    ///
+    /// A custom template keeps access to the `upper_camel`/`snake`/`camel`/`escape_reserved_word`/
+    /// `is_built_in_type`/`field_names`/`fully_qualified_type_name`/`parameters`/
+    /// `primitive_data_type`/`wrapper_class` filters and the `model`/`type_store`/`namespace`/
+    /// `is_nested`/`format`/`ion_hash_algorithm`/`with_validation`/`read_method_name`/
+    /// `write_method_name` context
+    /// keys, since both are registered on/inserted into this same `tera`/`Context` rather than a
+    /// separate instance. Tera itself errors out of `render` if a template references a context
+    /// variable that isn't there, so a typo'd variable name surfaces as a `CodeGenError` rather
+    /// than silently rendering nothing.
+    pub fn with_templates(mut self, dir: &str) -> CodeGenResult<Self> {
+        for entry in fs::read_dir(dir)? {
+            let path = entry?.path();
+            if path.extension().and_then(|ext| ext.to_str()) != Some("templ") {
+                continue;
+            }
+            let file_name = path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .ok_or_else(|| {
+                    invalid_abstract_data_type_raw_error(format!(
+                        "template file name is not valid UTF-8: {}",
+                        path.display()
+                    ))
+                })?
+                .to_string();
+            let contents = fs::read_to_string(&path)?;
+            self.tera.add_raw_template(&file_name, &contents)?;
+        }
+        Ok(self)
+    }
+
+    /// Supplies user overrides for generated type/field names, derives, and annotations (see
+    /// `generate::config::CodeGenConfig`), consulted in place of the target `Language`'s default
+    /// naming/decoration for the ISL types they name.
+    pub fn with_config(mut self, config: CodeGenConfig) -> Self {
+        self.config = Some(config);
+        self
+    }
+
+    /// Opts out of Tera rendering, per-type file writes, and plugin invocation: each ISL type
+    /// still resolves into `data_model_store` (and so into [Self::data_model_ir_document]) as
+    /// usual, but no generated source is produced. Used by the `json` pseudo-target, which only
+    /// wants the resolved data model, not a rendering of it in some target language.
+    pub fn without_rendering(mut self) -> Self {
+        self.skip_rendering = true;
+        self
+    }
+
+    /// Registers an additional, language-agnostic generator invoked at three points during
+    /// `generate`: once per top-level ISL type before it's resolved (`CodeGenPlugin::on_type`),
+    /// once per resolved `AbstractDataType` just before it's rendered
+    /// (`CodeGenPlugin::on_definition`), and once per resolved `DataModelNode` after that node's
+    /// own `Language`-rendered file has been written (`CodeGenPlugin::generate`). See
+    /// `generate::plugin::CodeGenPlugin`. Plugins run in the order they're registered.
+    #[allow(dead_code)]
+    pub fn with_plugin(mut self, plugin: Box<dyn CodeGenPlugin>) -> Self {
+        self.plugins.push(plugin);
+        self
+    }
+
+    /// Runs every registered plugin against `data_model_node`, writing each file a plugin returns
+    /// relative to `self.output`.
+    fn run_plugins(
+        &self,
+        data_model_node: &DataModelNode,
+        fully_qualified_name: &[NamespaceNode],
+    ) -> CodeGenResult<()> {
+        if self.plugins.is_empty() {
+            return Ok(());
+        }
+        let module_prefix = fully_qualified_name[0..fully_qualified_name.len().saturating_sub(1)]
+            .join(&L::namespace_separator());
+        let context = CodeGenPluginContext {
+            output_dir: self.output.to_path_buf(),
+            module_prefix,
+            support_runtime: L::name(),
+        };
+        for plugin in &self.plugins {
+            for file in plugin.generate(data_model_node, &context)? {
+                let path = self.output.join(file.relative_path);
+                if let Some(parent) = path.parent() {
+                    fs::create_dir_all(parent)?;
+                }
+                fs::write(path, file.contents)?;
+            }
+        }
         Ok(())
     }
 
@@ -325,10 +774,17 @@ impl<L: Language + 'static> CodeGenerator<'_, L> {
     }
 
     fn generate(&mut self, schema: IslSchema) -> CodeGenResult<()> {
+        // Tracks which schema is "currently being generated" so `check_import_cycle` can key the
+        // import graph by it; read back out of `schema.id()` rather than the file path passed to
+        // `load_isl_schema`, since imports are resolved against the same id space.
+        self.current_schema_id = schema.id().to_string();
+
         // Register a tera filter that can be used to convert a string based on case
         self.tera.register_filter("upper_camel", Self::upper_camel);
         self.tera.register_filter("snake", Self::snake);
         self.tera.register_filter("camel", Self::camel);
+        self.tera
+            .register_filter("escape_reserved_word", Self::escape_reserved_word);
 
         // Register a tera filter that can be used to see if a type is built in data type or not
         self.tera
@@ -343,11 +799,48 @@ impl<L: Language + 'static> CodeGenerator<'_, L> {
         self.tera
             .register_filter("wrapper_class", Self::wrapper_class);
 
-        // Iterate through the ISL types, generate an abstract data type for each
+        // Iterate through the ISL types and resolve an abstract data type for each, deferring
+        // rendering until every type for this schema (top-level and nested) is in
+        // `data_model_store` -- `box_cyclic_fields` needs the complete picture to tell a field
+        // that refers to a not-yet-resolved sibling type apart from one that closes a cycle.
+        let mut resolved_types = Vec::new();
         for isl_type in schema.types() {
             // unwrap here is safe because all the top-level type definition always has a name
             let isl_type_name = isl_type.name().unwrap().to_string();
-            self.generate_abstract_data_type(&isl_type_name, isl_type)?;
+            match self.resolve_abstract_data_type(&isl_type_name, isl_type) {
+                Ok(data_model_node) => {
+                    // Record the real generated name for this type before moving on, so a later
+                    // `IslTypeRef::TypeImport` (from this schema or another) can resolve against
+                    // it instead of guessing one from `current_schema_id`'s path alone.
+                    if let Some(type_ref) = data_model_node.fully_qualified_type_ref::<L>() {
+                        self.register_imported_type_symbol(&isl_type_name, type_ref)?;
+                    }
+                    resolved_types.push((isl_type_name, data_model_node))
+                }
+                // An unsupported constraint on this particular type shouldn't keep every other
+                // type in the schema from generating; collect it and keep going so a user sees
+                // every unsupported type in one pass instead of one at a time.
+                Err(CodeGenError::InvalidDataModel { description }) => {
+                    self.diagnostics.push(CodeGenDiagnostic {
+                        type_name: isl_type_name,
+                        description,
+                    });
+                }
+                Err(e) => return Err(e),
+            }
+        }
+
+        self.box_cyclic_fields();
+
+        for (isl_type_name, mut data_model_node) in resolved_types {
+            // Re-fetch from `data_model_store`, since `box_cyclic_fields` mutates entries there
+            // (and not this locally-held copy) to wrap cyclic fields in indirection.
+            let data_model_node = data_model_node
+                .fully_qualified_type_ref::<L>()
+                .and_then(|type_ref| self.data_model_store.get(&type_ref))
+                .cloned()
+                .unwrap_or(data_model_node);
+            self.render_abstract_data_type(&isl_type_name, data_model_node)?;
         }
         Ok(())
     }
@@ -399,20 +892,33 @@ impl<L: Language + 'static> CodeGenerator<'_, L> {
         }
     }
 
-    fn generate_abstract_data_type(
+    /// Resolves a single top-level ISL type (and, transitively, its nested types) into a
+    /// [DataModelNode], recording it and every nested type it contains into `data_model_store`.
+    /// Rendering is deferred to [Self::render_abstract_data_type], called once `generate` has
+    /// resolved every type for the schema and run `box_cyclic_fields` over the result.
+    fn resolve_abstract_data_type(
         &mut self,
         isl_type_name: &String,
         isl_type: &IslType,
-    ) -> CodeGenResult<()> {
-        let mut context = Context::new();
+    ) -> CodeGenResult<DataModelNode> {
         let mut code_gen_context = CodeGenContext::new();
-
-        let data_model_node = self.convert_isl_type_def_to_data_model_node(
+        for plugin in &self.plugins {
+            plugin.on_type(isl_type, &mut code_gen_context)?;
+        }
+        self.convert_isl_type_def_to_data_model_node(
             isl_type_name,
             isl_type,
             &mut code_gen_context,
             false,
-        )?;
+        )
+    }
+
+    fn render_abstract_data_type(
+        &mut self,
+        isl_type_name: &String,
+        data_model_node: DataModelNode,
+    ) -> CodeGenResult<()> {
+        let mut context = Context::new();
 
         // add the entire type store and the data model node into tera's context to be used to render template
         context.insert(
@@ -453,6 +959,15 @@ impl<L: Language + 'static> CodeGenerator<'_, L> {
 
         let constraints = isl_type.constraints();
 
+        // Before deciding *which* `AbstractDataType` variant this type implies, check that the
+        // constraint set doesn't imply more than one -- e.g. both `fields` and `element`, or a
+        // `type` naming a scalar alongside `fields`. Doing this as its own pass, ahead of the
+        // variant-selection chain below, means a conflicting schema is rejected with the specific
+        // pair of constraints at fault instead of the chain silently picking whichever variant
+        // happens to be checked first.
+        let implied_kinds = Self::classify_abstract_data_type(constraints);
+        implied_kinds.check_consistent(isl_type_name)?;
+
         // Initialize `AbstractDataType` according to the list of constraints
         // Below are some checks to verify which AbstractDatatype variant should be constructed based on given ISL constraints:
         // * If given list of constraints has any `fields` constraint then `AbstractDataType::Structure` needs to be constructed.
@@ -465,6 +980,11 @@ impl<L: Language + 'static> CodeGenerator<'_, L> {
         // * If given list of constraints has any `type` constraint except `type: list`, `type: struct` and `type: sexp`, then `AbstractDataType::Scalar` needs to be constructed.
         //      * The `base_type` for `Scalar` will be stored based on `type` constraint.
         // * If given list of constraints has any `valid_values` constraint which contains exclusively symbol values, then `AbstractDataType::Enum` needs to be constructed.
+        // * If given list of constraints has a `one_of` or `any_of` constraint, then `AbstractDataType::Union` needs to be constructed, one variant per member type.
+        // * If given list of constraints has a `type: struct` constraint but no `fields` constraint, then `AbstractDataType::Map` needs to be
+        //   constructed, since there are no field names to generate named struct members from.
+        // * If given list of constraints has an `ordered_elements` constraint, then `AbstractDataType::Tuple` needs to be constructed,
+        //   one positional element type per member of the constraint.
         // * All the other constraints except the above ones are not yet supported by code generator.
         let abstract_data_type = if constraints
             .iter()
@@ -491,26 +1011,97 @@ impl<L: Language + 'static> CodeGenerator<'_, L> {
             }
         } else if Self::contains_enum_constraints(constraints) {
             self.build_enum_from_constraints(constraints, code_gen_context, isl_type)?
+        } else if Self::contains_union_constraints(constraints) {
+            self.build_union_from_constraints(constraints, code_gen_context, isl_type)?
         } else if Self::contains_scalar_constraints(constraints) {
             if is_nested_type {
                 self.build_scalar_from_constraints(constraints, code_gen_context, isl_type)?
             } else {
                 self.build_wrapped_scalar_from_constraints(constraints, code_gen_context, isl_type)?
             }
+        } else if Self::contains_struct_type_constraint(constraints) {
+            self.build_map_from_constraints(constraints, code_gen_context, isl_type)?
+        } else if constraints
+            .iter()
+            .any(|it| matches!(it.constraint(), IslConstraintValue::OrderedElements(_)))
+        {
+            self.build_tuple_from_constraints(constraints, code_gen_context, isl_type)?
         } else {
-            todo!("Support for maps and tuples not implemented yet.")
+            return invalid_abstract_data_type_error(format!(
+                "Could not determine the abstract data type for type '{isl_type_name}': none of its constraints are supported by code generation yet.",
+            ));
         };
 
+        let name = self
+            .config
+            .as_ref()
+            .and_then(|config| config.type_name(isl_type_name))
+            .map(str::to_string)
+            .unwrap_or_else(|| {
+                // An explicit per-type override (just above) always wins; otherwise prefer a
+                // configured `Namer` type case/keyword escaping over this language's own default
+                // (`Case::UpperCamel`, then `Language::escape_reserved_word`).
+                let namer = self.config.as_ref().map(|config| config.namer());
+                let cased = namer
+                    .and_then(|namer| namer.type_case)
+                    .map(|case| case.convert(isl_type_name))
+                    .unwrap_or_else(|| isl_type_name.to_case(Case::UpperCamel));
+                match namer {
+                    Some(namer) => namer.escape_keyword(&cased, L::escape_reserved_word),
+                    None => L::escape_reserved_word(&cased),
+                }
+            });
+
+        let mut derives = L::default_derives();
+        let mut annotations = L::default_annotations();
+
+        // Infer which derive traits this type's fields support (mirroring bindgen's can-derive
+        // passes) instead of always emitting the same fixed set: `Copy`/`Default`/`Hash`/etc. are
+        // only legal when every field supports them too. For Rust this becomes the `#[derive(...)]`
+        // list, which already covers value semantics via `Debug`/`PartialEq`/`Eq`/`Hash` (narrowed
+        // the same way `Copy`/`Default` are, e.g. `Hash` is dropped for any type with an `f64`
+        // field). For Java, `partial_eq`/`hash` instead decide whether to generate `equals`/
+        // `hashCode` overrides; `toString` has no structural-contract caveat the way `equals`/
+        // `hashCode` do (unlike a `HashMap`/`byte[]` field, which can't honor Java's
+        // `equals`/`hashCode` contract, every field type can always be formatted), so it's
+        // generated unconditionally. All three are surfaced the same way other per-type
+        // decorations are (as `annotations`, consumed by the class template).
+        let inferred = self.derivability_for_abstract_data_type(&abstract_data_type);
+        match L::name().as_str() {
+            "rust" => derives.extend(inferred.rust_derives()),
+            "java" => {
+                if inferred.partial_eq {
+                    annotations.push("equals".to_string());
+                }
+                if inferred.hash {
+                    annotations.push("hashCode".to_string());
+                }
+                annotations.push("toString".to_string());
+            }
+            _ => {}
+        }
+
+        if let Some(config) = &self.config {
+            derives.extend(config.derives().iter().cloned());
+            annotations.extend(config.annotations().iter().cloned());
+        }
+
         let data_model_node = DataModelNode {
-            name: isl_type_name.to_case(Case::UpperCamel),
+            name,
             code_gen_type: Some(abstract_data_type.to_owned()),
             nested_types: code_gen_context.nested_types.to_owned(),
+            derives,
+            annotations,
         };
 
-        // TODO: verify the `occurs` value within a field, by default the fields are optional.
-        // add current data model node into the data model store
-        // verify if the field presence was provided as optional and set the type reference name as optional.
-        let type_name = abstract_data_type.fully_qualified_type_ref::<L>();
+        // add current data model node into the data model store, keyed by its own resolved type
+        // reference so sibling/later types whose fields refer to it can look its derivability up
+        // via `derivability_for`.
+        let type_name = abstract_data_type.fully_qualified_type_ref::<L>().ok_or(
+            invalid_abstract_data_type_raw_error(
+                "Can not determine fully qualified name for the data model",
+            ),
+        )?;
 
         self.data_model_store
             .insert(type_name, data_model_node.to_owned());
@@ -526,6 +1117,35 @@ impl<L: Language + 'static> CodeGenerator<'_, L> {
         Ok(data_model_node)
     }
 
+    /// First pass of the type-to-`AbstractDataType` resolution: collects which variants this
+    /// type's constraints imply into an [ImpliedAbstractDataTypeKinds] descriptor, without yet
+    /// deciding which one wins. The variant-selection chain in
+    /// [Self::convert_isl_type_def_to_data_model_node] is the second pass, run only after
+    /// [ImpliedAbstractDataTypeKinds::check_consistent] confirms the descriptor implies at most one
+    /// variant.
+    fn classify_abstract_data_type(constraints: &[IslConstraint]) -> ImpliedAbstractDataTypeKinds {
+        let mut kinds = ImpliedAbstractDataTypeKinds::default();
+        for constraint in constraints {
+            match constraint.constraint() {
+                IslConstraintValue::Fields(_, _) => kinds.fields = true,
+                IslConstraintValue::Element(_, _) => kinds.element = true,
+                IslConstraintValue::OrderedElements(_) => kinds.ordered_elements = true,
+                IslConstraintValue::OneOf(_) | IslConstraintValue::AnyOf(_) => kinds.union = true,
+                IslConstraintValue::Type(isl_type_ref) => {
+                    let name = isl_type_ref.name().as_str();
+                    if name != "list" && name != "sexp" && name != "struct" {
+                        kinds.scalar_type = Some(name.to_string());
+                    }
+                }
+                _ => {}
+            }
+        }
+        if Self::contains_enum_constraints(constraints) {
+            kinds.enum_ = true;
+        }
+        kinds
+    }
+
     /// Verifies if the given constraints contain a `type` constraint without any container type references. (e.g. `sexp`, `list`, `struct`)
     fn contains_scalar_constraints(constraints: &[IslConstraint]) -> bool {
         constraints.iter().any(|it| matches!(it.constraint(), IslConstraintValue::Type(isl_type_ref) if isl_type_ref.name().as_str() != "list"
@@ -533,20 +1153,45 @@ impl<L: Language + 'static> CodeGenerator<'_, L> {
                      && isl_type_ref.name().as_str() != "struct"))
     }
 
-    /// Verifies if the given constraints contain a `valid_values` constraint with only symbol values.
+    /// Verifies if the given constraints contain a `valid_values` constraint whose values are all
+    /// symbols and/or ints -- the value kinds `build_enum_from_constraints` knows how to turn into
+    /// enum variants.
     fn contains_enum_constraints(constraints: &[IslConstraint]) -> bool {
         constraints.iter().any(|it| {
             if let IslConstraintValue::ValidValues(valid_values) = it.constraint() {
-                valid_values
-                    .values()
-                    .iter()
-                    .all(|val| matches!(val, ValidValue::Element(Value::Symbol(_))))
+                valid_values.values().iter().all(|val| {
+                    matches!(
+                        val,
+                        ValidValue::Element(Value::Symbol(_)) | ValidValue::Element(Value::Int(_))
+                    )
+                })
             } else {
                 false
             }
         })
     }
 
+    /// Verifies if the given constraints contain a `one_of` or `any_of` constraint.
+    fn contains_union_constraints(constraints: &[IslConstraint]) -> bool {
+        constraints.iter().any(|it| {
+            matches!(
+                it.constraint(),
+                IslConstraintValue::OneOf(_) | IslConstraintValue::AnyOf(_)
+            )
+        })
+    }
+
+    /// Verifies if the given constraints contain a `type: struct` constraint. By the time this is
+    /// checked, the `fields` branch earlier in the dispatch chain has already failed to match, so
+    /// this only catches an open struct with no named fields at all -- every field of such a
+    /// struct is unnamed and gets collapsed into a single `AbstractDataType::Map` entry type
+    /// instead of a named struct member per field.
+    fn contains_struct_type_constraint(constraints: &[IslConstraint]) -> bool {
+        constraints.iter().any(|it| {
+            matches!(it.constraint(), IslConstraintValue::Type(isl_type_ref) if isl_type_ref.name().as_str() == "struct")
+        })
+    }
+
     fn render_generated_code(
         &mut self,
         type_name: &str,
@@ -554,6 +1199,18 @@ impl<L: Language + 'static> CodeGenerator<'_, L> {
         data_model_node: &DataModelNode,
         fully_qualified_name: &[NamespaceNode],
     ) -> CodeGenResult<()> {
+        if self.skip_rendering {
+            // The resolved `AbstractDataType` is already recorded in `data_model_store` by the
+            // caller; skip templating, file I/O, and plugins entirely.
+            return Ok(());
+        }
+
+        if let Some(abstract_data_type) = &data_model_node.code_gen_type {
+            for plugin in &self.plugins {
+                plugin.on_definition(type_name, abstract_data_type, context)?;
+            }
+        }
+
         // Add namespace to tera context
         let mut import_context = Context::new();
 
@@ -565,6 +1222,16 @@ impl<L: Language + 'static> CodeGenerator<'_, L> {
             "namespace",
             &fully_qualified_name[0..fully_qualified_name.len() - 1],
         );
+        // The other schema ids this type's schema imports from, so `import.templ` can render a
+        // module-qualified import statement (e.g. a Java `import`, a Rust `use`) for each one.
+        import_context.insert(
+            "imports",
+            &self
+                .schema_import_edges
+                .get(&self.current_schema_id)
+                .cloned()
+                .unwrap_or_default(),
+        );
 
         // Render or generate file for the template with the given context
         let template: &Template = &data_model_node.try_into()?;
@@ -573,10 +1240,53 @@ impl<L: Language + 'static> CodeGenerator<'_, L> {
         // We need to tune the `is_nested` flag to allow static classes being added inside a parent class
         context.insert("is_nested", &false);
 
+        // Lets the struct/class template pick the Ion encoding for the round-trippable
+        // `read`/`write` methods it emits for this type.
+        context.insert("format", &self.output_format);
+
+        // `None` unless the opt-in Ion Hash digest method was requested; lets the template decide
+        // whether to emit a digest method at all, and with which algorithm.
+        context.insert("ion_hash_algorithm", &self.ion_hash_algorithm);
+
+        // Lets the struct/class template decide whether to emit a constraint-checking
+        // constructor/reader path (see `with_validation`) alongside the default one. Like
+        // `ion_hash_algorithm`/`read_method_name`/`write_method_name`, no template here actually
+        // emits that path's body yet -- see the NOTE on `print_rust_code_gen_warnings` in `code.rs`.
+        context.insert("with_validation", &self.with_validation);
+
+        // The target language's conventional names for the (de)serialization methods the
+        // struct/class/scalar/sequence templates are expected to emit per `Language::read_method_name`/
+        // `Language::write_method_name` -- see the NOTE on `print_rust_code_gen_warnings` in
+        // `code.rs` for why no template here actually emits those methods' bodies yet.
+        context.insert("read_method_name", &L::read_method_name());
+        context.insert("write_method_name", &L::write_method_name());
+
         let rendered = self
             .tera
-            .render(&format!("{}.templ", L::template_name(template)), context)
-            .unwrap();
+            .render(&format!("{}.templ", L::template_name(template)), context)?;
+
+        // TypeScript also gets a `type_guard.templ` predicate per type (see
+        // `templates::typescript::TYPE_GUARD`); no other language registers this template, so
+        // this has to be conditional rather than a generic per-language render.
+        let rendered_type_guard = if L::name() == "typescript" {
+            self.tera.render("type_guard.templ", context)?
+        } else {
+            String::new()
+        };
+
+        // Rust emits a single shared `ion_generated_code.rs` file and renders `import.templ` once,
+        // up front, in `CodeGenerator::new` (Rust code generation has no per-type `--namespace`
+        // today, so one shared import block is enough). Java/Python/TypeScript instead emit one
+        // file per type, so each file needs its own import block rendered with this type's own
+        // `import_context` -- previously `import_context` was built above but never rendered,
+        // so non-Rust output never actually carried the namespace-qualified imports that
+        // `imported_target_type_for`/`namespace_for_imported_schema` compute.
+        let rendered_import = if L::name() == "rust" {
+            String::new()
+        } else {
+            self.tera.render("import.templ", &import_context)?
+        };
+
         let mut file_options = OpenOptions::new();
         if L::name() == "rust" {
             // since Rust code is generated into a single file, it needs append set to true.
@@ -592,7 +1302,12 @@ impl<L: Language + 'static> CodeGenerator<'_, L> {
                 L::file_name_for_type(type_name),
                 L::file_extension()
             )))?;
+        file.write_all(rendered_import.as_bytes())?;
         file.write_all(rendered.as_bytes())?;
+        file.write_all(rendered_type_guard.as_bytes())?;
+
+        self.run_plugins(data_model_node, fully_qualified_name)?;
+
         Ok(())
     }
 
@@ -616,11 +1331,29 @@ impl<L: Language + 'static> CodeGenerator<'_, L> {
         parent_code_gen_context: &mut CodeGenContext,
         type_name_suggestion: Option<&str>,
     ) -> CodeGenResult<Option<FullyQualifiedTypeReference>> {
+        if let IslTypeRef::TypeImport(isl_import_type, _) = isl_type_ref {
+            self.check_import_cycle(isl_import_type.id())?;
+        }
         Ok(match isl_type_ref {
             IslTypeRef::Named(name, _) => Self::target_type_for(field_presence, name),
             IslTypeRef::TypeImport(isl_import_type, _) => {
-                let name = isl_import_type.type_name();
-                Self::target_type_for(field_presence, name)
+                match self.resolve_imported_type(isl_import_type.id(), isl_import_type.type_name())
+                {
+                    Ok(type_ref) => Some(if field_presence == FieldPresence::Optional {
+                        L::target_type_as_optional(type_ref)
+                    } else {
+                        type_ref
+                    }),
+                    // The imported schema's types haven't been generated (yet, or at all) in this
+                    // run -- e.g. `generate_code_for_directory`'s plain directory-order traversal
+                    // visited this schema before the one it imports from -- so fall back to a
+                    // namespace guessed from the import's schema id rather than failing the build.
+                    Err(_) => Self::imported_target_type_for(
+                        field_presence,
+                        self.namespace_for_imported_schema(isl_import_type.id()),
+                        isl_import_type.type_name(),
+                    ),
+                }
             }
             IslTypeRef::Anonymous(type_def, _) => {
                 let name = type_name_suggestion.map(|t| t.to_string()).ok_or(
@@ -655,6 +1388,16 @@ impl<L: Language + 'static> CodeGenerator<'_, L> {
                 type_name: vec![NamespaceNode::Type(type_name.to_string())],
                 parameters: vec![],
             })
+            .map(|t| {
+                // Nullable-wrap first (the value itself may be `null`), then optional-wrap on top
+                // (the field may be absent entirely) — the two compose rather than one superseding
+                // the other, e.g. an optional `$int` field is `Option<Option<i64>>` in Rust.
+                if schema_type.is_nullable() {
+                    L::target_type_as_nullable(t)
+                } else {
+                    t
+                }
+            })
             .map(|t| {
                 if field_presence == FieldPresence::Optional {
                     L::target_type_as_optional(t)
@@ -664,41 +1407,540 @@ impl<L: Language + 'static> CodeGenerator<'_, L> {
             })
     }
 
-    /// Returns error if duplicate constraints are present based `found_constraint` flag
-    fn handle_duplicate_constraint(
+    /// Returns true if `isl_type_ref` names one of the `$`-prefixed nullable built-in types (e.g.
+    /// `$int`), meaning the field's value itself may be `null` in addition to being absent.
+    fn is_nullable_type_ref(isl_type_ref: &IslTypeRef) -> bool {
+        match isl_type_ref {
+            IslTypeRef::Named(name, _) => IonSchemaType::from(name).is_nullable(),
+            IslTypeRef::TypeImport(_, _) | IslTypeRef::Anonymous(_, _) => false,
+        }
+    }
+
+    /// Records that `isl_type_name`, a top-level type in the schema currently being generated
+    /// (`self.current_schema_id`), resolved to `type_ref`, so `resolve_imported_type` can later
+    /// look up its real generated name. Errors deterministically if `type_ref` is already claimed
+    /// by a different `(schema_id, type_name)` pair -- e.g. two schemas whose paths happen to
+    /// snake_case to the same namespace -- instead of letting one silently shadow the other.
+    fn register_imported_type_symbol(
         &mut self,
-        found_constraint: bool,
-        constraint_name: &str,
-        isl_type: &IslTypeRef,
-        field_presence: FieldPresence,
-        code_gen_context: &mut CodeGenContext,
-        type_name_suggestion: Option<&str>,
-    ) -> CodeGenResult<FullyQualifiedTypeReference> {
-        if found_constraint {
+        isl_type_name: &str,
+        type_ref: FullyQualifiedTypeReference,
+    ) -> CodeGenResult<()> {
+        let key = (self.current_schema_id.clone(), isl_type_name.to_string());
+        if let Some((existing_schema_id, existing_type_name)) = self
+            .imported_type_symbols
+            .iter()
+            .find(|(existing_key, existing_type_ref)| {
+                **existing_key != key && **existing_type_ref == type_ref
+            })
+            .map(|(existing_key, _)| existing_key.clone())
+        {
             return invalid_abstract_data_type_error(format!(
-                "Multiple `{}` constraints in the type definitions are not supported in code generation as it can lead to conflicting types.", constraint_name
+                "generated type name collision: '{existing_schema_id}#{existing_type_name}' and \
+                 '{}#{isl_type_name}' both resolve to '{}'",
+                self.current_schema_id,
+                type_ref.string_representation::<L>(),
             ));
         }
+        self.imported_type_symbols.insert(key, type_ref);
+        Ok(())
+    }
 
-        self.fully_qualified_type_ref_name(
-            isl_type,
-            field_presence,
-            code_gen_context,
-            type_name_suggestion,
-        )?
-        .ok_or(invalid_abstract_data_type_raw_error(format!(
-            "Could not determine `FullQualifiedTypeReference` for type {:?}",
-            isl_type
-        )))
+    /// Resolves an import to the fully qualified name the referenced type was *actually*
+    /// generated under, looked up by `(schema_id, type_name)` in `imported_type_symbols` instead
+    /// of guessed from `schema_id`'s path the way `namespace_for_imported_schema`/
+    /// `imported_target_type_for` do. Returns an "unresolved import" error when that schema's
+    /// types haven't been generated (yet, or at all) in this run.
+    fn resolve_imported_type(
+        &self,
+        schema_id: &str,
+        type_name: &str,
+    ) -> CodeGenResult<FullyQualifiedTypeReference> {
+        self.imported_type_symbols
+            .get(&(schema_id.to_string(), type_name.to_string()))
+            .cloned()
+            .ok_or_else(|| {
+                invalid_abstract_data_type_raw_error(format!(
+                    "unresolved import: type '{type_name}' from schema '{schema_id}' was not \
+                     generated (yet) in this run"
+                ))
+            })
     }
 
-    /// Builds `AbstractDataType::Structure` from the given constraints.
-    /// e.g. for a given type definition as below:
-    /// ```
-    /// type::{
-    ///   name: Foo,
-    ///   type: struct,
-    ///   fields: {
+    /// Converts an imported type's originating schema id (e.g. `common/foo.isl`, analogous to
+    /// ion-schema-rust's `get_imported_type` resolving an `import`'s `id`) into the sequence of
+    /// namespace segments that schema's types live under, so a field referencing an imported
+    /// type can be qualified with it instead of assuming it was defined in the current schema.
+    /// Only consulted as a fallback, when `resolve_imported_type` can't find a real generated name
+    /// for the import yet. Each namespace segment is cased with a configured `Namer`
+    /// `namespace_case` override if present, otherwise with this method's own default
+    /// (`Case::Snake`).
+    fn namespace_for_imported_schema(&self, schema_id: &str) -> Vec<NamespaceNode> {
+        let namer = self.config.as_ref().map(|config| config.namer());
+        schema_id
+            .trim_end_matches(".isl")
+            .split(['/', '\\'])
+            .filter(|segment| !segment.is_empty())
+            .map(|segment| {
+                let cased = namer
+                    .and_then(|namer| namer.namespace_case)
+                    .map(|case| case.convert(segment))
+                    .unwrap_or_else(|| segment.to_case(Case::Snake));
+                NamespaceNode::Package(cased)
+            })
+            .collect()
+    }
+
+    /// Returns the target type for a type imported from another schema document, qualified with
+    /// `imported_namespace` (a `use`/`mod` path in Rust, a package-qualified import in Java)
+    /// rather than the current schema's namespace, analogous to ion-schema-rust's
+    /// `get_imported_type`/`get_built_in_or_defined_type` lookups that follow `import`
+    /// statements to their originating schema. Only consulted as a fallback, when
+    /// `resolve_imported_type` can't find a real generated name for the import yet.
+    fn imported_target_type_for(
+        field_presence: FieldPresence,
+        imported_namespace: Vec<NamespaceNode>,
+        name: &str,
+    ) -> Option<FullyQualifiedTypeReference> {
+        let mut type_name = imported_namespace;
+        type_name.push(NamespaceNode::Type(L::escape_reserved_word(
+            &name.to_case(Case::UpperCamel),
+        )));
+        let type_reference = FullyQualifiedTypeReference {
+            type_name,
+            parameters: vec![],
+        };
+        Some(if field_presence == FieldPresence::Optional {
+            L::target_type_as_optional(type_reference)
+        } else {
+            type_reference
+        })
+    }
+
+    /// Records that the schema currently being generated (`self.current_schema_id`) imports a type
+    /// from `imported_schema_id`, and returns an error rather than recording the edge if doing so
+    /// would close an import cycle (e.g. schema `a` importing from `b`, which itself imports back
+    /// from `a`, directly or transitively). Import resolution here is a pure name computation
+    /// rather than a recursive re-generation of the imported schema, so there's no actual infinite
+    /// loop to hit today -- this exists so a cycle is reported as a clear error instead of silently
+    /// producing a type name that can never resolve, and so it keeps reporting correctly if import
+    /// resolution grows a recursive code path later.
+    fn check_import_cycle(&mut self, imported_schema_id: &str) -> CodeGenResult<()> {
+        if self.current_schema_id == imported_schema_id {
+            return invalid_abstract_data_type_error(format!(
+                "schema '{}' imports a type from itself",
+                self.current_schema_id
+            ));
+        }
+        if Self::schema_reaches(
+            &self.schema_import_edges,
+            imported_schema_id,
+            &self.current_schema_id,
+        ) {
+            return invalid_abstract_data_type_error(format!(
+                "import cycle detected: '{}' imports '{}', which already (transitively) imports '{}'",
+                self.current_schema_id, imported_schema_id, self.current_schema_id
+            ));
+        }
+        self.schema_import_edges
+            .entry(self.current_schema_id.clone())
+            .or_default()
+            .insert(imported_schema_id.to_string());
+        Ok(())
+    }
+
+    /// Returns true if `to` is reachable from `from` by following `edges` ("schema id imports from
+    /// schema id"), used to check whether adding a new `from -> to` edge would close a cycle.
+    fn schema_reaches(edges: &HashMap<String, BTreeSet<String>>, from: &str, to: &str) -> bool {
+        let mut visited = BTreeSet::new();
+        let mut stack = vec![from.to_string()];
+        while let Some(schema_id) = stack.pop() {
+            if schema_id == to {
+                return true;
+            }
+            if !visited.insert(schema_id.clone()) {
+                continue;
+            }
+            if let Some(imports) = edges.get(&schema_id) {
+                stack.extend(imports.iter().cloned());
+            }
+        }
+        false
+    }
+
+    /// Rewrites every `Structure` field / `Tuple` element in `data_model_store` that closes a
+    /// reference cycle (a self-referential or mutually-recursive ISL type) so the generated type
+    /// has finite size, e.g. `struct Node { child: Node }` becomes `struct Node { child: Box<Node> }`
+    /// in Rust. Run once per schema, after every type it defines (top-level and nested) has been
+    /// resolved into `data_model_store` -- cycle membership can only be decided once the full
+    /// graph is known, since an edge to a type that hasn't been resolved yet looks identical to
+    /// one that never will be.
+    fn box_cyclic_fields(&mut self) {
+        let flagged = self.cyclic_field_slots();
+        if flagged.is_empty() {
+            return;
+        }
+        for type_ref in self.data_model_store.keys().cloned().collect::<Vec<_>>() {
+            if let Some(mut node) = self.data_model_store.remove(&type_ref) {
+                Self::indirect_flagged_fields(&mut node, &flagged);
+                self.data_model_store.insert(type_ref, node);
+            }
+        }
+    }
+
+    /// Finds the `(owner type, field)` pairs that need rewriting for [Self::box_cyclic_fields].
+    ///
+    /// Builds a directed graph whose nodes are every `FullyQualifiedTypeReference` in
+    /// `data_model_store` and whose edges are `Structure` fields / `Tuple` elements held *by
+    /// value* -- i.e. ones whose `type_reference` has no parameters. A field wrapped in
+    /// `Option`/`Vec`/a map (all of which carry a parameterized `type_reference`) already has its
+    /// own heap indirection and can't make the generated type infinite-sized, so it's excluded.
+    /// A DFS with an on-stack marker over this graph finds the back edges that close a cycle,
+    /// whether a direct self-reference or one that only closes transitively through other types.
+    fn cyclic_field_slots(&self) -> HashSet<(FullyQualifiedTypeReference, FieldSlot)> {
+        let mut edges: HashMap<
+            FullyQualifiedTypeReference,
+            Vec<(FieldSlot, FullyQualifiedTypeReference)>,
+        > = HashMap::new();
+        for (type_ref, node) in &self.data_model_store {
+            let mut node_edges = Vec::new();
+            match &node.code_gen_type {
+                Some(AbstractDataType::Structure(structure)) => {
+                    // `HashMap` iteration order isn't deterministic; sort so which field (among
+                    // several that close the same cycle) gets flagged stays stable across runs.
+                    let mut fields: Vec<_> = structure.fields.iter().collect();
+                    fields.sort_by(|(a, _), (b, _)| a.cmp(b));
+                    for (field_name, field) in fields {
+                        if field.type_reference.parameters.is_empty() {
+                            node_edges.push((
+                                FieldSlot::Field(field_name.clone()),
+                                field.type_reference.clone(),
+                            ));
+                        }
+                    }
+                }
+                Some(AbstractDataType::Tuple(tuple)) => {
+                    for (index, element_type) in tuple.element_types.iter().enumerate() {
+                        if element_type.parameters.is_empty() {
+                            node_edges.push((FieldSlot::Element(index), element_type.clone()));
+                        }
+                    }
+                }
+                _ => {}
+            }
+            edges.insert(type_ref.clone(), node_edges);
+        }
+
+        // `HashMap` iteration order is randomized per-process; sort DFS start points by their
+        // string representation so which edge of a cycle gets flagged as the back edge -- and so
+        // regenerating the same schema twice -- stays stable.
+        let mut starts: Vec<_> = edges.keys().cloned().collect();
+        starts.sort_by_key(|type_ref| type_ref.string_representation::<L>());
+
+        let mut flagged = HashSet::new();
+        let mut visited = HashSet::new();
+        for start in starts {
+            let mut on_stack = HashSet::new();
+            Self::find_cyclic_edges(&start, &edges, &mut visited, &mut on_stack, &mut flagged);
+        }
+        flagged
+    }
+
+    fn find_cyclic_edges(
+        type_ref: &FullyQualifiedTypeReference,
+        edges: &HashMap<FullyQualifiedTypeReference, Vec<(FieldSlot, FullyQualifiedTypeReference)>>,
+        visited: &mut HashSet<FullyQualifiedTypeReference>,
+        on_stack: &mut HashSet<FullyQualifiedTypeReference>,
+        flagged: &mut HashSet<(FullyQualifiedTypeReference, FieldSlot)>,
+    ) {
+        if !visited.insert(type_ref.clone()) {
+            return;
+        }
+        on_stack.insert(type_ref.clone());
+
+        if let Some(node_edges) = edges.get(type_ref) {
+            for (slot, target) in node_edges {
+                if on_stack.contains(target) {
+                    flagged.insert((type_ref.clone(), slot.clone()));
+                } else if !visited.contains(target) {
+                    Self::find_cyclic_edges(target, edges, visited, on_stack, flagged);
+                }
+            }
+        }
+
+        on_stack.remove(type_ref);
+    }
+
+    /// Applies `flagged` (from [Self::cyclic_field_slots]) to `node` and, recursively, to its
+    /// `nested_types` -- each of which is also a `data_model_store` entry in its own right, so it
+    /// needs its own fields checked against `flagged` rather than only the top-level `node`'s.
+    fn indirect_flagged_fields(
+        node: &mut DataModelNode,
+        flagged: &HashSet<(FullyQualifiedTypeReference, FieldSlot)>,
+    ) {
+        if let Some(owner_ref) = node
+            .code_gen_type
+            .as_ref()
+            .and_then(|t| t.fully_qualified_type_ref::<L>())
+        {
+            match &mut node.code_gen_type {
+                Some(AbstractDataType::Structure(structure)) => {
+                    for (field_name, field) in structure.fields.iter_mut() {
+                        if flagged
+                            .contains(&(owner_ref.clone(), FieldSlot::Field(field_name.clone())))
+                        {
+                            field.type_reference =
+                                L::target_type_as_boxed(field.type_reference.clone());
+                        }
+                    }
+                }
+                Some(AbstractDataType::Tuple(tuple)) => {
+                    for (index, element_type) in tuple.element_types.iter_mut().enumerate() {
+                        if flagged.contains(&(owner_ref.clone(), FieldSlot::Element(index))) {
+                            *element_type = L::target_type_as_boxed(element_type.clone());
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        for nested in node.nested_types.iter_mut() {
+            Self::indirect_flagged_fields(nested, flagged);
+        }
+    }
+
+    /// The derive traits legal for a built-in scalar target type (see `Language::target_type`),
+    /// the base case for [Self::derivability_for]. Only `partial_eq`/`hash` are meaningful outside
+    /// Rust, where they stand in for a generated `equals`/`hashCode` override.
+    fn built_in_derivability(type_name: &str) -> Derivability {
+        match L::name().as_str() {
+            "rust" => match type_name {
+                // Plain inline value types: every one of these traits applies.
+                "i64" | "bool" | "rust_decimal::Decimal" => Derivability::all(),
+                // Heap-allocated, so never `Copy`.
+                "String" | "Vec<u8>" => Derivability {
+                    copy: false,
+                    ..Derivability::all()
+                },
+                // `f64` doesn't implement `Hash` (`NaN` breaks the reflexivity `Hash` relies on).
+                "f64" => Derivability {
+                    hash: false,
+                    ..Derivability::all()
+                },
+                // Neither implements `Copy`, `Default`, or `Hash`.
+                "ion_rs::Timestamp" | "ion_rs::Element" => Derivability {
+                    copy: false,
+                    default: false,
+                    hash: false,
+                    ..Derivability::all()
+                },
+                _ => Derivability::none(),
+            },
+            "java" => match type_name {
+                // Primitives, `String`, and `BigDecimal`/`Timestamp` all have well-defined
+                // structural `equals`/`hashCode` (boxed primitives included, despite `double`
+                // boxing to `Double`, whose `equals`/`hashCode` treat `NaN` consistently).
+                "int"
+                | "boolean"
+                | "double"
+                | "String"
+                | "java.math.BigDecimal"
+                | "com.amazon.ion.Timestamp" => Derivability {
+                    partial_eq: true,
+                    hash: true,
+                    ..Derivability::none()
+                },
+                // `byte[]`'s `equals`/`hashCode` are identity-based, which would be wrong to rely
+                // on; `IonValue` doesn't guarantee a structural contract either.
+                "byte[]" | "com.amazon.ion.IonValue" => Derivability::none(),
+                _ => Derivability::none(),
+            },
+            _ => Derivability::none(),
+        }
+    }
+
+    /// Computes which derive traits are legal for `type_ref` -- mirroring bindgen's can-derive
+    /// passes -- by walking into `Vec`/`Option`/`Box`/`HashMap` parameters, and for a user-defined
+    /// type, narrowing across its fields/variants/element type. Memoized in `derivability_cache`;
+    /// a type that's (transitively) still being analyzed (i.e. self-referential) is conservatively
+    /// treated as only `Clone`/`Debug`-able, since Rust requires `Box`-ing a recursive field
+    /// anyway, which already rules out `Copy`/`Default`/`Hash`/`PartialEq` without more care than
+    /// this pass attempts. Only Rust's `Vec`/`Option`/`Box`/`HashMap` wrapper type names are
+    /// recognized here; other languages' equivalent wrappers (e.g. Java's `java.util.ArrayList`)
+    /// fall through to the user-defined-type branch, which conservatively reports
+    /// [Derivability::none] for them since they were never themselves inserted into
+    /// `data_model_store`.
+    fn derivability_for(&mut self, type_ref: &FullyQualifiedTypeReference) -> Derivability {
+        if let Some(cached) = self.derivability_cache.get(type_ref) {
+            return *cached;
+        }
+        if self.derivability_in_progress.contains(type_ref) {
+            return Derivability {
+                copy: false,
+                clone: true,
+                debug: true,
+                default: false,
+                partial_eq: false,
+                hash: false,
+            };
+        }
+
+        let type_name = type_ref.type_name.join("::");
+        let derivability = match type_name.as_str() {
+            "Vec" => {
+                let mut d = self.derivability_for_parameter(type_ref);
+                d.copy = false;
+                d.default = true; // `Vec::default()` always exists, regardless of element type.
+                d
+            }
+            "Option" => {
+                let mut d = self.derivability_for_parameter(type_ref);
+                d.default = true; // `Option::default()` is `None`, regardless of `T`.
+                d
+            }
+            "Box" => {
+                let mut d = self.derivability_for_parameter(type_ref);
+                d.copy = false;
+                d.default = false; // `Box<T>` has no blanket `Default` impl even when `T: Default`.
+                d
+            }
+            "HashMap" => {
+                let mut d = Derivability::all();
+                for parameter in &type_ref.parameters {
+                    d.narrow_by(self.derivability_for(parameter));
+                }
+                d.copy = false;
+                d.hash = false; // `HashMap` doesn't implement `Hash`.
+                d.default = true; // `HashMap::default()` always exists.
+                d
+            }
+            _ if L::is_built_in_type(type_name.clone()) => Self::built_in_derivability(&type_name),
+            _ => {
+                self.derivability_in_progress.insert(type_ref.to_owned());
+                let result = self
+                    .data_model_store
+                    .get(type_ref)
+                    .and_then(|node| node.code_gen_type.clone())
+                    .map(|abstract_data_type| {
+                        self.derivability_for_abstract_data_type(&abstract_data_type)
+                    })
+                    .unwrap_or_else(Derivability::none);
+                self.derivability_in_progress.remove(type_ref);
+                result
+            }
+        };
+
+        self.derivability_cache
+            .insert(type_ref.to_owned(), derivability);
+        derivability
+    }
+
+    /// Looks up the derivability of a single-parameter wrapper type's (`Vec<T>`/`Option<T>`/
+    /// `Box<T>`) type parameter, defaulting to fully derivable if it somehow has none.
+    fn derivability_for_parameter(
+        &mut self,
+        type_ref: &FullyQualifiedTypeReference,
+    ) -> Derivability {
+        match type_ref.parameters.first() {
+            Some(parameter) => self.derivability_for(&parameter.to_owned()),
+            None => Derivability::all(),
+        }
+    }
+
+    /// Narrows a resolved user-defined type's derivability across its own fields/variants/element
+    /// type, per its [AbstractDataType] shape.
+    fn derivability_for_abstract_data_type(
+        &mut self,
+        abstract_data_type: &AbstractDataType,
+    ) -> Derivability {
+        match abstract_data_type.clone() {
+            AbstractDataType::Structure(structure) => {
+                let mut d = Derivability::all();
+                for field in structure.fields.values() {
+                    d.narrow_by(self.derivability_for(&field.type_reference.to_owned()));
+                }
+                d
+            }
+            AbstractDataType::Union(union) => {
+                let mut d = Derivability::all();
+                for (_, type_ref) in &union.variants {
+                    d.narrow_by(self.derivability_for(&type_ref.to_owned()));
+                }
+                d
+            }
+            AbstractDataType::Sequence(seq) => self.derivability_for(&seq.element_type.to_owned()),
+            AbstractDataType::WrappedSequence(seq) => {
+                self.derivability_for(&seq.element_type.to_owned())
+            }
+            AbstractDataType::WrappedScalar(scalar) => {
+                self.derivability_for(&scalar.base_type.to_owned())
+            }
+            AbstractDataType::Scalar(scalar) => self.derivability_for(&scalar.base_type.to_owned()),
+            AbstractDataType::Map(map) => {
+                let mut d = Derivability::all();
+                d.narrow_by(self.derivability_for(&map.key_type.to_owned()));
+                d.narrow_by(self.derivability_for(&map.value_type.to_owned()));
+                d.copy = false;
+                d.hash = false;
+                d.default = true;
+                d
+            }
+            AbstractDataType::Tuple(tuple) => {
+                let mut d = Derivability::all();
+                for element_type in &tuple.element_types {
+                    d.narrow_by(self.derivability_for(&element_type.to_owned()));
+                }
+                d
+            }
+            // An enum's variants are plain names plus an optional `i64` discriminant -- no
+            // nested type reference narrows its derivability below the default.
+            AbstractDataType::Enum(_) => Derivability::all(),
+        }
+    }
+
+    /// The simple (last-segment) name of the type currently being built, for use in
+    /// `CodeGenError::DuplicateConstraint`/`ConflictingConstraints` messages.
+    fn current_type_name(&self) -> String {
+        self.current_type_fully_qualified_name
+            .last()
+            .map(|node| node.name().to_string())
+            .unwrap_or_default()
+    }
+
+    /// Returns error if duplicate constraints are present based `found_constraint` flag
+    fn handle_duplicate_constraint(
+        &mut self,
+        found_constraint: bool,
+        constraint_name: &str,
+        isl_type: &IslTypeRef,
+        field_presence: FieldPresence,
+        code_gen_context: &mut CodeGenContext,
+        type_name_suggestion: Option<&str>,
+    ) -> CodeGenResult<FullyQualifiedTypeReference> {
+        if found_constraint {
+            return duplicate_constraint_error(self.current_type_name(), constraint_name);
+        }
+
+        self.fully_qualified_type_ref_name(
+            isl_type,
+            field_presence,
+            code_gen_context,
+            type_name_suggestion,
+        )?
+        .ok_or(invalid_abstract_data_type_raw_error(format!(
+            "Could not determine `FullQualifiedTypeReference` for type {:?}",
+            isl_type
+        )))
+    }
+
+    /// Builds `AbstractDataType::Structure` from the given constraints.
+    /// e.g. for a given type definition as below:
+    /// ```
+    /// type::{
+    ///   name: Foo,
+    ///   type: struct,
+    ///   fields: {
     ///      a: string,
     ///      b: int,
     ///   }
@@ -710,8 +1952,8 @@ impl<L: Language + 'static> CodeGenerator<'_, L> {
     ///  Structure {
     ///     name: vec!["org", "example", "Foo"], // assuming the namespace is `org.example`
     ///     fields: {
-    ///         a: FieldReference { FullyQualifiedTypeReference { type_name: vec!["String"], parameters: vec![] }, FieldPresence::Optional },
-    ///         b: FieldReference { FullyQualifiedTypeReference { type_name: vec!["int"], parameters: vec![] }, FieldPresence::Optional },
+    ///         a: FieldReference { type_reference: FullyQualifiedTypeReference { type_name: vec!["String"], parameters: vec![] }, presence: FieldPresence::Optional, occurs: UsizeRange::zero_or_one(), original_name: "a", generated_name: "a", rename: None, default: None, skip: false, nullable: false },
+    ///         b: FieldReference { type_reference: FullyQualifiedTypeReference { type_name: vec!["int"], parameters: vec![] }, presence: FieldPresence::Optional, occurs: UsizeRange::zero_or_one(), original_name: "b", generated_name: "b", rename: None, default: None, skip: false, nullable: false },
     ///     }, // HashMap with fields defined through `fields` constraint above
     ///     doc_comment: None // There is no doc comment defined in above ISL type def
     ///     source: IslType {name: "foo", .. } // Represents the `IslType` that is getting converted to `AbstractDataType`
@@ -735,27 +1977,86 @@ impl<L: Language + 'static> CodeGenerator<'_, L> {
                     // TODO: Check for `closed` annotation on fields and based on that return error while reading if there are extra fields.
                     let mut fields = HashMap::new();
                     for (name, value) in struct_fields.iter() {
-                        let field_presence = if value.occurs().inclusive_endpoints() == (0, 1) {
-                            FieldPresence::Optional
-                        } else if value.occurs().inclusive_endpoints() == (1, 1) {
+                        let occurs = value.occurs();
+                        let (min, max) = occurs.inclusive_endpoints();
+                        // `[0,1]`/`[1,1]` map to a scalar field wrapped as `Option`/plain per
+                        // `FieldPresence` as before; any range whose max is greater than one
+                        // repeats, so the field is generated as a collection instead (the full
+                        // range is preserved on `FieldReference::occurs` either way so the
+                        // generated reader can still enforce `min`/`max` cardinality).
+                        let field_presence = if max <= 1 {
+                            if min == 0 {
+                                FieldPresence::Optional
+                            } else {
+                                FieldPresence::Required
+                            }
+                        } else {
+                            FieldPresence::Required
+                        };
+                        // An optional field with a configured default is generated as a plain
+                        // typed member initialized to that default when absent, instead of being
+                        // wrapped in `Option`/`Optional`; a required field's default (if any) is
+                        // unused, since it's never absent in the first place.
+                        let default = self
+                            .config
+                            .as_ref()
+                            .and_then(|config| config.field_default(name))
+                            .map(str::to_string);
+                        let type_wrapping_presence = if default.is_some() {
                             FieldPresence::Required
                         } else {
-                            // TODO: change the field presence based on occurs constraint
-                            return invalid_abstract_data_type_error("Fields with occurs as a range aren't supported with code generation");
+                            field_presence
                         };
                         let type_name = self
                             .fully_qualified_type_ref_name(
                                 value.type_reference(),
-                                field_presence,
+                                type_wrapping_presence,
                                 code_gen_context,
                                 Some(name),
                             )?
                             .ok_or(invalid_abstract_data_type_raw_error(
                                 "Given type doesn't have a name",
                             ))?;
+                        let type_name = if max > 1 {
+                            L::target_type_as_sequence(type_name)
+                        } else {
+                            type_name
+                        };
+                        let rename = self
+                            .config
+                            .as_ref()
+                            .and_then(|config| config.field_name(name))
+                            .map(str::to_string);
+                        // An explicit per-field override always wins; otherwise prefer a
+                        // configured `Namer` field case/keyword escaping over this language's own
+                        // default rename rule (e.g. snake_case for Rust, camelCase for Java)
+                        // applied to the ISL field name.
+                        let generated_name = rename.clone().unwrap_or_else(|| {
+                            let namer = self.config.as_ref().map(|config| config.namer());
+                            let cased = namer
+                                .and_then(|namer| namer.field_case)
+                                .map(|case| case.convert(name))
+                                .unwrap_or_else(|| L::field_name_case().convert(name));
+                            match namer {
+                                Some(namer) => {
+                                    namer.escape_keyword(&cased, L::escape_reserved_word)
+                                }
+                                None => L::escape_reserved_word(&cased),
+                            }
+                        });
                         fields.insert(
                             name.to_string(),
-                            FieldReference(type_name.to_owned(), field_presence),
+                            FieldReference {
+                                type_reference: type_name.to_owned(),
+                                presence: field_presence,
+                                occurs: occurs.to_owned(),
+                                original_name: name.to_string(),
+                                generated_name,
+                                rename,
+                                default,
+                                skip: false,
+                                nullable: Self::is_nullable_type_ref(value.type_reference()),
+                            },
                         );
                     }
                     // unwrap here is safe as the `current_abstract_data_type_builder` will either be initialized with default implementation
@@ -766,9 +2067,14 @@ impl<L: Language + 'static> CodeGenerator<'_, L> {
                     // by default fields aren't closed
                     structure_builder.is_closed(false);
                 }
+                IslConstraintValue::ContainerLength(range) => {
+                    structure_builder.container_length(Some(LengthBound::from(range.to_owned())));
+                }
                 _ => {
-                    return invalid_abstract_data_type_error(
-                        "Could not determine the abstract data type due to conflicting constraints",
+                    return conflicting_constraints_error(
+                        self.current_type_name(),
+                        "`fields`, `type`, or `container_length` constraint",
+                        format!("{:?}", constraint.constraint()),
                     )
                 }
             }
@@ -777,7 +2083,10 @@ impl<L: Language + 'static> CodeGenerator<'_, L> {
         Ok(AbstractDataType::Structure(structure_builder.build()?))
     }
 
-    /// Builds `AbstractDataType::Enum` from the given constraints.
+    /// Builds `AbstractDataType::Enum` from the given constraints. `valid_values` may be all
+    /// `symbol`s, all `int`s, or a mix of both: each `symbol` becomes a variant named after its
+    /// own text, and each `int` becomes a variant named and discriminated per
+    /// `enum_variant_name_for_int` (e.g. `3` -> `V3`, `-1` -> `Neg1`).
     /// e.g. for a given type definition as below:
     /// ```
     /// type::{
@@ -791,12 +2100,11 @@ impl<L: Language + 'static> CodeGenerator<'_, L> {
     /// AbstractDataType::Enum(
     ///  Enum {
     ///     name: vec!["org", "example", "Foo"], // assuming the namespace is `org.example`
-    ///     variants: HashSet::from_iter(
-    ///                vec![
-    ///                 "foo",
-    ///                 "bar",
-    ///                 "baz"
-    ///               ].iter()) // Represents enum variants
+    ///     variants: vec![
+    ///                 ("foo".to_string(), None),
+    ///                 ("bar".to_string(), None),
+    ///                 ("baz".to_string(), None),
+    ///               ], // Represents enum variants
     ///     doc_comment: None // There is no doc comment defined in above ISL type def
     ///     source: IslType {name: "foo", .. } // Represents the `IslType` that is getting converted to `AbstractDataType`
     ///  }
@@ -821,22 +2129,36 @@ impl<L: Language + 'static> CodeGenerator<'_, L> {
                         .values()
                         .iter()
                         .map(|v| match v {
-                            ValidValue::Element(Value::Symbol(symbol_val) ) => {
-                                    symbol_val.text().map(|s| s.to_string()).ok_or(invalid_abstract_data_type_raw_error(
+                            ValidValue::Element(Value::Symbol(symbol_val)) => {
+                                let name = symbol_val.text().map(|s| s.to_string()).ok_or(
+                                    invalid_abstract_data_type_raw_error(
                                         "Could not determine enum variant name",
-                                    ))
-                                }
+                                    ),
+                                )?;
+                                Ok((name, None))
+                            }
+                            ValidValue::Element(Value::Int(int_val)) => {
+                                let value = int_val.as_i64().ok_or_else(|| {
+                                    invalid_abstract_data_type_raw_error(
+                                        "enum `valid_values` integers must fit in a 64-bit signed integer",
+                                    )
+                                })?;
+                                Ok((
+                                    Self::enum_variant_name_for_int(value),
+                                    Some(EnumVariantValue::Int(value)),
+                                ))
+                            }
                             _ => invalid_abstract_data_type_error(
-                                "Only `valid_values` constraint with values of type `symbol` are supported yet!"
+                                "Only `valid_values` constraint with values of type `symbol` or `int` are supported yet!"
                             ),
                         })
-                        .collect::<CodeGenResult<Vec<String>>>()?;
-                    enum_builder.variants(BTreeSet::from_iter(valid_values));
+                        .collect::<CodeGenResult<Vec<(String, Option<EnumVariantValue>)>>>()?;
+                    enum_builder.variants(valid_values);
                 }
                 IslConstraintValue::Type(isl_type_ref) => {
-                    if isl_type_ref.name() != "symbol" {
+                    if isl_type_ref.name() != "symbol" && isl_type_ref.name() != "int" {
                         return invalid_abstract_data_type_error(
-                            "Only `valid_values` constraint with values of type `symbol` are supported yet!"
+                            "Only `valid_values` constraint with values of type `symbol` or `int` are supported yet!"
                         );
                     }
 
@@ -851,8 +2173,10 @@ impl<L: Language + 'static> CodeGenerator<'_, L> {
                     found_base_type = true;
                 }
                 _ => {
-                    return invalid_abstract_data_type_error(
-                        "Could not determine the abstract data type due to conflicting constraints",
+                    return conflicting_constraints_error(
+                        self.current_type_name(),
+                        "`valid_values` or `type` constraint",
+                        format!("{:?}", constraint.constraint()),
                     )
                 }
             }
@@ -861,6 +2185,276 @@ impl<L: Language + 'static> CodeGenerator<'_, L> {
         Ok(AbstractDataType::Enum(enum_builder.build()?))
     }
 
+    /// Derives a legal, stable identifier for an enum variant backed by an integer `valid_values`
+    /// entry -- no raw integer literal is a legal Rust/Java identifier on its own, so every one is
+    /// sanitized: a negative value becomes `NegN` (e.g. `-5` -> `Neg5`), a non-negative value
+    /// becomes `VN` (e.g. `3` -> `V3`).
+    fn enum_variant_name_for_int(value: i64) -> String {
+        if value.is_negative() {
+            format!("Neg{}", value.unsigned_abs())
+        } else {
+            format!("V{value}")
+        }
+    }
+
+    /// Builds `AbstractDataType::Union` from the given constraints.
+    /// e.g. for a given type definition as below:
+    /// ```
+    /// type::{
+    ///   name: Foo,
+    ///   one_of: [int, string]
+    /// }
+    /// ```
+    /// This method builds `AbstractDataType` as following:
+    /// ```
+    /// AbstractDataType::Union(
+    ///  Union {
+    ///     name: vec!["org", "example", "Foo"], // assuming the namespace is `org.example`
+    ///     variants: vec![
+    ///         ("Int".to_string(), FullyQualifiedTypeReference { type_name: vec!["i64"], parameters: vec![] }),
+    ///         ("String".to_string(), FullyQualifiedTypeReference { type_name: vec!["String"], parameters: vec![] }),
+    ///     ],
+    ///     tag_representation: TagRepresentation::Untagged,
+    ///     doc_comment: None, // There is no doc comment defined in above ISL type def
+    ///     source: IslType {name: "foo", .. }, // Represents the `IslType` that is getting converted to `AbstractDataType`
+    ///  }
+    /// )
+    /// ```
+    ///
+    /// _Note: ISL carries no wire-level discriminator for `one_of`/`any_of`, so both are always
+    /// built as [TagRepresentation::Untagged] -- the generated read API relies on member order
+    /// being preserved from the ISL definition and tries each variant in turn._
+    fn build_union_from_constraints(
+        &mut self,
+        constraints: &[IslConstraint],
+        code_gen_context: &mut CodeGenContext,
+        parent_isl_type: &IslType,
+    ) -> CodeGenResult<AbstractDataType> {
+        let mut union_builder = UnionBuilder::default();
+        union_builder
+            .name(self.current_type_fully_qualified_name.to_owned())
+            .source(parent_isl_type.to_owned())
+            .tag_representation(TagRepresentation::Untagged);
+        let mut found_member_types = false;
+
+        for constraint in constraints {
+            let member_types = match constraint.constraint() {
+                IslConstraintValue::OneOf(member_types) => member_types,
+                IslConstraintValue::AnyOf(member_types) => member_types,
+                _ => continue,
+            };
+
+            if found_member_types {
+                return duplicate_constraint_error(self.current_type_name(), "one_of` or `any_of");
+            }
+            found_member_types = true;
+
+            let mut seen_variant_names = BTreeSet::new();
+            let mut anonymous_variant_count = 0;
+            let mut variants = Vec::with_capacity(member_types.len());
+            for member_type in member_types {
+                let variant_name_suggestion = match member_type {
+                    IslTypeRef::Named(name, _) => name.to_case(Case::UpperCamel),
+                    IslTypeRef::TypeImport(isl_import_type, _) => {
+                        isl_import_type.type_name().to_case(Case::UpperCamel)
+                    }
+                    IslTypeRef::Anonymous(_, _) => {
+                        anonymous_variant_count += 1;
+                        format!("NestedType{anonymous_variant_count}")
+                    }
+                };
+                let variant_name =
+                    Self::dedupe_variant_name(variant_name_suggestion, &seen_variant_names);
+                seen_variant_names.insert(variant_name.clone());
+
+                let type_reference = self
+                    .fully_qualified_type_ref_name(
+                        member_type,
+                        FieldPresence::Required,
+                        code_gen_context,
+                        Some(&variant_name),
+                    )?
+                    .ok_or(invalid_abstract_data_type_raw_error(format!(
+                        "Could not determine `FullyQualifiedTypeReference` for union member {variant_name}",
+                    )))?;
+                variants.push((variant_name, type_reference));
+            }
+            union_builder.variants(variants);
+        }
+
+        if !found_member_types {
+            return conflicting_constraints_error(
+                self.current_type_name(),
+                "`one_of` or `any_of` constraint",
+                "none of the type's constraints",
+            );
+        }
+
+        Ok(AbstractDataType::Union(union_builder.build()?))
+    }
+
+    /// Appends a numeric suffix to `name` until it no longer collides with `seen`, so two member
+    /// types that would otherwise produce the same variant name (e.g. two anonymous members, or an
+    /// anonymous member coinciding with a named one) still get distinct variants.
+    fn dedupe_variant_name(name: String, seen: &BTreeSet<String>) -> String {
+        if !seen.contains(&name) {
+            return name;
+        }
+        let mut counter = 2;
+        loop {
+            let candidate = format!("{name}{counter}");
+            if !seen.contains(&candidate) {
+                return candidate;
+            }
+            counter += 1;
+        }
+    }
+
+    /// Builds `AbstractDataType::Map` from the given constraints.
+    /// e.g. for a given type definition as below:
+    /// ```
+    /// type::{
+    ///   name: Foo,
+    ///   type: struct,
+    /// }
+    /// ```
+    /// This method builds `AbstractDataType` as following:
+    /// ```
+    /// AbstractDataType::Map(
+    ///  Map {
+    ///     name: vec!["org", "example", "Foo"], // assuming the namespace is `org.example`
+    ///     key_type: FullyQualifiedTypeReference { type_name: vec!["String"], parameters: vec![] },
+    ///     value_type: FullyQualifiedTypeReference { type_name: vec!["ion_rs::Element"], parameters: vec![] },
+    ///     doc_comment: None, // There is no doc comment defined in above ISL type def
+    ///     source: IslType {name: "foo", .. }, // Represents the `IslType` that is getting converted to `AbstractDataType`
+    ///  }
+    /// )
+    /// ```
+    ///
+    /// _Note: a bare `type: struct` with no `fields` constraint gives code generation no per-field
+    /// names to work with, so every field is modeled as an entry in a single `key_type`/
+    /// `value_type` map rather than a named struct member. The key is always Ion's struct
+    /// field-name type (`String`); the value is `document` (any Ion value), since ISL has no
+    /// constraint that narrows the value type of an unnamed struct's fields. A struct that mixes
+    /// named `fields` with an open tail of unnamed ones would need both a `Structure` and a
+    /// spillover `Map` field for the remainder -- that combination isn't modeled yet, so such a
+    /// type still generates as a plain `Structure` over just its named fields._
+    fn build_map_from_constraints(
+        &mut self,
+        constraints: &[IslConstraint],
+        _code_gen_context: &mut CodeGenContext,
+        parent_isl_type: &IslType,
+    ) -> CodeGenResult<AbstractDataType> {
+        let mut map_builder = MapBuilder::default();
+        map_builder
+            .name(self.current_type_fully_qualified_name.to_owned())
+            .source(parent_isl_type.to_owned());
+
+        let mut found_type = false;
+        for constraint in constraints {
+            if matches!(constraint.constraint(), IslConstraintValue::Type(_)) {
+                if found_type {
+                    return duplicate_constraint_error(self.current_type_name(), "type");
+                }
+                found_type = true;
+            }
+        }
+
+        map_builder.key_type(
+            Self::target_type_for(FieldPresence::Required, &"string".to_string()).ok_or(
+                invalid_abstract_data_type_raw_error(
+                    "Could not determine `FullyQualifiedTypeReference` for map key type",
+                ),
+            )?,
+        );
+        map_builder.value_type(
+            Self::target_type_for(FieldPresence::Required, &"document".to_string()).ok_or(
+                invalid_abstract_data_type_raw_error(
+                    "Could not determine `FullyQualifiedTypeReference` for map value type",
+                ),
+            )?,
+        );
+
+        Ok(AbstractDataType::Map(map_builder.build()?))
+    }
+
+    /// Builds `AbstractDataType::Tuple` from the given constraints.
+    /// e.g. for a given type definition as below:
+    /// ```
+    /// type::{
+    ///   name: Foo,
+    ///   ordered_elements: [int, string],
+    /// }
+    /// ```
+    /// This method builds `AbstractDataType` as following:
+    /// ```
+    /// AbstractDataType::Tuple(
+    ///  Tuple {
+    ///     name: vec!["org", "example", "Foo"], // assuming the namespace is `org.example`
+    ///     element_types: vec![
+    ///         FullyQualifiedTypeReference { type_name: vec!["int"], parameters: vec![] },
+    ///         FullyQualifiedTypeReference { type_name: vec!["String"], parameters: vec![] },
+    ///     ],
+    ///     doc_comment: None, // There is no doc comment defined in above ISL type def
+    ///     source: IslType {name: "foo", .. }, // Represents the `IslType` that is getting converted to `AbstractDataType`
+    ///  }
+    /// )
+    /// ```
+    ///
+    /// _Note: unlike `fields`, `ordered_elements` gives each member position no name of its own,
+    /// so an anonymous member type is named `Element{n}` the same way `one_of`/`any_of` names an
+    /// anonymous union variant `NestedType{n}`._
+    fn build_tuple_from_constraints(
+        &mut self,
+        constraints: &[IslConstraint],
+        code_gen_context: &mut CodeGenContext,
+        parent_isl_type: &IslType,
+    ) -> CodeGenResult<AbstractDataType> {
+        let mut tuple_builder = TupleBuilder::default();
+        tuple_builder
+            .name(self.current_type_fully_qualified_name.to_owned())
+            .source(parent_isl_type.to_owned());
+
+        let mut found_ordered_elements = false;
+        for constraint in constraints {
+            let IslConstraintValue::OrderedElements(element_types) = constraint.constraint() else {
+                continue;
+            };
+
+            if found_ordered_elements {
+                return duplicate_constraint_error(self.current_type_name(), "ordered_elements");
+            }
+            found_ordered_elements = true;
+
+            let mut element_types_resolved = Vec::with_capacity(element_types.len());
+            for (index, element_type) in element_types.iter().enumerate() {
+                let type_name_suggestion = format!("Element{index}");
+                let type_reference = self
+                    .fully_qualified_type_ref_name(
+                        element_type,
+                        FieldPresence::Required,
+                        code_gen_context,
+                        Some(&type_name_suggestion),
+                    )?
+                    .ok_or(invalid_abstract_data_type_raw_error(format!(
+                        "Could not determine `FullyQualifiedTypeReference` for tuple element {index}",
+                    )))?;
+                element_types_resolved.push(type_reference);
+            }
+            tuple_builder.element_types(element_types_resolved);
+        }
+
+        if !found_ordered_elements {
+            return conflicting_constraints_error(
+                self.current_type_name(),
+                "`ordered_elements` constraint",
+                "none of the type's constraints",
+            );
+        }
+
+        Ok(AbstractDataType::Tuple(tuple_builder.build()?))
+    }
+
     /// Builds `AbstractDataType::WrappedScalar` from the given constraints.
     /// ```
     /// type::{
@@ -909,12 +2503,15 @@ impl<L: Language + 'static> CodeGenerator<'_, L> {
                     found_base_type = true;
                 }
                 IslConstraintValue::ContainerLength(_) => {
-                    // TODO: add support for container length
-                    // this is currently not supported and is a no-op
+                    // A scalar has no element count to bound -- `container_length` only applies
+                    // to `Sequence`/`WrappedSequence`, which carry it in their own
+                    // `container_length` field (see `build_wrapped_sequence_from_constraints`).
                 }
                 _ => {
-                    return invalid_abstract_data_type_error(
-                        "Could not determine the abstract data type due to conflicting constraints",
+                    return conflicting_constraints_error(
+                        self.current_type_name(),
+                        "`type` or `container_length` constraint",
+                        format!("{:?}", constraint.constraint()),
                     );
                 }
             }
@@ -967,8 +2564,10 @@ impl<L: Language + 'static> CodeGenerator<'_, L> {
                     found_base_type = true;
                 }
                 _ => {
-                    return invalid_abstract_data_type_error(
-                        "Could not determine the abstract data type due to conflicting constraints",
+                    return conflicting_constraints_error(
+                        self.current_type_name(),
+                        "`type` constraint",
+                        format!("{:?}", constraint.constraint()),
                     );
                 }
             }
@@ -1026,9 +2625,7 @@ impl<L: Language + 'static> CodeGenerator<'_, L> {
                 }
                 IslConstraintValue::Type(isl_type_ref) => {
                     if found_base_type {
-                        return invalid_abstract_data_type_error(
-                            "Multiple `type` constraints in the type definitions are not supported in code generation as it can lead to conflicting types."
-                        );
+                        return duplicate_constraint_error(self.current_type_name(), "type");
                     }
                     if isl_type_ref.name() == "sexp" {
                         wrapped_sequence_builder.sequence_type(SequenceType::SExp);
@@ -1037,13 +2634,15 @@ impl<L: Language + 'static> CodeGenerator<'_, L> {
                     }
                     found_base_type = true;
                 }
-                IslConstraintValue::ContainerLength(_) => {
-                    // TODO: add support for container length
-                    // this is currently not supported and is a no-op
+                IslConstraintValue::ContainerLength(range) => {
+                    wrapped_sequence_builder
+                        .container_length(Some(LengthBound::from(range.to_owned())));
                 }
                 _ => {
-                    return invalid_abstract_data_type_error(
-                        "Could not determine the abstract data type due to conflicting constraints",
+                    return conflicting_constraints_error(
+                        self.current_type_name(),
+                        "`element`, `type`, or `container_length` constraint",
+                        format!("{:?}", constraint.constraint()),
                     );
                 }
             }
@@ -1115,13 +2714,14 @@ impl<L: Language + 'static> CodeGenerator<'_, L> {
                         sequence_builder.sequence_type(SequenceType::List);
                     }
                 }
-                IslConstraintValue::ContainerLength(_) => {
-                    // TODO: add support for container length
-                    // this is currently not supported and is a no-op
+                IslConstraintValue::ContainerLength(range) => {
+                    sequence_builder.container_length(Some(LengthBound::from(range.to_owned())));
                 }
                 _ => {
-                    return invalid_abstract_data_type_error(
-                        "Could not determine the abstract data type due to conflicting constraints",
+                    return conflicting_constraints_error(
+                        self.current_type_name(),
+                        "`element`, `type`, or `container_length` constraint",
+                        format!("{:?}", constraint.constraint()),
                     );
                 }
             }
@@ -1135,6 +2735,7 @@ mod isl_to_model_tests {
     use super::*;
     use crate::commands::generate::model::AbstractDataType;
     use ion_schema::isl;
+    use ion_schema::isl::ranges::UsizeRange;
 
     #[test]
     fn isl_to_model_test_for_struct() -> CodeGenResult<()> {
@@ -1196,23 +2797,37 @@ mod isl_to_model_tests {
                 HashMap::from_iter(vec![
                     (
                         "foo".to_string(),
-                        FieldReference(
-                            FullyQualifiedTypeReference {
+                        FieldReference {
+                            type_reference: FullyQualifiedTypeReference {
                                 type_name: vec![NamespaceNode::Type("String".to_string())],
                                 parameters: vec![]
                             },
-                            FieldPresence::Optional
-                        )
+                            presence: FieldPresence::Optional,
+                            occurs: UsizeRange::zero_or_one(),
+                            original_name: "foo".to_string(),
+                            generated_name: "foo".to_string(),
+                            rename: None,
+                            default: None,
+                            skip: false,
+                            nullable: false,
+                        }
                     ),
                     (
                         "bar".to_string(),
-                        FieldReference(
-                            FullyQualifiedTypeReference {
+                        FieldReference {
+                            type_reference: FullyQualifiedTypeReference {
                                 type_name: vec![NamespaceNode::Type("Integer".to_string())],
                                 parameters: vec![]
                             },
-                            FieldPresence::Optional
-                        )
+                            presence: FieldPresence::Optional,
+                            occurs: UsizeRange::zero_or_one(),
+                            original_name: "bar".to_string(),
+                            generated_name: "bar".to_string(),
+                            rename: None,
+                            default: None,
+                            skip: false,
+                            nullable: false,
+                        }
                     )
                 ])
             )
@@ -1221,16 +2836,153 @@ mod isl_to_model_tests {
     }
 
     #[test]
-    fn isl_to_model_test_for_nested_struct() -> CodeGenResult<()> {
+    fn isl_to_model_test_for_java_value_semantics_annotations() -> CodeGenResult<()> {
         let isl_type = isl::isl_type::v_2_0::load_isl_type(
             r#"
-                // ISL type definition with nested `fields` constraint
+                // All fields are Java built-ins that support structural equality, so `equals`
+                // and `hashCode` should be generated alongside the unconditional `toString`.
                 type:: {
-                    name: my_nested_struct,
+                    name: my_struct,
                     type: struct,
                     fields: {
-                        foo: {
-                            fields: {
+                        foo: string,
+                        bar: int
+                    },
+                }
+            "#
+            .as_bytes(),
+        )?;
+
+        // Initialize code generator for Java
+        let mut java_code_generator = CodeGenerator::<JavaLanguage>::new(
+            Path::new("./"),
+            vec![
+                NamespaceNode::Package("org".to_string()),
+                NamespaceNode::Package("example".to_string()),
+            ],
+        );
+        let data_model_node = java_code_generator.convert_isl_type_def_to_data_model_node(
+            &"my_struct".to_string(),
+            &isl_type,
+            &mut CodeGenContext::new(),
+            false,
+        )?;
+        assert!(data_model_node.annotations.contains(&"equals".to_string()));
+        assert!(data_model_node
+            .annotations
+            .contains(&"hashCode".to_string()));
+        assert!(data_model_node
+            .annotations
+            .contains(&"toString".to_string()));
+        Ok(())
+    }
+
+    #[test]
+    fn isl_to_model_test_for_rust_serde_derives() -> CodeGenResult<()> {
+        let isl_type = isl::isl_type::v_2_0::load_isl_type(
+            r#"
+                type:: {
+                    name: my_struct,
+                    type: struct,
+                    fields: {
+                        foo: string,
+                        bar: int
+                    },
+                }
+            "#
+            .as_bytes(),
+        )?;
+
+        let mut rust_code_generator = CodeGenerator::<RustLanguage>::new(Path::new("./"));
+        let data_model_node = rust_code_generator.convert_isl_type_def_to_data_model_node(
+            &"my_struct".to_string(),
+            &isl_type,
+            &mut CodeGenContext::new(),
+            false,
+        )?;
+        // `Serialize`/`Deserialize` are unconditional defaults for the Rust target (unlike
+        // `Copy`/`Hash`/etc., which are narrowed per-field), so every generated type carries them.
+        assert!(data_model_node.derives.contains(&"Serialize".to_string()));
+        assert!(data_model_node.derives.contains(&"Deserialize".to_string()));
+        Ok(())
+    }
+
+    #[test]
+    fn isl_to_model_test_for_json_ir_serialization() -> CodeGenResult<()> {
+        let isl_type = isl::isl_type::v_2_0::load_isl_type(
+            r#"
+                // A named ISL type so the IR document's "source" field (the originating ISL
+                // type name) has something non-anonymous to serialize.
+                type:: {
+                    name: my_ir_struct,
+                    type: struct,
+                    fields: {
+                        foo: string,
+                    },
+                }
+            "#
+            .as_bytes(),
+        )?;
+
+        // Initialize code generator for Java
+        let mut java_code_generator = CodeGenerator::<JavaLanguage>::new(
+            Path::new("./"),
+            vec![
+                NamespaceNode::Package("org".to_string()),
+                NamespaceNode::Package("example".to_string()),
+            ],
+        );
+        let mut data_model_node = java_code_generator.convert_isl_type_def_to_data_model_node(
+            &"my_ir_struct".to_string(),
+            &isl_type,
+            &mut CodeGenContext::new(),
+            false,
+        )?;
+        // The namespace path is asserted directly against the resolved model (rather than the
+        // serialized JSON below) since the other tests in this module already cover the shape of
+        // a resolved `FullyQualifiedTypeReference` -- this test's job is the IR document wrapper.
+        assert_eq!(
+            data_model_node
+                .fully_qualified_type_ref::<JavaLanguage>()
+                .unwrap()
+                .string_representation::<JavaLanguage>(),
+            "org.example.MyIrStruct"
+        );
+
+        let ir_document =
+            DataModelIrDocument::new(vec![data_model_node]).with_schema_id("my_schema.isl");
+        let json = serde_json::to_value(&ir_document)
+            .expect("a resolved DataModelIrDocument should always serialize");
+
+        assert_eq!(json["format_version"], 1);
+        assert_eq!(json["schema_id"], "my_schema.isl");
+        assert_eq!(json["types"][0]["name"], "MyIrStruct");
+        // The struct's originating ISL source name round-trips into the IR document, giving a
+        // golden file that can be regression-tested without re-resolving the ISL.
+        assert_eq!(
+            json["types"][0]["code_gen_type"]["Structure"]["source"],
+            "my_ir_struct"
+        );
+        // `types_by_name` is the flattened lookup index built alongside `types`.
+        assert!(json["types_by_name"]
+            .as_object()
+            .unwrap()
+            .contains_key("MyIrStruct"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn isl_to_model_test_for_nested_struct() -> CodeGenResult<()> {
+        let isl_type = isl::isl_type::v_2_0::load_isl_type(
+            r#"
+                // ISL type definition with nested `fields` constraint
+                type:: {
+                    name: my_nested_struct,
+                    type: struct,
+                    fields: {
+                        foo: {
+                            fields: {
                                 baz: bool
                             },
                             type: struct,
@@ -1285,8 +3037,8 @@ mod isl_to_model_tests {
                 HashMap::from_iter(vec![
                     (
                         "foo".to_string(),
-                        FieldReference(
-                            FullyQualifiedTypeReference {
+                        FieldReference {
+                            type_reference: FullyQualifiedTypeReference {
                                 type_name: vec![
                                     NamespaceNode::Package("org".to_string()),
                                     NamespaceNode::Package("example".to_string()),
@@ -1295,18 +3047,32 @@ mod isl_to_model_tests {
                                 ],
                                 parameters: vec![]
                             },
-                            FieldPresence::Optional
-                        )
+                            presence: FieldPresence::Optional,
+                            occurs: UsizeRange::zero_or_one(),
+                            original_name: "foo".to_string(),
+                            generated_name: "foo".to_string(),
+                            rename: None,
+                            default: None,
+                            skip: false,
+                            nullable: false,
+                        }
                     ),
                     (
                         "bar".to_string(),
-                        FieldReference(
-                            FullyQualifiedTypeReference {
+                        FieldReference {
+                            type_reference: FullyQualifiedTypeReference {
                                 type_name: vec![NamespaceNode::Type("Integer".to_string())],
                                 parameters: vec![]
                             },
-                            FieldPresence::Optional
-                        )
+                            presence: FieldPresence::Optional,
+                            occurs: UsizeRange::zero_or_one(),
+                            original_name: "bar".to_string(),
+                            generated_name: "bar".to_string(),
+                            rename: None,
+                            default: None,
+                            skip: false,
+                            nullable: false,
+                        }
                     )
                 ])
             );
@@ -1330,4 +3096,828 @@ mod isl_to_model_tests {
         }
         Ok(())
     }
+
+    #[test]
+    fn isl_to_model_test_for_union() -> CodeGenResult<()> {
+        let isl_type = isl::isl_type::v_2_0::load_isl_type(
+            r#"
+                // ISL type definition with a `one_of` constraint, one named member and one
+                // anonymous member that should reuse the nested-type machinery.
+                type:: {
+                    name: my_union,
+                    one_of: [
+                        int,
+                        { fields: { baz: bool } },
+                    ],
+                }
+            "#
+            .as_bytes(),
+        )?;
+
+        // Initialize code generator for Java
+        let mut java_code_generator = CodeGenerator::<JavaLanguage>::new(
+            Path::new("./"),
+            vec![
+                NamespaceNode::Package("org".to_string()),
+                NamespaceNode::Package("example".to_string()),
+            ],
+        );
+        let data_model_node = java_code_generator.convert_isl_type_def_to_data_model_node(
+            &"my_union".to_string(),
+            &isl_type,
+            &mut CodeGenContext::new(),
+            false,
+        )?;
+        let abstract_data_type = data_model_node.code_gen_type.unwrap();
+        assert!(matches!(abstract_data_type, AbstractDataType::Union(_)));
+        if let AbstractDataType::Union(union) = abstract_data_type {
+            assert_eq!(union.tag_representation, TagRepresentation::Untagged);
+            assert_eq!(union.source, isl_type);
+            assert_eq!(union.variants.len(), 2);
+            assert_eq!(union.variants[0].0, "Int");
+            assert_eq!(
+                union.variants[0].1,
+                FullyQualifiedTypeReference {
+                    type_name: vec![NamespaceNode::Type("int".to_string())],
+                    parameters: vec![]
+                }
+            );
+            // The anonymous member gets a synthesized variant name and reuses the same
+            // nested-type machinery as a struct field's inline anonymous type.
+            assert_eq!(union.variants[1].0, "NestedType1");
+            assert_eq!(
+                union.variants[1].1,
+                FullyQualifiedTypeReference {
+                    type_name: vec![
+                        NamespaceNode::Package("org".to_string()),
+                        NamespaceNode::Package("example".to_string()),
+                        NamespaceNode::Type("MyUnion".to_string()),
+                        NamespaceNode::Type("NestedType1".to_string())
+                    ],
+                    parameters: vec![]
+                }
+            );
+            assert_eq!(data_model_node.nested_types.len(), 1);
+            // The nested type itself resolves the same way an anonymous struct field would (see
+            // `isl_to_model_test_for_nested_struct`), rather than being some union-specific shape.
+            let nested_type = &data_model_node.nested_types[0];
+            assert_eq!(nested_type.name, "NestedType1");
+            assert!(nested_type.is_structure());
+            if let Some(AbstractDataType::Structure(nested_structure)) = &nested_type.code_gen_type
+            {
+                assert!(nested_structure.fields.contains_key("baz"));
+            } else {
+                panic!("expected the union's anonymous variant to resolve to a Structure");
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn isl_to_model_test_for_map() -> CodeGenResult<()> {
+        let isl_type = isl::isl_type::v_2_0::load_isl_type(
+            r#"
+                // ISL type definition with `type: struct` but no `fields` constraint: every
+                // field is unnamed, so this should be modeled as a map rather than a struct.
+                type:: {
+                    name: my_map,
+                    type: struct,
+                }
+            "#
+            .as_bytes(),
+        )?;
+
+        // Initialize code generator for Java
+        let mut java_code_generator = CodeGenerator::<JavaLanguage>::new(
+            Path::new("./"),
+            vec![
+                NamespaceNode::Package("org".to_string()),
+                NamespaceNode::Package("example".to_string()),
+            ],
+        );
+        let data_model_node = java_code_generator.convert_isl_type_def_to_data_model_node(
+            &"my_map".to_string(),
+            &isl_type,
+            &mut CodeGenContext::new(),
+            false,
+        )?;
+        let abstract_data_type = data_model_node.code_gen_type.unwrap();
+        assert!(matches!(abstract_data_type, AbstractDataType::Map(_)));
+        if let AbstractDataType::Map(map) = abstract_data_type {
+            assert_eq!(map.source, isl_type);
+            assert_eq!(
+                map.key_type,
+                FullyQualifiedTypeReference {
+                    type_name: vec![NamespaceNode::Type("String".to_string())],
+                    parameters: vec![]
+                }
+            );
+            assert_eq!(
+                map.value_type,
+                FullyQualifiedTypeReference {
+                    type_name: vec![NamespaceNode::Type("com.amazon.ion.IonValue".to_string())],
+                    parameters: vec![]
+                }
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn isl_to_model_test_for_field_occurs() -> CodeGenResult<()> {
+        let isl_type = isl::isl_type::v_2_0::load_isl_type(
+            r#"
+                // `foo` is required, `bar` is explicitly optional, and `baz` may repeat, so it
+                // becomes a sequence rather than an `Option`.
+                type:: {
+                    name: my_struct,
+                    type: struct,
+                    fields: {
+                        foo: { type: string, occurs: required },
+                        bar: { type: int, occurs: optional },
+                        baz: { type: bool, occurs: range::[0,5] },
+                    },
+                }
+            "#
+            .as_bytes(),
+        )?;
+
+        let mut rust_code_generator = CodeGenerator::<RustLanguage>::new(Path::new("./"));
+        let data_model_node = rust_code_generator.convert_isl_type_def_to_data_model_node(
+            &"my_struct".to_string(),
+            &isl_type,
+            &mut CodeGenContext::new(),
+            false,
+        )?;
+        let abstract_data_type = data_model_node.code_gen_type.unwrap();
+        assert!(matches!(abstract_data_type, AbstractDataType::Structure(_)));
+        if let AbstractDataType::Structure(structure) = abstract_data_type {
+            let foo = &structure.fields["foo"];
+            assert_eq!(foo.presence, FieldPresence::Required);
+            assert_eq!(
+                foo.type_reference,
+                FullyQualifiedTypeReference {
+                    type_name: vec!["String".to_string()],
+                    parameters: vec![]
+                }
+            );
+
+            let bar = &structure.fields["bar"];
+            assert_eq!(bar.presence, FieldPresence::Optional);
+            assert_eq!(
+                bar.type_reference,
+                FullyQualifiedTypeReference {
+                    type_name: vec!["Option".to_string()],
+                    parameters: vec![FullyQualifiedTypeReference {
+                        type_name: vec!["i64".to_string()],
+                        parameters: vec![]
+                    }]
+                }
+            );
+
+            // A field that may repeat is always generated as a collection, regardless of whether
+            // its minimum occurrence is zero -- an empty `Vec` already represents "none present".
+            let baz = &structure.fields["baz"];
+            assert_eq!(baz.presence, FieldPresence::Required);
+            assert_eq!(
+                baz.type_reference,
+                FullyQualifiedTypeReference {
+                    type_name: vec!["Vec".to_string()],
+                    parameters: vec![FullyQualifiedTypeReference {
+                        type_name: vec!["bool".to_string()],
+                        parameters: vec![]
+                    }]
+                }
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn isl_to_model_test_for_field_default_from_config() -> CodeGenResult<()> {
+        let isl_type = isl::isl_type::v_2_0::load_isl_type(
+            r#"
+                // `bar` is optional but `--config` gives it a default, so it should generate as
+                // a plain `i64` instead of `Option<i64>`.
+                type:: {
+                    name: my_struct,
+                    type: struct,
+                    fields: {
+                        bar: { type: int, occurs: optional },
+                    },
+                }
+            "#
+            .as_bytes(),
+        )?;
+
+        let mut config_file = tempfile::NamedTempFile::new()?;
+        writeln!(config_file, r#"{{ field_defaults: {{ bar: "42" }} }}"#)?;
+        let config = CodeGenConfig::from_file(config_file.path())?;
+
+        let mut rust_code_generator =
+            CodeGenerator::<RustLanguage>::new(Path::new("./")).with_config(config);
+        let data_model_node = rust_code_generator.convert_isl_type_def_to_data_model_node(
+            &"my_struct".to_string(),
+            &isl_type,
+            &mut CodeGenContext::new(),
+            false,
+        )?;
+        let abstract_data_type = data_model_node.code_gen_type.unwrap();
+        assert!(matches!(abstract_data_type, AbstractDataType::Structure(_)));
+        if let AbstractDataType::Structure(structure) = abstract_data_type {
+            let bar = &structure.fields["bar"];
+            // Still `Optional` for serialization purposes (the field really may be absent on the
+            // wire), but unwrapped since a configured default fills the gap when it is.
+            assert_eq!(bar.presence, FieldPresence::Optional);
+            assert_eq!(bar.default, Some("42".to_string()));
+            assert_eq!(
+                bar.type_reference,
+                FullyQualifiedTypeReference {
+                    type_name: vec!["i64".to_string()],
+                    parameters: vec![]
+                }
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn isl_to_model_test_for_field_name_casing() -> CodeGenResult<()> {
+        let isl_type = isl::isl_type::v_2_0::load_isl_type(
+            r#"
+                // `my-field` has no `--config` override, so it falls back to each target
+                // language's default field-name casing rule.
+                type:: {
+                    name: my_struct,
+                    type: struct,
+                    fields: {
+                        'my-field': string,
+                    },
+                }
+            "#
+            .as_bytes(),
+        )?;
+
+        let mut rust_code_generator = CodeGenerator::<RustLanguage>::new(Path::new("./"));
+        let data_model_node = rust_code_generator.convert_isl_type_def_to_data_model_node(
+            &"my_struct".to_string(),
+            &isl_type,
+            &mut CodeGenContext::new(),
+            false,
+        )?;
+        let abstract_data_type = data_model_node.code_gen_type.unwrap();
+        assert!(matches!(abstract_data_type, AbstractDataType::Structure(_)));
+        if let AbstractDataType::Structure(structure) = abstract_data_type {
+            let field = &structure.fields["my-field"];
+            assert_eq!(field.original_name, "my-field".to_string());
+            assert_eq!(field.rename, None);
+            assert_eq!(field.generated_name, "my_field".to_string());
+        }
+
+        let mut java_code_generator = CodeGenerator::<JavaLanguage>::new(
+            Path::new("./"),
+            vec![NamespaceNode::Package("org".to_string())],
+        );
+        let data_model_node = java_code_generator.convert_isl_type_def_to_data_model_node(
+            &"my_struct".to_string(),
+            &isl_type,
+            &mut CodeGenContext::new(),
+            false,
+        )?;
+        let abstract_data_type = data_model_node.code_gen_type.unwrap();
+        assert!(matches!(abstract_data_type, AbstractDataType::Structure(_)));
+        if let AbstractDataType::Structure(structure) = abstract_data_type {
+            let field = &structure.fields["my-field"];
+            assert_eq!(field.original_name, "my-field".to_string());
+            assert_eq!(field.rename, None);
+            assert_eq!(field.generated_name, "myField".to_string());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn isl_to_model_test_for_field_case_override_from_config() -> CodeGenResult<()> {
+        let isl_type = isl::isl_type::v_2_0::load_isl_type(
+            r#"
+                // `--config` overrides this language's default field-name casing rule
+                // (snake_case for Rust) with SCREAMING_SNAKE_CASE for every field.
+                type:: {
+                    name: my_struct,
+                    type: struct,
+                    fields: {
+                        'my-field': string,
+                    },
+                }
+            "#
+            .as_bytes(),
+        )?;
+
+        let mut config_file = tempfile::NamedTempFile::new()?;
+        writeln!(
+            config_file,
+            r#"{{ namer: {{ field_case: "screaming_snake" }} }}"#
+        )?;
+        let config = CodeGenConfig::from_file(config_file.path())?;
+
+        let mut rust_code_generator =
+            CodeGenerator::<RustLanguage>::new(Path::new("./")).with_config(config);
+        let data_model_node = rust_code_generator.convert_isl_type_def_to_data_model_node(
+            &"my_struct".to_string(),
+            &isl_type,
+            &mut CodeGenContext::new(),
+            false,
+        )?;
+        let abstract_data_type = data_model_node.code_gen_type.unwrap();
+        assert!(matches!(abstract_data_type, AbstractDataType::Structure(_)));
+        if let AbstractDataType::Structure(structure) = abstract_data_type {
+            let field = &structure.fields["my-field"];
+            assert_eq!(field.generated_name, "MY_FIELD".to_string());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn isl_to_model_test_for_namer_keyword_escaping_from_config() -> CodeGenResult<()> {
+        let isl_type = isl::isl_type::v_2_0::load_isl_type(
+            r#"
+                // `type` is a Rust reserved word. Left to this language's own
+                // `escape_reserved_word`, it would generate as the raw identifier `r#type`; the
+                // configured `namer` overrides that with a trailing underscore instead, and keeps
+                // the field's casing as-is so the reserved-word check still matches `type`.
+                type:: {
+                    name: my_struct,
+                    type: struct,
+                    fields: {
+                        type: string,
+                    },
+                }
+            "#
+            .as_bytes(),
+        )?;
+
+        let mut config_file = tempfile::NamedTempFile::new()?;
+        writeln!(
+            config_file,
+            r#"{{ namer: {{ field_case: "keep", keyword_suffix: "_" }} }}"#
+        )?;
+        let config = CodeGenConfig::from_file(config_file.path())?;
+
+        let mut rust_code_generator =
+            CodeGenerator::<RustLanguage>::new(Path::new("./")).with_config(config);
+        let data_model_node = rust_code_generator.convert_isl_type_def_to_data_model_node(
+            &"my_struct".to_string(),
+            &isl_type,
+            &mut CodeGenContext::new(),
+            false,
+        )?;
+        let abstract_data_type = data_model_node.code_gen_type.unwrap();
+        assert!(matches!(abstract_data_type, AbstractDataType::Structure(_)));
+        if let AbstractDataType::Structure(structure) = abstract_data_type {
+            let field = &structure.fields["type"];
+            assert_eq!(field.generated_name, "type_".to_string());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn box_cyclic_fields_boxes_a_direct_self_reference() -> CodeGenResult<()> {
+        let isl_type = isl::isl_type::v_2_0::load_isl_type(
+            r#"
+                // `child` refers back to `my_struct` itself, so generating it by value would make
+                // `MyStruct` infinite-sized in Rust.
+                type:: {
+                    name: my_struct,
+                    type: struct,
+                    fields: {
+                        value: { type: int, occurs: required },
+                        child: { type: my_struct, occurs: required },
+                    },
+                }
+            "#
+            .as_bytes(),
+        )?;
+
+        let mut rust_code_generator = CodeGenerator::<RustLanguage>::new(Path::new("./"));
+        rust_code_generator.convert_isl_type_def_to_data_model_node(
+            &"my_struct".to_string(),
+            &isl_type,
+            &mut CodeGenContext::new(),
+            false,
+        )?;
+
+        rust_code_generator.box_cyclic_fields();
+
+        let my_struct_ref = FullyQualifiedTypeReference {
+            type_name: vec!["MyStruct".to_string()],
+            parameters: vec![],
+        };
+        let structure = match &rust_code_generator.data_model_store[&my_struct_ref].code_gen_type {
+            Some(AbstractDataType::Structure(structure)) => structure,
+            other => panic!("expected a `Structure`, found {other:?}"),
+        };
+        assert_eq!(
+            structure.fields["child"].type_reference,
+            FullyQualifiedTypeReference {
+                type_name: vec!["Box".to_string()],
+                parameters: vec![my_struct_ref],
+            }
+        );
+        // `value` doesn't participate in the cycle, so it's untouched.
+        assert_eq!(
+            structure.fields["value"].type_reference,
+            FullyQualifiedTypeReference {
+                type_name: vec!["i64".to_string()],
+                parameters: vec![],
+            }
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn box_cyclic_fields_boxes_one_edge_of_a_two_type_cycle() -> CodeGenResult<()> {
+        let type_a = isl::isl_type::v_2_0::load_isl_type(
+            r#"
+                type:: {
+                    name: type_a,
+                    type: struct,
+                    fields: {
+                        b: { type: type_b, occurs: required },
+                    },
+                }
+            "#
+            .as_bytes(),
+        )?;
+        let type_b = isl::isl_type::v_2_0::load_isl_type(
+            r#"
+                type:: {
+                    name: type_b,
+                    type: struct,
+                    fields: {
+                        a: { type: type_a, occurs: required },
+                    },
+                }
+            "#
+            .as_bytes(),
+        )?;
+
+        let mut rust_code_generator = CodeGenerator::<RustLanguage>::new(Path::new("./"));
+        rust_code_generator.convert_isl_type_def_to_data_model_node(
+            &"type_a".to_string(),
+            &type_a,
+            &mut CodeGenContext::new(),
+            false,
+        )?;
+        rust_code_generator.convert_isl_type_def_to_data_model_node(
+            &"type_b".to_string(),
+            &type_b,
+            &mut CodeGenContext::new(),
+            false,
+        )?;
+
+        rust_code_generator.box_cyclic_fields();
+
+        let type_a_ref = FullyQualifiedTypeReference {
+            type_name: vec!["TypeA".to_string()],
+            parameters: vec![],
+        };
+        let type_b_ref = FullyQualifiedTypeReference {
+            type_name: vec!["TypeB".to_string()],
+            parameters: vec![],
+        };
+
+        // Breaking either edge of the cycle is enough to make both types finite-sized; the DFS
+        // always discovers `type_a` first (`starts` is sorted) and so always boxes the edge that
+        // closes the cycle back to it, i.e. `type_b`'s `a` field.
+        let structure_a = match &rust_code_generator.data_model_store[&type_a_ref].code_gen_type {
+            Some(AbstractDataType::Structure(structure)) => structure,
+            other => panic!("expected a `Structure`, found {other:?}"),
+        };
+        assert_eq!(
+            structure_a.fields["b"].type_reference,
+            type_b_ref.clone(),
+            "the edge that doesn't close the cycle is left by value"
+        );
+
+        let structure_b = match &rust_code_generator.data_model_store[&type_b_ref].code_gen_type {
+            Some(AbstractDataType::Structure(structure)) => structure,
+            other => panic!("expected a `Structure`, found {other:?}"),
+        };
+        assert_eq!(
+            structure_b.fields["a"].type_reference,
+            FullyQualifiedTypeReference {
+                type_name: vec!["Box".to_string()],
+                parameters: vec![type_a_ref],
+            }
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn unsupported_constraints_are_collected_as_diagnostics_instead_of_aborting(
+    ) -> CodeGenResult<()> {
+        // Neither type below matches any of the supported `AbstractDataType` branches: `valid_values`
+        // only becomes an enum when every value is a symbol or an int, and a bare `precision`
+        // constraint with no accompanying `type` isn't a scalar, struct, sequence, union, or tuple
+        // either.
+        let unsupported_valid_values = isl::isl_type::v_2_0::load_isl_type(
+            r#"
+                type:: {
+                    name: not_an_enum,
+                    valid_values: ["one", "two", "three"],
+                }
+            "#
+            .as_bytes(),
+        )?;
+        let unsupported_precision = isl::isl_type::v_2_0::load_isl_type(
+            r#"
+                type:: {
+                    name: bare_precision,
+                    precision: 2,
+                }
+            "#
+            .as_bytes(),
+        )?;
+        let my_struct = isl::isl_type::v_2_0::load_isl_type(
+            r#"
+                type:: {
+                    name: my_struct,
+                    type: struct,
+                    fields: {
+                        foo: string,
+                    },
+                }
+            "#
+            .as_bytes(),
+        )?;
+
+        let mut rust_code_generator = CodeGenerator::<RustLanguage>::new(Path::new("./"));
+        assert!(matches!(
+            rust_code_generator
+                .resolve_abstract_data_type(&"not_an_enum".to_string(), &unsupported_valid_values),
+            Err(CodeGenError::InvalidDataModel { .. })
+        ));
+        assert!(matches!(
+            rust_code_generator
+                .resolve_abstract_data_type(&"bare_precision".to_string(), &unsupported_precision),
+            Err(CodeGenError::InvalidDataModel { .. })
+        ));
+        // A type whose constraints code generation does support still resolves normally
+        // afterwards -- an earlier type's unsupported constraints don't poison the generator.
+        assert!(rust_code_generator
+            .resolve_abstract_data_type(&"my_struct".to_string(), &my_struct)
+            .is_ok());
+        Ok(())
+    }
+
+    #[test]
+    fn duplicate_type_constraint_reports_structured_error() -> CodeGenResult<()> {
+        let duplicate_type = isl::isl_type::v_2_0::load_isl_type(
+            r#"
+                type:: {
+                    name: two_types,
+                    type: int,
+                    type: string,
+                }
+            "#
+            .as_bytes(),
+        )?;
+
+        let mut rust_code_generator = CodeGenerator::<RustLanguage>::new(Path::new("./"));
+        match rust_code_generator
+            .resolve_abstract_data_type(&"two_types".to_string(), &duplicate_type)
+        {
+            Err(CodeGenError::DuplicateConstraint {
+                type_name,
+                constraint_name,
+            }) => {
+                assert_eq!(type_name, "two_types");
+                assert_eq!(constraint_name, "type");
+            }
+            other => {
+                panic!("expected Err(CodeGenError::DuplicateConstraint {{ .. }}), got {other:?}")
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn conflicting_struct_constraint_reports_expected_and_found() -> CodeGenResult<()> {
+        let conflicting_struct = isl::isl_type::v_2_0::load_isl_type(
+            r#"
+                type:: {
+                    name: my_struct,
+                    fields: {
+                        foo: string,
+                    },
+                    precision: 2,
+                }
+            "#
+            .as_bytes(),
+        )?;
+
+        let mut rust_code_generator = CodeGenerator::<RustLanguage>::new(Path::new("./"));
+        match rust_code_generator
+            .resolve_abstract_data_type(&"my_struct".to_string(), &conflicting_struct)
+        {
+            Err(CodeGenError::ConflictingConstraints {
+                type_name,
+                expected_found,
+            }) => {
+                assert_eq!(type_name, "my_struct");
+                assert_eq!(expected_found.expected, "`fields` or `type` constraint");
+            }
+            other => {
+                panic!("expected Err(CodeGenError::ConflictingConstraints {{ .. }}), got {other:?}")
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn container_length_is_carried_onto_the_wrapped_sequence() -> CodeGenResult<()> {
+        let exact_length = isl::isl_type::v_2_0::load_isl_type(
+            r#"
+                type:: {
+                    name: three_strings,
+                    type: list,
+                    element: string,
+                    container_length: 3,
+                }
+            "#
+            .as_bytes(),
+        )?;
+        let ranged_length = isl::isl_type::v_2_0::load_isl_type(
+            r#"
+                type:: {
+                    name: a_few_strings,
+                    type: list,
+                    element: string,
+                    container_length: range::[2, 5],
+                }
+            "#
+            .as_bytes(),
+        )?;
+
+        let mut rust_code_generator = CodeGenerator::<RustLanguage>::new(Path::new("./"));
+        rust_code_generator
+            .resolve_abstract_data_type(&"three_strings".to_string(), &exact_length)?;
+        rust_code_generator
+            .resolve_abstract_data_type(&"a_few_strings".to_string(), &ranged_length)?;
+
+        let exact_type_ref = FullyQualifiedTypeReference {
+            type_name: vec!["ThreeStrings".to_string()],
+            parameters: vec![],
+        };
+        let ranged_type_ref = FullyQualifiedTypeReference {
+            type_name: vec!["AFewStrings".to_string()],
+            parameters: vec![],
+        };
+
+        match &rust_code_generator.data_model_store[&exact_type_ref].code_gen_type {
+            Some(AbstractDataType::WrappedSequence(seq)) => {
+                let container_length = seq.container_length.expect("expected a container_length");
+                assert_eq!(container_length.min, 3);
+                assert_eq!(container_length.exact(), Some(3));
+            }
+            other => panic!("expected a `WrappedSequence`, found {other:?}"),
+        }
+
+        match &rust_code_generator.data_model_store[&ranged_type_ref].code_gen_type {
+            Some(AbstractDataType::WrappedSequence(seq)) => {
+                let container_length = seq.container_length.expect("expected a container_length");
+                assert_eq!(container_length.min, 2);
+                assert_eq!(container_length.max, Some(5));
+                assert_eq!(container_length.exact(), None);
+            }
+            other => panic!("expected a `WrappedSequence`, found {other:?}"),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn container_length_is_carried_onto_the_structure() -> CodeGenResult<()> {
+        let isl_type = isl::isl_type::v_2_0::load_isl_type(
+            r#"
+                type:: {
+                    name: my_struct,
+                    type: struct,
+                    fields: {
+                        foo: string,
+                    },
+                    container_length: range::[1, 2],
+                }
+            "#
+            .as_bytes(),
+        )?;
+
+        let mut rust_code_generator = CodeGenerator::<RustLanguage>::new(Path::new("./"));
+        let data_model_node = rust_code_generator.convert_isl_type_def_to_data_model_node(
+            &"my_struct".to_string(),
+            &isl_type,
+            &mut CodeGenContext::new(),
+            false,
+        )?;
+        let abstract_data_type = data_model_node.code_gen_type.unwrap();
+        match abstract_data_type {
+            AbstractDataType::Structure(structure) => {
+                let container_length = structure
+                    .container_length
+                    .expect("expected a container_length");
+                assert_eq!(container_length.min, 1);
+                assert_eq!(container_length.max, Some(2));
+                assert_eq!(container_length.exact(), None);
+            }
+            other => panic!("expected a `Structure`, found {other:?}"),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn int_valid_values_become_enum_variants_with_discriminants() -> CodeGenResult<()> {
+        let mixed_enum = isl::isl_type::v_2_0::load_isl_type(
+            r#"
+                type:: {
+                    name: status,
+                    valid_values: [active, -1, 3],
+                }
+            "#
+            .as_bytes(),
+        )?;
+
+        let mut rust_code_generator = CodeGenerator::<RustLanguage>::new(Path::new("./"));
+        rust_code_generator.resolve_abstract_data_type(&"status".to_string(), &mixed_enum)?;
+
+        let type_ref = FullyQualifiedTypeReference {
+            type_name: vec!["Status".to_string()],
+            parameters: vec![],
+        };
+        match &rust_code_generator.data_model_store[&type_ref].code_gen_type {
+            Some(AbstractDataType::Enum(enum_type)) => {
+                assert_eq!(
+                    enum_type.variants,
+                    vec![
+                        ("active".to_string(), None),
+                        ("Neg1".to_string(), Some(EnumVariantValue::Int(-1))),
+                        ("V3".to_string(), Some(EnumVariantValue::Int(3))),
+                    ]
+                );
+            }
+            other => panic!("expected an `Enum`, found {other:?}"),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn resolve_imported_type_finds_a_symbol_registered_by_an_earlier_schema() {
+        let mut rust_code_generator = CodeGenerator::<RustLanguage>::new(Path::new("./"));
+        rust_code_generator.current_schema_id = "common/foo.isl".to_string();
+        let type_ref = FullyQualifiedTypeReference {
+            type_name: vec!["Foo".to_string()],
+            parameters: vec![],
+        };
+        rust_code_generator
+            .register_imported_type_symbol("foo", type_ref.clone())
+            .unwrap();
+
+        assert_eq!(
+            rust_code_generator
+                .resolve_imported_type("common/foo.isl", "foo")
+                .unwrap(),
+            type_ref
+        );
+    }
+
+    #[test]
+    fn resolve_imported_type_errors_when_the_schema_was_never_generated() {
+        let rust_code_generator = CodeGenerator::<RustLanguage>::new(Path::new("./"));
+
+        match rust_code_generator.resolve_imported_type("never/generated.isl", "foo") {
+            Err(CodeGenError::InvalidDataModel { description }) => {
+                assert!(description.contains("unresolved import"));
+            }
+            other => panic!("expected Err(CodeGenError::InvalidDataModel {{ .. }}), got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn register_imported_type_symbol_rejects_a_deterministic_name_collision() {
+        let mut rust_code_generator = CodeGenerator::<RustLanguage>::new(Path::new("./"));
+        let type_ref = FullyQualifiedTypeReference {
+            type_name: vec!["Foo".to_string()],
+            parameters: vec![],
+        };
+
+        rust_code_generator.current_schema_id = "a/foo.isl".to_string();
+        rust_code_generator
+            .register_imported_type_symbol("foo", type_ref.clone())
+            .unwrap();
+
+        rust_code_generator.current_schema_id = "b/foo.isl".to_string();
+        match rust_code_generator.register_imported_type_symbol("foo", type_ref) {
+            Err(CodeGenError::InvalidDataModel { description }) => {
+                assert!(description.contains("collision"));
+            }
+            other => panic!("expected Err(CodeGenError::InvalidDataModel {{ .. }}), got {other:?}"),
+        }
+    }
 }
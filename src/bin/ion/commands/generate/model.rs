@@ -1,6 +1,7 @@
 use derive_builder::Builder;
 use ion_schema::isl::isl_type::IslType;
-use std::collections::HashMap;
+use ion_schema::isl::ranges::UsizeRange;
+use std::collections::{BTreeMap, HashMap};
 use std::fmt::Debug;
 // This module contains a data model that the code generator can use to render a template based on the type of the model.
 // Currently, this same data model is represented by `AbstractDataType` but it doesn't hold all the information for the template.
@@ -41,6 +42,14 @@ pub struct DataModelNode {
     pub(crate) code_gen_type: Option<AbstractDataType>,
     // Represents the nested types for this data model
     pub(crate) nested_types: Vec<DataModelNode>,
+    // Extra derive/decorator attributes to render on this type, combining the target
+    // `Language`'s own defaults with any `--config`-supplied overrides (see
+    // `generate::config::CodeGenConfig`).
+    #[serde(default)]
+    pub(crate) derives: Vec<String>,
+    // Extra annotations (e.g. `@JsonProperty`-style or Lombok attributes) to render on this type.
+    #[serde(default)]
+    pub(crate) annotations: Vec<String>,
 }
 
 impl DataModelNode {
@@ -73,6 +82,30 @@ impl DataModelNode {
         false
     }
 
+    #[allow(dead_code)]
+    pub fn is_union(&self) -> bool {
+        if let Some(code_gen_type) = &self.code_gen_type {
+            return matches!(code_gen_type, AbstractDataType::Union(_));
+        }
+        false
+    }
+
+    #[allow(dead_code)]
+    pub fn is_map(&self) -> bool {
+        if let Some(code_gen_type) = &self.code_gen_type {
+            return matches!(code_gen_type, AbstractDataType::Map(_));
+        }
+        false
+    }
+
+    #[allow(dead_code)]
+    pub fn is_tuple(&self) -> bool {
+        if let Some(code_gen_type) = &self.code_gen_type {
+            return matches!(code_gen_type, AbstractDataType::Tuple(_));
+        }
+        false
+    }
+
     pub fn fully_qualified_type_ref<L: Language>(&mut self) -> Option<FullyQualifiedTypeReference> {
         self.code_gen_type
             .as_ref()
@@ -80,6 +113,102 @@ impl DataModelNode {
     }
 }
 
+/// Bumped whenever a breaking change is made to the shape of [DataModelNode] or its nested types.
+const DATA_MODEL_IR_FORMAT_VERSION: u32 = 1;
+
+/// A self-describing, versioned JSON document capturing every [DataModelNode] produced by a code
+/// generation run. Intended for external tools that want to generate bindings for languages this
+/// crate doesn't target directly, so it must be complete enough to regenerate code without the
+/// original ISL.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DataModelIrDocument {
+    format_version: u32,
+    // The `id` of the ISL schema this document was generated from (empty if unknown), so a tool
+    // diffing IR documents across schema versions -- or across a multi-schema build -- can tell
+    // which schema a given document came from without re-deriving it from the output path.
+    #[serde(default)]
+    schema_id: String,
+    types: Vec<DataModelNode>,
+    // A flattened, dot-joined-path index over `types` and their `nested_types` (e.g.
+    // `"Foo.Bar"` for `Bar` nested under top-level `Foo`), so external consumers of this IR can
+    // look up a type by name without walking the tree themselves. Rebuilt any time `types`
+    // changes shape, e.g. after [sort](Self::sort) or [dedup_nested](Self::dedup_nested).
+    types_by_name: BTreeMap<String, DataModelNode>,
+}
+
+impl DataModelIrDocument {
+    pub fn new(types: Vec<DataModelNode>) -> Self {
+        let mut document = Self {
+            format_version: DATA_MODEL_IR_FORMAT_VERSION,
+            schema_id: String::new(),
+            types,
+            types_by_name: BTreeMap::new(),
+        };
+        document.rebuild_types_by_name();
+        document
+    }
+
+    /// Records which ISL schema this document was generated from. See the `schema_id` field.
+    pub fn with_schema_id(mut self, schema_id: impl Into<String>) -> Self {
+        self.schema_id = schema_id.into();
+        self
+    }
+
+    /// Stable-sorts this document's types, and each type's `nested_types`, by name so that
+    /// regenerating from an unchanged schema produces the same ordering across runs. See
+    /// [postprocess::sort_data_model_forest](crate::commands::generate::postprocess::sort_data_model_forest).
+    pub fn sort(&mut self) {
+        crate::commands::generate::postprocess::sort_data_model_forest(&mut self.types);
+        self.rebuild_types_by_name();
+    }
+
+    /// Hoists structurally identical nested types that appear under more than one parent into a
+    /// shared top-level type, returning the names of the types that were hoisted. See
+    /// [postprocess::dedup_nested_types](crate::commands::generate::postprocess::dedup_nested_types).
+    pub fn dedup_nested(&mut self) -> Vec<String> {
+        let hoisted = crate::commands::generate::postprocess::dedup_nested_types(&mut self.types);
+        self.rebuild_types_by_name();
+        hoisted
+    }
+
+    /// Boxes fields that form a reference cycle (directly or transitively through other
+    /// structures) so the generated type has finite size, returning `"TypeName.field_name"` for
+    /// each field that was boxed. See
+    /// [postprocess::break_reference_cycles](crate::commands::generate::postprocess::break_reference_cycles).
+    pub fn break_cycles<L: Language>(&mut self) -> Vec<String> {
+        let boxed =
+            crate::commands::generate::postprocess::break_reference_cycles::<L>(&mut self.types);
+        self.rebuild_types_by_name();
+        boxed
+    }
+
+    fn rebuild_types_by_name(&mut self) {
+        self.types_by_name.clear();
+        for node in &self.types {
+            flatten_into(node, &mut self.types_by_name);
+        }
+    }
+}
+
+fn flatten_into(node: &DataModelNode, index: &mut BTreeMap<String, DataModelNode>) {
+    index.insert(node.name.clone(), node.clone());
+    for nested in &node.nested_types {
+        flatten_nested_into(nested, &node.name, index);
+    }
+}
+
+fn flatten_nested_into(
+    node: &DataModelNode,
+    prefix: &str,
+    index: &mut BTreeMap<String, DataModelNode>,
+) {
+    let path = format!("{prefix}.{}", node.name);
+    index.insert(path.clone(), node.clone());
+    for nested in &node.nested_types {
+        flatten_nested_into(nested, &path, index);
+    }
+}
+
 /// Represents a fully qualified type name for a type definition
 /// e.g. For a `Foo` class in `org.example` namespace
 ///     In Java, `org.example.Foo`
@@ -159,16 +288,95 @@ impl FullyQualifiedTypeReference {
             .map(|p| p.string_representation::<L>())
             .collect::<Vec<_>>()
             .join(", ");
+        let (open, close) = L::generic_parameter_brackets();
         format!(
-            "{}<{}>",
+            "{}{open}{}{close}",
             self.type_name.join(&L::namespace_separator()),
             parameters
         )
     }
 }
 
+/// Which derive traits are legal for a generated Rust type, computed by [crate::commands::generate::generator::CodeGenerator]
+/// by walking a type's fields the way bindgen's can-derive passes do: a trait is derivable for a
+/// container only if every field can derive it (e.g. `Copy` is cleared by any `Vec`/heap-owning
+/// field). Rust renders these directly as a `#[derive(...)]` line via [Derivability::rust_derives];
+/// Java reuses `partial_eq`/`hash` to decide whether to generate `equals`/`hashCode` overrides.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Derivability {
+    pub(crate) copy: bool,
+    pub(crate) clone: bool,
+    pub(crate) debug: bool,
+    pub(crate) default: bool,
+    pub(crate) partial_eq: bool,
+    pub(crate) hash: bool,
+}
+
+impl Derivability {
+    /// The identity element for narrowing a container's derivability across its fields: every
+    /// trait starts out derivable, and each field narrows it (never widens it) via `narrow_by`.
+    pub fn all() -> Self {
+        Derivability {
+            copy: true,
+            clone: true,
+            debug: true,
+            default: true,
+            partial_eq: true,
+            hash: true,
+        }
+    }
+
+    /// No trait is derivable. The base case for an unresolvable or (transitively) self-referential
+    /// type, where deriving anything would require unbounded recursion.
+    pub fn none() -> Self {
+        Derivability {
+            copy: false,
+            clone: false,
+            debug: false,
+            default: false,
+            partial_eq: false,
+            hash: false,
+        }
+    }
+
+    /// Clears every flag `field` doesn't also have, mirroring how a struct can only derive a trait
+    /// when *every* field can.
+    pub fn narrow_by(&mut self, field: Derivability) {
+        self.copy &= field.copy;
+        self.clone &= field.clone;
+        self.debug &= field.debug;
+        self.default &= field.default;
+        self.partial_eq &= field.partial_eq;
+        self.hash &= field.hash;
+    }
+
+    /// Renders this as the contents of a Rust `#[derive(...)]` attribute, e.g.
+    /// `["Copy", "Clone", "Debug"]`. `Copy` is listed before `Clone` since it implies it.
+    pub fn rust_derives(&self) -> Vec<String> {
+        let mut derives = Vec::new();
+        if self.copy {
+            derives.push("Copy".to_string());
+        }
+        if self.clone {
+            derives.push("Clone".to_string());
+        }
+        if self.debug {
+            derives.push("Debug".to_string());
+        }
+        if self.default {
+            derives.push("Default".to_string());
+        }
+        if self.partial_eq {
+            derives.push("PartialEq".to_string());
+        }
+        if self.hash {
+            derives.push("Hash".to_string());
+        }
+        derives
+    }
+}
+
 /// A target-language-agnostic data type that determines which template(s) to use for code generation.
-// TODO: Add more code gen types like sum/discriminated union, enum and map.
 #[non_exhaustive]
 #[derive(Debug, Clone, PartialEq, Serialize)]
 pub enum AbstractDataType {
@@ -182,6 +390,15 @@ pub enum AbstractDataType {
     WrappedSequence(WrappedSequence),
     // A collection of field name/value pairs (e.g. a map)
     Structure(Structure),
+    // A discriminated union over the member types of an ISL `one_of`/`any_of` constraint.
+    Union(Union),
+    // An open struct / associative container keyed and valued by a single pair of types.
+    Map(Map),
+    // A heterogeneous, fixed-arity tuple over the member types of an ISL `ordered_elements`
+    // constraint.
+    Tuple(Tuple),
+    // An enumeration of named variants over the values of an ISL `valid_values` constraint.
+    Enum(Enum),
 }
 
 impl AbstractDataType {
@@ -203,6 +420,18 @@ impl AbstractDataType {
             AbstractDataType::Structure(Structure { doc_comment, .. }) => {
                 doc_comment.as_ref().map(|s| s.as_str())
             }
+            AbstractDataType::Union(Union { doc_comment, .. }) => {
+                doc_comment.as_ref().map(|s| s.as_str())
+            }
+            AbstractDataType::Map(Map { doc_comment, .. }) => {
+                doc_comment.as_ref().map(|s| s.as_str())
+            }
+            AbstractDataType::Tuple(Tuple { doc_comment, .. }) => {
+                doc_comment.as_ref().map(|s| s.as_str())
+            }
+            AbstractDataType::Enum(Enum { doc_comment, .. }) => {
+                doc_comment.as_ref().map(|s| s.as_str())
+            }
         }
     }
 
@@ -219,10 +448,337 @@ impl AbstractDataType {
                 Some(L::target_type_as_sequence(seq.element_type.to_owned()))
             }
             AbstractDataType::Structure(structure) => Some(structure.name.to_owned().into()),
+            AbstractDataType::Union(union) => Some(union.name.to_owned().into()),
+            AbstractDataType::Map(map) => Some(L::target_type_as_map(
+                map.key_type.to_owned(),
+                map.value_type.to_owned(),
+            )),
+            AbstractDataType::Tuple(tuple) => Some(tuple.name.to_owned().into()),
+            AbstractDataType::Enum(enum_type) => Some(enum_type.name.to_owned().into()),
+        }
+    }
+
+    /// A structural signature for this data model, independent of the name this particular
+    /// occurrence happened to be generated with (e.g. `FooElement` vs `BarElement` for two
+    /// inline type definitions with identical shape), its doc comment, or its originating ISL
+    /// `source`. Two `AbstractDataType`s with the same signature are interchangeable for every
+    /// reader/writer this crate would generate from them, so
+    /// [dedup_nested_types](crate::commands::generate::postprocess::dedup_nested_types) uses this
+    /// instead of a name- and source-sensitive `Debug` dump to recognize duplicate anonymous
+    /// nested types.
+    pub(crate) fn structural_signature(&self) -> String {
+        match self {
+            AbstractDataType::WrappedScalar(w) => format!("WrappedScalar({:?})", w.base_type),
+            AbstractDataType::Scalar(s) => format!("Scalar({:?})", s.base_type),
+            AbstractDataType::Sequence(seq) => {
+                format!(
+                    "Sequence({:?}, {:?}, {:?})",
+                    seq.sequence_type, seq.element_type, seq.container_length
+                )
+            }
+            AbstractDataType::WrappedSequence(seq) => {
+                format!(
+                    "WrappedSequence({:?}, {:?}, {:?})",
+                    seq.sequence_type, seq.element_type, seq.container_length
+                )
+            }
+            AbstractDataType::Structure(structure) => {
+                let mut fields: Vec<String> = structure
+                    .fields
+                    .iter()
+                    .map(|(name, field)| {
+                        format!(
+                            "{name}:{:?}:{:?}:{:?}:{}",
+                            field.type_reference,
+                            field.presence,
+                            field.occurs.inclusive_endpoints(),
+                            field.nullable
+                        )
+                    })
+                    .collect();
+                fields.sort();
+                format!(
+                    "Structure(closed={}, {{{}}})",
+                    structure.is_closed,
+                    fields.join(", ")
+                )
+            }
+            AbstractDataType::Union(union) => {
+                format!(
+                    "Union({:?}, {:?})",
+                    union.tag_representation, union.variants
+                )
+            }
+            AbstractDataType::Map(map) => format!("Map({:?}, {:?})", map.key_type, map.value_type),
+            AbstractDataType::Tuple(tuple) => format!("Tuple({:?})", tuple.element_types),
+            AbstractDataType::Enum(enum_type) => format!("Enum({:?})", enum_type.variants),
+        }
+    }
+
+    /// The fully qualified name this `AbstractDataType` is rendered/referenced under, for
+    /// variants generated as their own standalone named type (`struct`/`class`/`enum`) rather
+    /// than inlined at each use site. `None` for `Scalar`/`Sequence`/`Map`, which render directly
+    /// as their `base_type`/`Vec<T>`/`HashMap<K, V>` wherever referenced and so have no
+    /// independent name a duplicate-removal pass could redirect references to.
+    pub(crate) fn canonical_name(&self) -> Option<&FullyQualifiedTypeName> {
+        match self {
+            AbstractDataType::WrappedScalar(w) => Some(&w.name),
+            AbstractDataType::WrappedSequence(w) => Some(&w.name),
+            AbstractDataType::Structure(s) => Some(&s.name),
+            AbstractDataType::Union(u) => Some(&u.name),
+            AbstractDataType::Tuple(t) => Some(&t.name),
+            AbstractDataType::Enum(e) => Some(&e.name),
+            AbstractDataType::Scalar(_)
+            | AbstractDataType::Sequence(_)
+            | AbstractDataType::Map(_) => None,
+        }
+    }
+
+    /// Rewrites every nested `FullyQualifiedTypeReference` this `AbstractDataType` holds (struct
+    /// fields, tuple elements, union variants, sequence/map element types) whose `type_name`
+    /// matches a key in `rewrites`, replacing it with the corresponding value. Used by
+    /// [dedup_nested_types](crate::commands::generate::postprocess::dedup_nested_types) to
+    /// repoint references at the surviving copy of a hoisted duplicate nested type.
+    pub(crate) fn rewrite_type_references(&mut self, rewrites: &HashMap<Vec<String>, Vec<String>>) {
+        match self {
+            AbstractDataType::WrappedScalar(w) => rewrite_reference(&mut w.base_type, rewrites),
+            AbstractDataType::Scalar(s) => rewrite_reference(&mut s.base_type, rewrites),
+            AbstractDataType::Sequence(seq) => rewrite_reference(&mut seq.element_type, rewrites),
+            AbstractDataType::WrappedSequence(seq) => {
+                rewrite_reference(&mut seq.element_type, rewrites)
+            }
+            AbstractDataType::Structure(structure) => {
+                for field in structure.fields.values_mut() {
+                    rewrite_reference(&mut field.type_reference, rewrites);
+                }
+            }
+            AbstractDataType::Union(union) => {
+                for (_, type_ref) in union.variants.iter_mut() {
+                    rewrite_reference(type_ref, rewrites);
+                }
+            }
+            AbstractDataType::Map(map) => {
+                rewrite_reference(&mut map.key_type, rewrites);
+                rewrite_reference(&mut map.value_type, rewrites);
+            }
+            AbstractDataType::Tuple(tuple) => {
+                for element in tuple.element_types.iter_mut() {
+                    rewrite_reference(element, rewrites);
+                }
+            }
+            // An enum's variants are literal `valid_values` (symbols/ints), not type references.
+            AbstractDataType::Enum(_) => {}
+        }
+    }
+}
+
+/// Replaces `type_ref`'s `type_name` with its mapped value in `rewrites`, if any, then recurses
+/// into `parameters` (e.g. the `T` in `Vec<T>`/`Option<T>`) so a hoisted duplicate nested inside a
+/// collection or optional wrapper is still redirected.
+fn rewrite_reference(
+    type_ref: &mut FullyQualifiedTypeReference,
+    rewrites: &HashMap<Vec<String>, Vec<String>>,
+) {
+    if let Some(canonical) = rewrites.get(&type_ref.type_name) {
+        type_ref.type_name = canonical.clone();
+    }
+    for parameter in type_ref.parameters.iter_mut() {
+        rewrite_reference(parameter, rewrites);
+    }
+}
+
+/// Represents an open struct / associative container keyed and valued by a single pair of types.
+/// e.g. Given below ISL,
+/// ```
+/// type::{
+///   name: map_type,
+///   type: struct,
+/// }
+/// ```
+/// Corresponding generated code in Rust would look like following:
+/// ```
+/// struct MapType {
+///    value: HashMap<String, ion_rs::Element>
+/// }
+/// ```
+#[allow(dead_code)]
+#[derive(Debug, Clone, Builder, PartialEq, Serialize)]
+#[builder(setter(into))]
+pub struct Map {
+    // Represents the fully qualified name for this data model
+    pub(crate) name: FullyQualifiedTypeName,
+    // Represents doc comment for the generated code
+    #[builder(default)]
+    pub(crate) doc_comment: Option<String>,
+    // Represents the fully qualified name of the key type (e.g. `String` in Rust).
+    pub(crate) key_type: FullyQualifiedTypeReference,
+    // Represents the fully qualified name of the value type.
+    pub(crate) value_type: FullyQualifiedTypeReference,
+    // Represents the source ISL type which can be used to get other constraints useful for this type.
+    #[serde(skip_serializing_if = "is_anonymous")]
+    #[serde(serialize_with = "serialize_type_name")]
+    pub(crate) source: IslType,
+}
+
+/// The strategy used to discriminate between a `Union`'s variants on the wire, borrowed from
+/// serde's enum representations (see <https://serde.rs/enum-representations.html>).
+#[allow(dead_code)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub enum TagRepresentation {
+    /// `{ "VariantName": <value> }`
+    Externally,
+    /// The variant name is stored in `tag` alongside the variant's own fields.
+    Internally { tag: String },
+    /// The variant name is stored in `tag`, the variant's value in `content`.
+    Adjacently { tag: String, content: String },
+    /// No tag is written; on read, each variant is attempted in declaration order and the first
+    /// one that validates is used. Requires that variant order be preserved from the ISL
+    /// definition so that matching stays deterministic.
+    Untagged,
+}
+
+/// Represents a heterogeneous, fixed-arity tuple over the member types of an ISL
+/// `ordered_elements` constraint.
+/// e.g. Given below ISL,
+/// ```
+/// type::{
+///   name: tuple_type,
+///   ordered_elements: [int, string],
+/// }
+/// ```
+/// Corresponding generated code in Rust would look like following:
+/// ```
+/// struct TupleType(i64, String);
+/// ```
+#[allow(dead_code)]
+#[derive(Debug, Clone, Builder, PartialEq, Serialize)]
+#[builder(setter(into))]
+pub struct Tuple {
+    // Represents the fully qualified name for this data model
+    pub(crate) name: FullyQualifiedTypeName,
+    // Represents doc comment for the generated code
+    #[builder(default)]
+    pub(crate) doc_comment: Option<String>,
+    // Represents the fully qualified type of each element, in the order they appeared in the
+    // `ordered_elements` constraint. Unlike `Structure::fields`, these have no field names to key
+    // off of, so position in this `Vec` is itself the only way to address a given element (a
+    // positional accessor like Rust's `.0`/`.1` or a Java record component).
+    pub(crate) element_types: Vec<FullyQualifiedTypeReference>,
+    // Represents the source ISL type which can be used to get other constraints useful for this type.
+    #[serde(skip_serializing_if = "is_anonymous")]
+    #[serde(serialize_with = "serialize_type_name")]
+    pub(crate) source: IslType,
+}
+
+/// Represents a discriminated union (sum type) over the member types of an ISL
+/// `one_of`/`any_of` constraint.
+/// e.g. Given below ISL,
+/// ```
+/// type::{
+///   name: union_type,
+///   one_of: [int, string]
+/// }
+/// ```
+/// Corresponding generated code in Rust would look like following:
+/// ```
+/// enum UnionType {
+///    Int(i64),
+///    String(String),
+/// }
+/// ```
+#[allow(dead_code)]
+#[derive(Debug, Clone, Builder, PartialEq, Serialize)]
+#[builder(setter(into))]
+pub struct Union {
+    // Represents the fully qualified name for this data model
+    pub(crate) name: FullyQualifiedTypeName,
+    // Represents doc comment for the generated code
+    #[builder(default)]
+    pub(crate) doc_comment: Option<String>,
+    // Represents the variants of this union as (variant_name, variant_type) pairs, in the order
+    // the member types appeared in the ISL `one_of`/`any_of` constraint.
+    pub(crate) variants: Vec<(String, FullyQualifiedTypeReference)>,
+    // Represents how a variant is discriminated on the wire.
+    pub(crate) tag_representation: TagRepresentation,
+    // Represents the source ISL type which can be used to get other constraints useful for this type.
+    #[serde(skip_serializing_if = "is_anonymous")]
+    #[serde(serialize_with = "serialize_type_name")]
+    pub(crate) source: IslType,
+}
+
+impl Union {
+    /// Provides a string representation of this `Union`'s variants, rendered according to
+    /// `tag_representation` so that templates can emit the matching (de)serialization code.
+    pub fn string_representation<L: Language>(&self) -> String {
+        let variants = self
+            .variants
+            .iter()
+            .map(|(name, type_ref)| format!("{name}({})", type_ref.string_representation::<L>()))
+            .collect::<Vec<_>>()
+            .join(", ");
+        match &self.tag_representation {
+            TagRepresentation::Externally => format!("externally tagged {{ {variants} }}"),
+            TagRepresentation::Internally { tag } => {
+                format!("internally tagged (tag = \"{tag}\") {{ {variants} }}")
+            }
+            TagRepresentation::Adjacently { tag, content } => {
+                format!(
+                    "adjacently tagged (tag = \"{tag}\", content = \"{content}\") {{ {variants} }}"
+                )
+            }
+            TagRepresentation::Untagged => format!("untagged {{ {variants} }}"),
         }
     }
 }
 
+/// The literal discriminant value from the ISL `valid_values` constraint backing one [Enum]
+/// variant. `None` alongside a variant in [Enum::variants] when the value was a bare `symbol`,
+/// whose text already is the variant name; `Some` when it was an `int`, which needs both a
+/// sanitized variant name (see `build_enum_from_constraints`) and this discriminant to parse an
+/// incoming value back to the right variant.
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub enum EnumVariantValue {
+    /// Rendered as a native enum discriminant (e.g. `Foo = 3` in Rust/Java) and matched back from
+    /// an incoming Ion int during parsing.
+    Int(i64),
+}
+
+/// Represents an enumeration of named variants, generated from an ISL `valid_values` constraint
+/// whose elements are `symbol`s and/or `int`s.
+/// e.g. Given below ISL,
+/// ```
+/// type::{
+///   name: enum_type,
+///   valid_values: [a, b, c]
+/// }
+/// ```
+/// Corresponding generated code in Rust would look like following:
+/// ```
+/// enum EnumType {
+///    A,
+///    B,
+///    C,
+/// }
+/// ```
+#[allow(dead_code)]
+#[derive(Debug, Clone, Builder, PartialEq, Serialize)]
+#[builder(setter(into))]
+pub struct Enum {
+    // Represents the fully qualified name for this data model
+    pub(crate) name: FullyQualifiedTypeName,
+    // Represents doc comment for the generated code
+    #[builder(default)]
+    pub(crate) doc_comment: Option<String>,
+    // Represents the variants of this enum as (variant_name, discriminant) pairs, in the order
+    // the values appeared in the ISL `valid_values` constraint.
+    pub(crate) variants: Vec<(String, Option<EnumVariantValue>)>,
+    // Represents the source ISL type which can be used to get other constraints useful for this type.
+    #[serde(skip_serializing_if = "is_anonymous")]
+    #[serde(serialize_with = "serialize_type_name")]
+    pub(crate) source: IslType,
+}
+
 /// Helper function for serializing abstract data type's `source` field that represents an ISL type.
 /// This method returns the name for the given ISL type.
 // TODO: `IslType` does not implement `Serialize`, once that is available this method can be removed.
@@ -259,7 +815,7 @@ pub struct Scalar {
     // }
     // ```
     // Corresponding `FullyQualifiedReference` would be `FullyQualifiedTypeReference { type_name: vec!["String"], parameters: vec![] }`.
-    base_type: FullyQualifiedTypeReference,
+    pub(crate) base_type: FullyQualifiedTypeReference,
     // Represents doc comment for the generated code
     // If the doc comment is provided for this scalar type then this is `Some(doc_comment)`, other it is None.
     #[builder(default)]
@@ -300,7 +856,7 @@ pub struct WrappedScalar {
     // ```
     // Corresponding `name` would be `vec!["Foo"]` and `base_type` would be `FullyQualifiedTypeReference { type_name: vec!["String"], parameters: vec![] }`.
     name: FullyQualifiedTypeName,
-    base_type: FullyQualifiedTypeReference,
+    pub(crate) base_type: FullyQualifiedTypeReference,
     // Represents doc comment for the generated code
     // If the doc comment is provided for this scalar type then this is `Some(doc_comment)`, other it is None.
     #[builder(default)]
@@ -349,6 +905,11 @@ pub struct WrappedSequence {
     element_type: FullyQualifiedTypeReference,
     // Represents the type of the sequence which is either `sexp` or `list`.
     sequence_type: SequenceType,
+    // The ISL `container_length` bound on this sequence, if any -- an exact count (Rust's
+    // `[T; N]`, where the target language supports fixed-size arrays) or a closed `min..max`
+    // range (a length-checked constructor/read path instead).
+    #[builder(default)]
+    pub(crate) container_length: Option<LengthBound>,
     // Represents the source ISL type which can be used to get other constraints useful for this type.
     // For example, getting the length of this sequence from `container_length` constraint or getting a `regex` value for string type.
     // This will also be useful for `text` type to verify if this is a `string` or `symbol`.
@@ -384,6 +945,11 @@ pub struct Sequence {
     pub(crate) element_type: FullyQualifiedTypeReference,
     // Represents the type of the sequence which is either `sexp` or `list`.
     pub(crate) sequence_type: SequenceType,
+    // The ISL `container_length` bound on this sequence, if any -- an exact count (Rust's
+    // `[T; N]`, where the target language supports fixed-size arrays) or a closed `min..max`
+    // range (a length-checked constructor/read path instead).
+    #[builder(default)]
+    pub(crate) container_length: Option<LengthBound>,
     // Represents the source ISL type which can be used to get other constraints useful for this type.
     // For example, getting the length of this sequence from `container_length` constraint or getting a `regex` value for string type.
     // This will also be useful for `text` type to verify if this is a `string` or `symbol`.
@@ -425,6 +991,11 @@ pub struct Structure {
     // field_value represents `FieldReference` i.e. the type of the value field as fully qualified name and the presence for this field.
     // _Note: that a hashmap with (FullQualifiedTypeReference, DataModel) pairs will be stored in code generator to get information on the field_value name used here._
     pub(crate) fields: HashMap<String, FieldReference>,
+    // The ISL `container_length` bound on this struct's total field count, if any -- enforced by
+    // [Structure::diagnose] the same way `Sequence`/`WrappedSequence` carry their own
+    // `container_length` for element counts (see [LengthBound]).
+    #[builder(default)]
+    pub(crate) container_length: Option<LengthBound>,
     // Represents the source ISL type which can be used to get other constraints useful for this type.
     // For example, getting the length of this sequence from `container_length` constraint or getting a `regex` value for string type.
     // This will also be useful for `text` type to verify if this is a `string` or `symbol`.
@@ -441,20 +1012,272 @@ pub enum FieldPresence {
     Optional,
 }
 
-/// Represents a reference to the field with its fully qualified name and its presence (i.e. required or optional)
+/// Represents a reference to a structure's field: its type, its presence (i.e. required or
+/// optional), the full `occurs` range it was declared with, and any per-field rendering
+/// overrides, modeled on serde_derive's field attributes (see
+/// <https://serde.rs/field-attrs.html>).
 #[derive(Debug, Clone, PartialEq, Serialize)]
-pub struct FieldReference(
-    pub(crate) FullyQualifiedTypeReference,
-    pub(crate) FieldPresence,
-);
+pub struct FieldReference {
+    pub(crate) type_reference: FullyQualifiedTypeReference,
+    pub(crate) presence: FieldPresence,
+    // The field's ISL `occurs` range, preserved in full (rather than collapsed into
+    // `FieldPresence`) so the code generator can tell a `[0,1]`/`[1,1]` field, which maps to a
+    // scalar/`Option`, apart from a field whose `occurs` max is greater than one, which maps to
+    // a collection (`Vec<T>`/`List<T>`) plus an emitted cardinality check.
+    #[serde(serialize_with = "serialize_occurs")]
+    pub(crate) occurs: UsizeRange,
+    // The original ISL field name, retained so the generated serialization layer can still
+    // read/write the on-the-wire name even when `rename` or [Language::field_name_case] changes
+    // the in-memory identifier.
+    pub(crate) original_name: String,
+    // The identifier actually emitted for this field: `rename` if set, otherwise `original_name`
+    // run through the target language's default [Language::field_name_case] rule (e.g.
+    // `my-field` becomes `my_field` in Rust, `myField` in Java). Precomputed once here (rather
+    // than recomputed per-template) since it's generic over the target language, which the
+    // `FieldReference` value itself is not.
+    pub(crate) generated_name: String,
+    // Overrides the identifier emitted for this field instead of `original_name`/language casing.
+    pub(crate) rename: Option<String>,
+    // An expression used to initialize this field when a `FieldPresence::Optional` field is
+    // absent. When set, the field is generated as a plain typed member instead of being wrapped
+    // in `Option`/`Optional`.
+    pub(crate) default: Option<String>,
+    // Omits this field from (de)serialization while still generating it.
+    pub(crate) skip: bool,
+    // True when this field's ISL type reference was one of the `$`-prefixed nullable built-in
+    // forms (e.g. `$int`), meaning the value itself may be `null` in addition to `type_reference`,
+    // as distinct from `presence`, which governs whether the field can be absent entirely.
+    pub(crate) nullable: bool,
+}
+
+impl FieldReference {
+    /// Returns true when this field's `occurs` range allows more than one occurrence, meaning
+    /// `type_reference` was generated as a collection (`Vec<T>`/`List<T>`) rather than a scalar.
+    #[allow(dead_code)]
+    pub fn is_collection(&self) -> bool {
+        self.occurs.inclusive_endpoints().1 > 1
+    }
+
+    /// Returns true when this field may be absent entirely, meaning `type_reference` was already
+    /// passed through [Language::target_type_as_optional](crate::commands::generate::utils::Language::target_type_as_optional)
+    /// (e.g. wrapped in `Option<T>`/`java.util.Optional<T>`) when this field was generated.
+    #[allow(dead_code)]
+    pub fn is_optional(&self) -> bool {
+        self.presence == FieldPresence::Optional
+    }
+
+    /// Returns the `(min, max)` number of occurrences allowed for this field, used to emit
+    /// cardinality checks (reject fewer than `min` or more than `max` occurrences) during read.
+    #[allow(dead_code)]
+    pub fn occurs_endpoints(&self) -> (usize, usize) {
+        self.occurs.inclusive_endpoints()
+    }
+}
+
+/// Helper function for serializing `FieldReference::occurs`.
+/// This method returns the `(min, max)` inclusive endpoints for the given `UsizeRange`.
+// TODO: `UsizeRange` does not implement `Serialize`, once that is available this method can be removed.
+fn serialize_occurs<S>(occurs: &UsizeRange, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    occurs.inclusive_endpoints().serialize(serializer)
+}
+
+/// A sequence's ISL `container_length` constraint, carried on [Sequence]/[WrappedSequence] so the
+/// code generator can emit a fixed-size representation (e.g. Rust's `[T; N]`) for an exact length,
+/// or a length-checked constructor/read path for a closed `min..max` range.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct LengthBound {
+    pub(crate) min: usize,
+    pub(crate) max: Option<usize>,
+}
+
+impl LengthBound {
+    /// Returns the exact length this bound requires (`min == max`), or `None` for a genuine range
+    /// -- used to decide between a fixed-size array (e.g. `[T; N]` in Rust) and a length-checked
+    /// `Vec<T>`/`List<T>`.
+    pub fn exact(&self) -> Option<usize> {
+        match self.max {
+            Some(max) if max == self.min => Some(self.min),
+            _ => None,
+        }
+    }
+}
+
+impl From<UsizeRange> for LengthBound {
+    /// `UsizeRange`'s upper endpoint is `usize::MAX` when ISL left `container_length` unbounded
+    /// above (e.g. `container_length: range::[2, max]`), which is represented here as `max: None`
+    /// rather than a literal `Some(usize::MAX)`.
+    fn from(range: UsizeRange) -> Self {
+        let (min, max) = range.inclusive_endpoints();
+        LengthBound {
+            min,
+            max: (max != usize::MAX).then_some(max),
+        }
+    }
+}
+
+/// A validation finding produced by [Structure::diagnose], naming the structure it's about and
+/// the concrete field names that are missing or unrecognized.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Diagnostic {
+    pub(crate) type_name: FullyQualifiedTypeName,
+    pub(crate) missing_fields: Vec<String>,
+    pub(crate) unexpected_fields: Vec<String>,
+    // Set when the struct carries a `container_length` constraint and the number of observed
+    // fields falls outside it, independent of whether any individual field name was
+    // missing/unexpected.
+    pub(crate) field_count_violation: Option<String>,
+}
+
+impl Diagnostic {
+    /// Renders this diagnostic as a single human-readable message,
+    /// e.g. "Missing structure fields: bar, baz".
+    pub fn message(&self) -> String {
+        let mut parts = vec![];
+        if !self.missing_fields.is_empty() {
+            parts.push(format!(
+                "Missing structure fields: {}",
+                self.missing_fields.join(", ")
+            ));
+        }
+        if !self.unexpected_fields.is_empty() {
+            parts.push(format!(
+                "Unexpected structure fields: {}",
+                self.unexpected_fields.join(", ")
+            ));
+        }
+        if let Some(field_count_violation) = &self.field_count_violation {
+            parts.push(field_count_violation.to_owned());
+        }
+        parts.join("; ")
+    }
+}
+
+/// What a generated reader should do upon observing a field name that isn't one of a
+/// [Structure]'s declared `fields`, per [Structure::unexpected_field_action].
+#[derive(Debug, Clone, PartialEq)]
+pub enum UnexpectedFieldAction {
+    /// The structure is closed: generated readers should reject the value and surface a clear
+    /// error naming the offending field, rather than silently accepting it.
+    Reject(String),
+    /// The structure is open: generated readers should keep collecting the field as extra,
+    /// unmodeled content instead of rejecting it.
+    Accept,
+}
+
+impl Structure {
+    /// Decides what a generated reader should do, while incrementally reading fields off the
+    /// wire, upon observing a field named `name` that isn't one of this structure's declared
+    /// `fields`. Returns `None` when `name` is a recognized field, since that isn't "unexpected"
+    /// and the reader should just deserialize it normally.
+    // TODO: this only covers `fields`' own `closed` annotation; the ISL `field_names` constraint
+    // (which validates each struct key against a named symbol-enum type, independent of which
+    // field names are declared) isn't resolved from `source` yet.
+    #[allow(dead_code)]
+    pub fn unexpected_field_action(&self, name: &str) -> Option<UnexpectedFieldAction> {
+        if self.fields.contains_key(name) {
+            return None;
+        }
+        Some(if self.is_closed {
+            UnexpectedFieldAction::Reject(name.to_string())
+        } else {
+            UnexpectedFieldAction::Accept
+        })
+    }
+
+    /// Compares `observed_fields` (the field names actually present on an instance, or a
+    /// candidate set recorded at compile time) against this structure's declared `fields`,
+    /// returning a [Diagnostic] listing any missing required fields and, if this structure is
+    /// closed, any observed names it doesn't recognize. A field whose `presence` is
+    /// `FieldPresence::Required` (and which isn't `skip`ped) is missing regardless of whether the
+    /// structure is open or closed -- `is_closed` only governs whether *extra* fields are
+    /// tolerated, not whether declared ones are optional.
+    #[allow(dead_code)]
+    pub fn diagnose(&self, observed_fields: &[String]) -> Option<Diagnostic> {
+        let observed: std::collections::HashSet<&str> =
+            observed_fields.iter().map(|s| s.as_str()).collect();
+        let mut missing_fields: Vec<String> = self
+            .fields
+            .iter()
+            .filter(|(name, field_ref)| {
+                matches!(field_ref.presence, FieldPresence::Required)
+                    && !field_ref.skip
+                    && !observed.contains(name.as_str())
+            })
+            .map(|(name, _)| name.to_owned())
+            .collect();
+        missing_fields.sort();
+
+        let mut unexpected_fields: Vec<String> = if self.is_closed {
+            observed_fields
+                .iter()
+                .filter(|name| !self.fields.contains_key(name.as_str()))
+                .cloned()
+                .collect()
+        } else {
+            Vec::new()
+        };
+        unexpected_fields.sort();
+
+        let field_count_violation = self.container_length.as_ref().and_then(|bound| {
+            let observed_count = observed_fields.len();
+            let too_few = observed_count < bound.min;
+            let too_many = bound.max.is_some_and(|max| observed_count > max);
+            (too_few || too_many).then(|| match bound.exact() {
+                Some(exact) => {
+                    format!("Expected exactly {exact} struct fields, found {observed_count}")
+                }
+                None => format!(
+                    "Expected between {} and {} struct fields, found {observed_count}",
+                    bound.min,
+                    bound
+                        .max
+                        .map(|max| max.to_string())
+                        .unwrap_or_else(|| "unbounded".to_string())
+                ),
+            })
+        });
+
+        if missing_fields.is_empty()
+            && unexpected_fields.is_empty()
+            && field_count_violation.is_none()
+        {
+            return None;
+        }
+        Some(Diagnostic {
+            type_name: self.name.to_owned(),
+            missing_fields,
+            unexpected_fields,
+            field_count_violation,
+        })
+    }
+}
+
+impl DataModelNode {
+    /// Runs [Structure::diagnose] over this node's own `Structure`, if it has one, wrapping the
+    /// result in a `Vec` so callers (the code generator's compile-time sanity checks, and the
+    /// generated runtime validators) can share the same reusable result type regardless of how
+    /// many diagnostics a future node shape might produce.
+    #[allow(dead_code)]
+    pub fn diagnostics(&self, observed_fields: &[String]) -> Vec<Diagnostic> {
+        match &self.code_gen_type {
+            Some(AbstractDataType::Structure(structure)) => {
+                structure.diagnose(observed_fields).into_iter().collect()
+            }
+            _ => vec![],
+        }
+    }
+}
 
 #[cfg(test)]
 mod model_tests {
     use super::*;
     use ion_schema::isl::isl_constraint::v_2_0::*;
+    use ion_schema::isl::isl_constraint::IslConstraint;
     use ion_schema::isl::isl_type::v_2_0::anonymous_type;
     use ion_schema::isl::isl_type_reference::v_2_0::*;
-    use ion_schema::isl::ranges::UsizeRange;
 
     #[test]
     fn scalar_builder_test() {
@@ -559,25 +1382,40 @@ mod model_tests {
             fields: HashMap::from_iter(vec![
                 (
                     "foo".to_string(),
-                    FieldReference(
-                        FullyQualifiedTypeReference {
+                    FieldReference {
+                        type_reference: FullyQualifiedTypeReference {
                             type_name: vec!["String".to_string()],
                             parameters: vec![],
                         },
-                        FieldPresence::Required,
-                    ),
+                        presence: FieldPresence::Required,
+                        occurs: UsizeRange::zero_or_one(),
+                        original_name: "foo".to_string(),
+                        generated_name: "foo".to_string(),
+                        rename: None,
+                        default: None,
+                        skip: false,
+                        nullable: false,
+                    },
                 ),
                 (
                     "bar".to_string(),
-                    FieldReference(
-                        FullyQualifiedTypeReference {
+                    FieldReference {
+                        type_reference: FullyQualifiedTypeReference {
                             type_name: vec!["int".to_string()],
                             parameters: vec![],
                         },
-                        FieldPresence::Required,
-                    ),
+                        presence: FieldPresence::Required,
+                        occurs: UsizeRange::zero_or_one(),
+                        original_name: "bar".to_string(),
+                        generated_name: "bar".to_string(),
+                        rename: None,
+                        default: None,
+                        skip: false,
+                        nullable: false,
+                    },
                 ),
             ]),
+            container_length: None,
             source: anonymous_type(vec![
                 type_constraint(named_type_ref("struct")),
                 fields(
@@ -616,23 +1454,37 @@ mod model_tests {
             .fields(HashMap::from_iter(vec![
                 (
                     "foo".to_string(),
-                    FieldReference(
-                        FullyQualifiedTypeReference {
+                    FieldReference {
+                        type_reference: FullyQualifiedTypeReference {
                             type_name: vec!["String".to_string()],
                             parameters: vec![],
                         },
-                        FieldPresence::Required,
-                    ),
+                        presence: FieldPresence::Required,
+                        occurs: UsizeRange::zero_or_one(),
+                        original_name: "foo".to_string(),
+                        generated_name: "foo".to_string(),
+                        rename: None,
+                        default: None,
+                        skip: false,
+                        nullable: false,
+                    },
                 ),
                 (
                     "bar".to_string(),
-                    FieldReference(
-                        FullyQualifiedTypeReference {
+                    FieldReference {
+                        type_reference: FullyQualifiedTypeReference {
                             type_name: vec!["int".to_string()],
                             parameters: vec![],
                         },
-                        FieldPresence::Required,
-                    ),
+                        presence: FieldPresence::Required,
+                        occurs: UsizeRange::zero_or_one(),
+                        original_name: "bar".to_string(),
+                        generated_name: "bar".to_string(),
+                        rename: None,
+                        default: None,
+                        skip: false,
+                        nullable: false,
+                    },
                 ),
             ]))
             .source(anonymous_type(vec![
@@ -661,4 +1513,238 @@ mod model_tests {
         // Verify the expected_struct is same as the one built by struct_builder
         assert_eq!(expected_struct, struct_builder.build().unwrap());
     }
+
+    #[test]
+    fn struct_diagnose_test() {
+        let closed_struct = Structure {
+            name: vec!["org".to_string(), "example".to_string(), "Foo".to_string()],
+            doc_comment: None,
+            is_closed: true,
+            fields: HashMap::from_iter(vec![
+                (
+                    "foo".to_string(),
+                    FieldReference {
+                        type_reference: FullyQualifiedTypeReference {
+                            type_name: vec!["String".to_string()],
+                            parameters: vec![],
+                        },
+                        presence: FieldPresence::Required,
+                        occurs: UsizeRange::zero_or_one(),
+                        original_name: "foo".to_string(),
+                        generated_name: "foo".to_string(),
+                        rename: None,
+                        default: None,
+                        skip: false,
+                        nullable: false,
+                    },
+                ),
+                (
+                    "bar".to_string(),
+                    FieldReference {
+                        type_reference: FullyQualifiedTypeReference {
+                            type_name: vec!["int".to_string()],
+                            parameters: vec![],
+                        },
+                        presence: FieldPresence::Required,
+                        occurs: UsizeRange::zero_or_one(),
+                        original_name: "bar".to_string(),
+                        generated_name: "bar".to_string(),
+                        rename: None,
+                        default: None,
+                        skip: false,
+                        nullable: false,
+                    },
+                ),
+            ]),
+            container_length: None,
+            source: anonymous_type(vec![
+                type_constraint(named_type_ref("struct")),
+                fields(
+                    vec![
+                        (
+                            "foo".to_string(),
+                            variably_occurring_type_ref(
+                                named_type_ref("string"),
+                                UsizeRange::zero_or_one(),
+                            ),
+                        ),
+                        (
+                            "bar".to_string(),
+                            variably_occurring_type_ref(
+                                named_type_ref("int"),
+                                UsizeRange::zero_or_one(),
+                            ),
+                        ),
+                    ]
+                    .into_iter(),
+                ),
+            ]),
+        };
+
+        // All declared fields present, no extras: no diagnostic
+        assert_eq!(
+            closed_struct.diagnose(&["foo".to_string(), "bar".to_string()]),
+            None
+        );
+
+        // "bar" missing, "baz" unexpected
+        let diagnostic = closed_struct
+            .diagnose(&["foo".to_string(), "baz".to_string()])
+            .unwrap();
+        assert_eq!(diagnostic.missing_fields, vec!["bar".to_string()]);
+        assert_eq!(diagnostic.unexpected_fields, vec!["baz".to_string()]);
+        assert_eq!(
+            diagnostic.message(),
+            "Missing structure fields: bar; Unexpected structure fields: baz"
+        );
+
+        // An open structure still requires its declared required fields -- `is_closed` only
+        // controls whether extra fields are tolerated.
+        let open_struct = Structure {
+            is_closed: false,
+            ..closed_struct
+        };
+        let diagnostic = open_struct
+            .diagnose(&["foo".to_string(), "baz".to_string()])
+            .unwrap();
+        assert_eq!(diagnostic.missing_fields, vec!["bar".to_string()]);
+        assert!(diagnostic.unexpected_fields.is_empty());
+    }
+
+    #[test]
+    fn struct_diagnose_test_for_container_length() {
+        let fields = HashMap::from_iter(vec![(
+            "foo".to_string(),
+            FieldReference {
+                type_reference: FullyQualifiedTypeReference {
+                    type_name: vec!["String".to_string()],
+                    parameters: vec![],
+                },
+                presence: FieldPresence::Optional,
+                occurs: UsizeRange::zero_or_one(),
+                original_name: "foo".to_string(),
+                generated_name: "foo".to_string(),
+                rename: None,
+                default: None,
+                skip: false,
+                nullable: false,
+            },
+        )]);
+        let source = anonymous_type(vec![
+            type_constraint(named_type_ref("struct")),
+            fields_constraint_for(&["foo"]),
+        ]);
+
+        // An open struct with `container_length: 2` still rejects too few/too many fields, even
+        // though no individual field name is missing or unexpected.
+        let open_struct = Structure {
+            name: vec!["org".to_string(), "example".to_string(), "Foo".to_string()],
+            doc_comment: None,
+            is_closed: false,
+            fields: fields.clone(),
+            container_length: Some(LengthBound {
+                min: 2,
+                max: Some(2),
+            }),
+            source: source.clone(),
+        };
+
+        let diagnostic = open_struct.diagnose(&["foo".to_string()]).unwrap();
+        assert!(diagnostic.missing_fields.is_empty());
+        assert!(diagnostic.unexpected_fields.is_empty());
+        assert_eq!(
+            diagnostic.field_count_violation,
+            Some("Expected exactly 2 struct fields, found 1".to_string())
+        );
+
+        // Exactly 2 observed fields satisfies the bound: no diagnostic.
+        assert_eq!(
+            open_struct.diagnose(&["foo".to_string(), "extra".to_string()]),
+            None
+        );
+
+        // A ranged bound rejects too many fields, independent of `is_closed`.
+        let ranged_struct = Structure {
+            container_length: Some(LengthBound {
+                min: 1,
+                max: Some(2),
+            }),
+            ..open_struct
+        };
+        let diagnostic = ranged_struct
+            .diagnose(&["foo".to_string(), "a".to_string(), "b".to_string()])
+            .unwrap();
+        assert_eq!(
+            diagnostic.field_count_violation,
+            Some("Expected between 1 and 2 struct fields, found 3".to_string())
+        );
+    }
+
+    #[test]
+    fn unexpected_field_action_test() {
+        let mut fields = HashMap::new();
+        fields.insert(
+            "foo".to_string(),
+            FieldReference {
+                type_reference: FullyQualifiedTypeReference {
+                    type_name: vec!["String".to_string()],
+                    parameters: vec![],
+                },
+                presence: FieldPresence::Required,
+                occurs: UsizeRange::zero_or_one(),
+                original_name: "foo".to_string(),
+                generated_name: "foo".to_string(),
+                rename: None,
+                default: None,
+                skip: false,
+                nullable: false,
+            },
+        );
+        let source = anonymous_type(vec![
+            type_constraint(named_type_ref("struct")),
+            fields_constraint_for(&["foo"]),
+        ]);
+
+        let closed_struct = Structure {
+            name: vec!["org".to_string(), "example".to_string(), "Foo".to_string()],
+            doc_comment: None,
+            is_closed: true,
+            fields: fields.clone(),
+            container_length: None,
+            source: source.clone(),
+        };
+        // A known field is never "unexpected", regardless of `is_closed`.
+        assert_eq!(closed_struct.unexpected_field_action("foo"), None);
+        assert_eq!(
+            closed_struct.unexpected_field_action("baz"),
+            Some(UnexpectedFieldAction::Reject("baz".to_string()))
+        );
+
+        let open_struct = Structure {
+            is_closed: false,
+            ..closed_struct
+        };
+        assert_eq!(
+            open_struct.unexpected_field_action("baz"),
+            Some(UnexpectedFieldAction::Accept)
+        );
+    }
+
+    fn fields_constraint_for(names: &[&str]) -> IslConstraint {
+        fields(
+            names
+                .iter()
+                .map(|name| {
+                    (
+                        name.to_string(),
+                        variably_occurring_type_ref(
+                            named_type_ref("string"),
+                            UsizeRange::zero_or_one(),
+                        ),
+                    )
+                })
+                .collect::<Vec<_>>()
+                .into_iter(),
+        )
+    }
 }
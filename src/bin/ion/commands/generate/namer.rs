@@ -0,0 +1,127 @@
+//! A per-language naming policy, modeled on how FlatBuffers lets a schema compile configure the
+//! case convention and keyword escaping its generators apply, rather than baking one fixed
+//! convention into each target `Language` impl. A `--config` document can layer a [Namer] on top
+//! of a target `Language`'s own defaults (`Language::field_name_case`/`escape_reserved_word`,
+//! `Case::UpperCamel` for type names), the same way `CodeGenConfig::type_name`/`field_name` let a
+//! single ISL name be overridden without replacing the language's naming convention wholesale.
+
+use convert_case::{Case as ConvertCase, Casing};
+
+/// A case convention [Namer] can apply to one category of generated identifier, independent of
+/// the target language's own default.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Case {
+    PascalCase,
+    LowerCamel,
+    SnakeCase,
+    ScreamingSnake,
+    /// All lowercase, with no word-boundary separators (e.g. `foobar`).
+    Lower,
+    /// All uppercase, with no word-boundary separators (e.g. `FOOBAR`).
+    Upper,
+    /// Leaves the identifier exactly as it was spelled in the source ISL, applying neither a
+    /// target language's default casing nor any of the other variants here.
+    Keep,
+}
+
+impl Case {
+    /// Converts `name` to this case convention.
+    pub(crate) fn convert(&self, name: &str) -> String {
+        match self {
+            Case::PascalCase => name.to_case(ConvertCase::UpperCamel),
+            Case::LowerCamel => name.to_case(ConvertCase::Camel),
+            Case::SnakeCase => name.to_case(ConvertCase::Snake),
+            Case::ScreamingSnake => name.to_case(ConvertCase::ScreamingSnake),
+            Case::Lower => name.to_case(ConvertCase::Lower),
+            Case::Upper => name.to_case(ConvertCase::Upper),
+            Case::Keep => name.to_string(),
+        }
+    }
+
+    /// Parses one of this enum's variants from the lowercase, snake_case spelling used in a
+    /// `--config` document (e.g. `"pascal_case"`, `"lower_camel"`). Returns `None` for anything
+    /// else, mirroring how `CodeGenConfig::from_file` reports an unrecognized config value.
+    pub(crate) fn parse(value: &str) -> Option<Case> {
+        match value {
+            "pascal_case" => Some(Case::PascalCase),
+            "lower_camel" => Some(Case::LowerCamel),
+            "snake_case" => Some(Case::SnakeCase),
+            "screaming_snake" => Some(Case::ScreamingSnake),
+            "lower" => Some(Case::Lower),
+            "upper" => Some(Case::Upper),
+            "keep" => Some(Case::Keep),
+            _ => None,
+        }
+    }
+
+    /// Parses one of this enum's variants from the spelling used by the `--naming` CLI flag
+    /// (e.g. `"PascalCase"`, `"SCREAMING_SNAKE_CASE"`), which favors the case convention's own
+    /// name over the snake_case spelling [`Self::parse`] uses for `--config` documents.
+    pub(crate) fn parse_cli_name(value: &str) -> Option<Case> {
+        match value {
+            "lowercase" => Some(Case::Lower),
+            "UPPERCASE" => Some(Case::Upper),
+            "PascalCase" => Some(Case::PascalCase),
+            "camelCase" => Some(Case::LowerCamel),
+            "snake_case" => Some(Case::SnakeCase),
+            "SCREAMING_SNAKE_CASE" => Some(Case::ScreamingSnake),
+            _ => None,
+        }
+    }
+}
+
+/// Centralizes a code generation run's naming policy: the case convention applied to each
+/// category of generated identifier, and how a reserved-word collision (e.g. an ISL field
+/// literally named `class` in Java, or `type` in Rust) gets escaped. `None` in any `Case` field
+/// means "no override" -- fall back to the target `Language`'s own default for that category, the
+/// same way an unconfigured `CodeGenConfig::type_name`/`field_name` falls back to a language's
+/// default naming.
+///
+/// `method_case` and `constant_case` round out the category list this struct is modeled to cover
+/// (types, fields, methods/accessors, constants, namespace nodes), but have no generated-name call
+/// site to apply to yet -- this crate's code generation doesn't emit accessor method bodies or
+/// named constants today (see the `read_method_name`/`write_method_name` NOTE in `code.rs`), so
+/// wiring them in now would configure a policy with nothing to drive. They're kept here, rather
+/// than added later, since a future call site should only need to start reading them, not reshape
+/// this struct's public surface.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct Namer {
+    pub(crate) type_case: Option<Case>,
+    pub(crate) field_case: Option<Case>,
+    #[allow(dead_code)]
+    pub(crate) method_case: Option<Case>,
+    #[allow(dead_code)]
+    pub(crate) constant_case: Option<Case>,
+    pub(crate) namespace_case: Option<Case>,
+    // Replaces a target language's own `escape_reserved_word` suffixing once a reserved-word
+    // collision is found, applied as `format!("{prefix}{name}{suffix}")` over the *original*
+    // (case-converted but unescaped) name. `None` for either half keeps that language's own
+    // escaping for the identifiers it would otherwise rewrite.
+    pub(crate) keyword_prefix: Option<String>,
+    pub(crate) keyword_suffix: Option<String>,
+}
+
+impl Namer {
+    /// Applies this `Namer`'s keyword escaping override to `name` if it collides with one of the
+    /// target language's reserved words, detected by running `name` through `default_escape` (a
+    /// target `Language`'s own `escape_reserved_word`) and checking whether it changed anything.
+    /// Falls back to `default_escape`'s own result when no `keyword_prefix`/`keyword_suffix` is
+    /// configured, or when `name` wasn't reserved in the first place.
+    pub(crate) fn escape_keyword(
+        &self,
+        name: &str,
+        default_escape: impl FnOnce(&str) -> String,
+    ) -> String {
+        let default_escaped = default_escape(name);
+        if default_escaped == name
+            || (self.keyword_prefix.is_none() && self.keyword_suffix.is_none())
+        {
+            return default_escaped;
+        }
+        format!(
+            "{}{name}{}",
+            self.keyword_prefix.as_deref().unwrap_or_default(),
+            self.keyword_suffix.as_deref().unwrap_or_default(),
+        )
+    }
+}
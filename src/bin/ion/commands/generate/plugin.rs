@@ -0,0 +1,120 @@
+use crate::commands::generate::context::CodeGenContext;
+use crate::commands::generate::model::{AbstractDataType, DataModelNode};
+use crate::commands::generate::result::CodeGenResult;
+use ion_schema::isl::isl_type::IslType;
+use std::path::PathBuf;
+use tera::Context;
+
+/// Configuration threaded through every [`CodeGenPlugin`] invocation: where generated files land,
+/// the fully-qualified module prefix new types nest under (e.g. the Rust module path or Java
+/// package the core `Language` target is already rendering into), and the name of the support
+/// runtime the plugin's emitted code depends on (e.g. `"ion-rs"`, `"ion-java"`).
+#[derive(Debug, Clone)]
+pub struct CodeGenPluginContext {
+    pub output_dir: PathBuf,
+    /// The fully-qualified module/package prefix the core `Language` target is already
+    /// rendering into (e.g. `"org.example"` for Java), already joined with that language's
+    /// namespace separator. Empty for languages with no `--namespace` option.
+    pub module_prefix: String,
+    pub support_runtime: String,
+}
+
+/// A single file a [`CodeGenPlugin`] wants written to disk, relative to
+/// [`CodeGenPluginContext::output_dir`].
+pub struct GeneratedFile {
+    pub relative_path: PathBuf,
+    pub contents: String,
+}
+
+/// An extension point invoked once per resolved [`DataModelNode`], in addition to (not instead
+/// of) the target `Language`'s own Tera-rendered output. This lets a new target language or
+/// output shape be added to code generation -- e.g. bindings for a language this crate doesn't
+/// build in, or a second file alongside the primary generated type (a schema doc comment, a
+/// serializer test fixture) -- without touching `CodeGenerator`'s core traversal: implement this
+/// trait and register an instance via
+/// [`crate::commands::generate::generator::CodeGenerator::with_plugin`].
+pub trait CodeGenPlugin {
+    /// A short, human-readable name for diagnostics (e.g. `"rust"`, `"typescript-extra"`).
+    fn name(&self) -> &str;
+
+    /// Runs once per top-level ISL type, before it's converted into a [`DataModelNode`]. Lets a
+    /// plugin read information off `isl_type` that the core data model doesn't carry (e.g. an
+    /// annotation or an otherwise-unsupported constraint) and stash it on `code_gen_context` for
+    /// [`Self::on_definition`] to pick back up once the type is resolved. Default: no-op.
+    fn on_type(
+        &self,
+        _isl_type: &IslType,
+        _code_gen_context: &mut CodeGenContext,
+    ) -> CodeGenResult<()> {
+        Ok(())
+    }
+
+    /// Runs once the resolved `AbstractDataType` for `name` is about to be rendered, with write
+    /// access to the Tera [`Context`] the struct/class/scalar/... template renders against. Lets
+    /// a plugin attach extra template variables -- validation metadata, a generated doc comment,
+    /// anything a custom template (registered via
+    /// [`crate::commands::generate::generator::CodeGenerator::with_templates`]) wants to read --
+    /// without forking the core templates. Default: no-op.
+    fn on_definition(
+        &self,
+        _name: &str,
+        _abstract_data_type: &AbstractDataType,
+        _tera_context: &mut Context,
+    ) -> CodeGenResult<()> {
+        Ok(())
+    }
+
+    /// Emits zero or more files for `node`, resolved against `context`. Called once per
+    /// top-level [`DataModelNode`] the core traversal renders, after that node's primary file has
+    /// already been written. Nested types are not passed separately; they are reachable through
+    /// `node.nested_types`.
+    fn generate(
+        &self,
+        node: &DataModelNode,
+        context: &CodeGenPluginContext,
+    ) -> CodeGenResult<Vec<GeneratedFile>>;
+}
+
+/// A built-in, ready-to-register plugin demonstrating [`CodeGenPlugin::on_definition`]: for any
+/// ISL `struct` that wasn't declared `closed` (i.e. the ISL `fields` constraint permits values to
+/// carry fields beyond the ones listed), attaches a `plugin_doc_comments` entry to the Tera
+/// context noting that, so a custom template can surface it as a generated doc comment without
+/// the core `Structure` template needing to know this plugin exists. Register it with
+/// [`crate::commands::generate::generator::CodeGenerator::with_plugin`].
+pub struct OpenStructureDocCommentPlugin;
+
+impl CodeGenPlugin for OpenStructureDocCommentPlugin {
+    fn name(&self) -> &str {
+        "open-structure-doc-comment"
+    }
+
+    fn on_definition(
+        &self,
+        _name: &str,
+        abstract_data_type: &AbstractDataType,
+        tera_context: &mut Context,
+    ) -> CodeGenResult<()> {
+        if let AbstractDataType::Structure(structure) = abstract_data_type {
+            if !structure.is_closed {
+                let mut doc_comments: Vec<String> = tera_context
+                    .get("plugin_doc_comments")
+                    .and_then(|value| serde_json::from_value(value.clone()).ok())
+                    .unwrap_or_default();
+                doc_comments.push(
+                    "This type permits additional fields beyond the ones declared here."
+                        .to_string(),
+                );
+                tera_context.insert("plugin_doc_comments", &doc_comments);
+            }
+        }
+        Ok(())
+    }
+
+    fn generate(
+        &self,
+        _node: &DataModelNode,
+        _context: &CodeGenPluginContext,
+    ) -> CodeGenResult<Vec<GeneratedFile>> {
+        Ok(vec![])
+    }
+}
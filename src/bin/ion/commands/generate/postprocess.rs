@@ -0,0 +1,227 @@
+//! Deterministic passes over the assembled [DataModelNode] tree, run before the tree is rendered
+//! or emitted, analogous to bindgen's `sort_semantically`/`merge_extern_blocks` post-processing
+//! stages. These operate on the resolved data model itself, so they apply uniformly regardless of
+//! which [Language](crate::commands::generate::utils::Language) backend is rendering it.
+
+use crate::commands::generate::model::{AbstractDataType, DataModelNode};
+use crate::commands::generate::utils::Language;
+use std::collections::{HashMap, HashSet};
+
+/// Stable-sorts `nodes` and, recursively, each node's `nested_types`, by name. Run before
+/// rendering (gated behind `--sort`) so regenerating from an unchanged schema produces the same
+/// type ordering across runs, minimizing diffs.
+pub(crate) fn sort_data_model_forest(nodes: &mut [DataModelNode]) {
+    nodes.sort_by(|a, b| a.name.cmp(&b.name));
+    for node in nodes.iter_mut() {
+        sort_nested_types(node);
+    }
+}
+
+fn sort_nested_types(node: &mut DataModelNode) {
+    node.nested_types.sort_by(|a, b| a.name.cmp(&b.name));
+    for nested in node.nested_types.iter_mut() {
+        sort_nested_types(nested);
+    }
+}
+
+/// Finds nested types that are structurally identical -- same base kind, field set with
+/// presence, element type, or enum variants, per
+/// [AbstractDataType::structural_signature](crate::commands::generate::model::AbstractDataType::structural_signature)
+/// -- but appear under more than one parent in `nodes`, and hoists the first occurrence of each
+/// duplicate group to the top level of `nodes`, removing the duplicate copies from their parents.
+/// Every reference to a removed duplicate (a struct field, tuple element, union variant, or
+/// sequence/map element type, including ones nested inside a `Vec<T>`/`Option<T>`) is rewritten to
+/// point at the surviving copy's fully qualified name. Returns the names of the types that were
+/// hoisted this way.
+///
+/// Unification never needs to fall back to a type alias for a user-visible name clash: two
+/// structurally identical nested types only reach this pass with *different* names in the first
+/// place (identical sibling field names under the same parent would already collide in the
+/// source ISL), so hoisting one under its own name and redirecting the other's references to it
+/// can't shadow anything.
+pub(crate) fn dedup_nested_types(nodes: &mut Vec<DataModelNode>) -> Vec<String> {
+    let mut seen: HashMap<String, DataModelNode> = HashMap::new();
+    let mut rewrites: HashMap<Vec<String>, Vec<String>> = HashMap::new();
+    let mut hoisted_names = Vec::new();
+
+    for node in nodes.iter_mut() {
+        dedup_nested_types_in(node, &mut seen, &mut rewrites, &mut hoisted_names);
+    }
+
+    for canonical in seen.into_values() {
+        if !nodes.iter().any(|n| n.name == canonical.name) {
+            nodes.push(canonical);
+        }
+    }
+
+    if !rewrites.is_empty() {
+        for node in nodes.iter_mut() {
+            rewrite_references_in(node, &rewrites);
+        }
+    }
+
+    hoisted_names
+}
+
+fn dedup_nested_types_in(
+    node: &mut DataModelNode,
+    seen: &mut HashMap<String, DataModelNode>,
+    rewrites: &mut HashMap<Vec<String>, Vec<String>>,
+    hoisted_names: &mut Vec<String>,
+) {
+    let mut remaining = Vec::with_capacity(node.nested_types.len());
+    for mut nested in std::mem::take(&mut node.nested_types) {
+        dedup_nested_types_in(&mut nested, seen, rewrites, hoisted_names);
+
+        let Some(signature) = nested
+            .code_gen_type
+            .as_ref()
+            .map(|code_gen_type| code_gen_type.structural_signature())
+        else {
+            // A node with no `code_gen_type` (e.g. a bare module/package) is never a duplicate.
+            remaining.push(nested);
+            continue;
+        };
+
+        if let Some(existing) = seen.get(&signature) {
+            let existing_name = existing.name.clone();
+            if !hoisted_names.contains(&existing_name) {
+                hoisted_names.push(existing_name);
+            }
+            if let (Some(duplicate_name), Some(canonical_name)) = (
+                nested
+                    .code_gen_type
+                    .as_ref()
+                    .and_then(|t| t.canonical_name()),
+                existing
+                    .code_gen_type
+                    .as_ref()
+                    .and_then(|t| t.canonical_name()),
+            ) {
+                if duplicate_name != canonical_name {
+                    rewrites.insert(duplicate_name.clone(), canonical_name.clone());
+                }
+            }
+            continue;
+        }
+
+        seen.insert(signature, nested.clone());
+        remaining.push(nested);
+    }
+    node.nested_types = remaining;
+}
+
+fn rewrite_references_in(node: &mut DataModelNode, rewrites: &HashMap<Vec<String>, Vec<String>>) {
+    if let Some(code_gen_type) = &mut node.code_gen_type {
+        code_gen_type.rewrite_type_references(rewrites);
+    }
+    for nested in node.nested_types.iter_mut() {
+        rewrite_references_in(nested, rewrites);
+    }
+}
+
+/// Finds fields that form a reference cycle (a structure whose field, directly or transitively
+/// through other structures, refers back to itself) and rewrites each such field's
+/// `type_reference` through [Language::target_type_as_boxed], so the generated type has finite
+/// size instead of trying to embed itself inline. Returns `"TypeName.field_name"` for each field
+/// that was boxed this way, in the order it was found.
+///
+/// Only fields whose `type_reference` has no parameters are considered: a non-empty `parameters`
+/// (e.g. `Vec<T>`/`HashMap<K, V>`) already means the field is stored behind a container, which is
+/// indirection enough to break the cycle on its own.
+///
+/// _Note:_ like [dedup_nested_types] above, this operates purely on the resolved data model rather
+/// than the live per-type rendering pipeline, so it only sees a field's own `type_reference` and
+/// not the target language's namespace-qualified name for it
+/// ([Language::add_type_to_namespace](crate::commands::generate::utils::Language::add_type_to_namespace)).
+/// That's fine here because cycle membership only depends on which type a field's reference
+/// resolves to, not on how that reference is spelled in the target language.
+pub(crate) fn break_reference_cycles<L: Language>(nodes: &mut [DataModelNode]) -> Vec<String> {
+    let mut edges: HashMap<FullyQualifiedTypeNameKey, Vec<(String, FullyQualifiedTypeNameKey)>> =
+        HashMap::new();
+    for node in nodes.iter() {
+        collect_structure_edges(node, &mut edges);
+    }
+
+    let mut to_box: Vec<(FullyQualifiedTypeNameKey, String)> = Vec::new();
+    let mut visited = HashSet::new();
+    for start in edges.keys().cloned().collect::<Vec<_>>() {
+        let mut on_stack = HashSet::new();
+        find_back_edges(&start, &edges, &mut visited, &mut on_stack, &mut to_box);
+    }
+
+    let mut boxed_names = Vec::with_capacity(to_box.len());
+    for node in nodes.iter_mut() {
+        box_cyclic_fields::<L>(node, &to_box, &mut boxed_names);
+    }
+    boxed_names
+}
+
+// `FullyQualifiedTypeName` (`Vec<String>`) isn't `pub` outside this module, so the graph is keyed
+// on a locally-named alias of the same shape.
+type FullyQualifiedTypeNameKey = Vec<String>;
+
+fn collect_structure_edges(
+    node: &DataModelNode,
+    edges: &mut HashMap<FullyQualifiedTypeNameKey, Vec<(String, FullyQualifiedTypeNameKey)>>,
+) {
+    if let Some(AbstractDataType::Structure(structure)) = &node.code_gen_type {
+        let mut fields: Vec<_> = structure.fields.iter().collect();
+        fields.sort_by(|(a, _), (b, _)| a.cmp(b));
+        let field_edges = fields
+            .into_iter()
+            .filter(|(_, field)| field.type_reference.parameters.is_empty())
+            .map(|(field_name, field)| (field_name.clone(), field.type_reference.type_name.clone()))
+            .collect();
+        edges.insert(structure.name.clone(), field_edges);
+    }
+    for nested in &node.nested_types {
+        collect_structure_edges(nested, edges);
+    }
+}
+
+fn find_back_edges(
+    node_name: &FullyQualifiedTypeNameKey,
+    edges: &HashMap<FullyQualifiedTypeNameKey, Vec<(String, FullyQualifiedTypeNameKey)>>,
+    visited: &mut HashSet<FullyQualifiedTypeNameKey>,
+    on_stack: &mut HashSet<FullyQualifiedTypeNameKey>,
+    to_box: &mut Vec<(FullyQualifiedTypeNameKey, String)>,
+) {
+    if !visited.insert(node_name.clone()) {
+        return;
+    }
+    on_stack.insert(node_name.clone());
+
+    if let Some(field_edges) = edges.get(node_name) {
+        for (field_name, target_name) in field_edges {
+            if on_stack.contains(target_name) {
+                to_box.push((node_name.clone(), field_name.clone()));
+            } else if !visited.contains(target_name) {
+                find_back_edges(target_name, edges, visited, on_stack, to_box);
+            }
+        }
+    }
+
+    on_stack.remove(node_name);
+}
+
+fn box_cyclic_fields<L: Language>(
+    node: &mut DataModelNode,
+    to_box: &[(FullyQualifiedTypeNameKey, String)],
+    boxed_names: &mut Vec<String>,
+) {
+    if let Some(AbstractDataType::Structure(structure)) = &mut node.code_gen_type {
+        for (type_name, field_name) in to_box {
+            if type_name != &structure.name {
+                continue;
+            }
+            if let Some(field) = structure.fields.get_mut(field_name) {
+                field.type_reference = L::target_type_as_boxed(field.type_reference.clone());
+                boxed_names.push(format!("{}.{}", node.name, field_name));
+            }
+        }
+    }
+    for nested in &mut node.nested_types {
+        box_cyclic_fields::<L>(nested, to_box, boxed_names);
+    }
+}
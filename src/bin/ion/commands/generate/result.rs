@@ -1,13 +1,35 @@
 use crate::commands::generate::model::{
     EnumBuilderError, ScalarBuilderError, SequenceBuilderError, StructureBuilderError,
-    WrappedScalarBuilderError, WrappedSequenceBuilderError,
+    TupleBuilderError, UnionBuilderError, WrappedScalarBuilderError, WrappedSequenceBuilderError,
 };
 use ion_schema::result::IonSchemaError;
+use std::fmt;
 use thiserror::Error;
 
 /// Represents code generation result
 pub type CodeGenResult<T> = Result<T, CodeGenError>;
 
+/// Separates the constraint(s) a `build_*_from_constraints` method could accept from the one it
+/// actually saw, modeled on rustc's `TypeError`/`ExpectedFound` pattern. Keeping the two apart
+/// lets `CodeGenError::ConflictingConstraints`'s message spell out both instead of folding them
+/// into one flat sentence.
+///
+/// Note: unlike rustc's `MultiSpan`, there's no source location attached here -- the ISL types
+/// this crate parses (`IslType`/`IslConstraint`) don't carry a position/span back to the
+/// originating document, so a `CodeGenError` can only name the constraint and the type being
+/// built, not point at the line that declared it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExpectedFound<T> {
+    pub expected: T,
+    pub found: T,
+}
+
+impl<T: fmt::Display> fmt::Display for ExpectedFound<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "expected {}, found {}", self.expected, self.found)
+    }
+}
+
 /// Represents an error found during code generation
 #[derive(Debug, Error)]
 pub enum CodeGenError {
@@ -30,6 +52,39 @@ pub enum CodeGenError {
     InvalidDataModel { description: String },
     #[error("{description}")]
     DataModelBuilderError { description: String },
+    /// A type definition declared the same constraint more than once, e.g. two `type`
+    /// constraints on one type -- code generation needs exactly one to pick a base type from.
+    #[error("type '{type_name}': duplicate `{constraint_name}` constraint (a type definition may only declare `{constraint_name}` once)")]
+    DuplicateConstraint {
+        type_name: String,
+        constraint_name: String,
+    },
+    /// A type definition's constraints didn't match any `AbstractDataType` this `build_*` method
+    /// knows how to build, e.g. a constraint the corresponding builder doesn't recognize at all.
+    #[error("type '{type_name}': {expected_found}")]
+    ConflictingConstraints {
+        type_name: String,
+        expected_found: ExpectedFound<String>,
+    },
+}
+
+/// A single unsupported-constraint diagnostic collected while resolving an ISL type into a
+/// `DataModelNode`, instead of aborting code generation at the first one. See
+/// `CodeGenerator::diagnostics` and `CodeGenerator::generate_code_for_authorities`.
+///
+/// This is unrelated to `generate::model::Diagnostic`, which describes a *runtime* validation
+/// failure reported by generated code reading malformed Ion data, not a code-generation-time
+/// limitation.
+#[derive(Debug, Clone)]
+pub struct CodeGenDiagnostic {
+    pub type_name: String,
+    pub description: String,
+}
+
+impl std::fmt::Display for CodeGenDiagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.type_name, self.description)
+    }
 }
 
 /// A convenience method for creating an CodeGen containing an CodeGenError::InvalidDataModel
@@ -48,6 +103,35 @@ pub fn invalid_abstract_data_type_raw_error<S: AsRef<str>>(description: S) -> Co
     }
 }
 
+/// A convenience method for creating a `CodeGenResult` containing a
+/// `CodeGenError::DuplicateConstraint` for the type named `type_name`.
+pub fn duplicate_constraint_error<T>(
+    type_name: impl Into<String>,
+    constraint_name: impl Into<String>,
+) -> CodeGenResult<T> {
+    Err(CodeGenError::DuplicateConstraint {
+        type_name: type_name.into(),
+        constraint_name: constraint_name.into(),
+    })
+}
+
+/// A convenience method for creating a `CodeGenResult` containing a
+/// `CodeGenError::ConflictingConstraints` for the type named `type_name`, separating the
+/// constraint(s) code generation could accept (`expected`) from the one it actually saw (`found`).
+pub fn conflicting_constraints_error<T>(
+    type_name: impl Into<String>,
+    expected: impl Into<String>,
+    found: impl Into<String>,
+) -> CodeGenResult<T> {
+    Err(CodeGenError::ConflictingConstraints {
+        type_name: type_name.into(),
+        expected_found: ExpectedFound {
+            expected: expected.into(),
+            found: found.into(),
+        },
+    })
+}
+
 impl From<WrappedScalarBuilderError> for CodeGenError {
     fn from(value: WrappedScalarBuilderError) -> Self {
         CodeGenError::DataModelBuilderError {
@@ -95,3 +179,19 @@ impl From<EnumBuilderError> for CodeGenError {
         }
     }
 }
+
+impl From<UnionBuilderError> for CodeGenError {
+    fn from(value: UnionBuilderError) -> Self {
+        CodeGenError::DataModelBuilderError {
+            description: value.to_string(),
+        }
+    }
+}
+
+impl From<TupleBuilderError> for CodeGenError {
+    fn from(value: TupleBuilderError) -> Self {
+        CodeGenError::DataModelBuilderError {
+            description: value.to_string(),
+        }
+    }
+}
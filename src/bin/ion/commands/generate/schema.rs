@@ -0,0 +1,280 @@
+use crate::commands::{CommandIo, IonCliCommand, WithIonCliArgument};
+use anyhow::{Context, Result};
+use clap::{Arg, ArgMatches, Command};
+use ion_rs::{v1_0, AnyEncoding, Element, ElementReader, IonType, List, Reader, Struct, Writer};
+
+pub struct SchemaCommand;
+
+impl IonCliCommand for SchemaCommand {
+    fn name(&self) -> &'static str {
+        "schema"
+    }
+
+    fn about(&self) -> &'static str {
+        "Infers an Ion Schema type definition from a stream of sample Ion or JSON values."
+    }
+
+    fn unstable_features(&self) -> &'static [&'static str] {
+        &["generate"]
+    }
+
+    fn is_porcelain(&self) -> bool {
+        false
+    }
+
+    fn configure_args(&self, command: Command) -> Command {
+        command.with_input().with_output().arg(
+            Arg::new("type-name")
+                .long("type-name")
+                .default_value("inferred_type")
+                .help("Name to give the top-level inferred type"),
+        )
+    }
+
+    fn run(&self, _command_path: &mut Vec<String>, args: &ArgMatches) -> Result<()> {
+        let type_name = args.get_one::<String>("type-name").unwrap().to_owned();
+        CommandIo::new(args)?.for_each_input(|output, input| {
+            let input_name = input.name().to_owned();
+            let reader = Reader::new(AnyEncoding, input.into_source())
+                .with_context(|| format!("input file '{input_name}' was not valid Ion"))?;
+
+            // `shape` accumulates a structural merge of every top-level value in a single pass
+            // over `reader` -- the stream itself is never buffered, only the (much smaller) shape
+            // being inferred from it.
+            let mut shape = TypeShape::default();
+            for value in reader.into_elements() {
+                shape.observe(&value?);
+            }
+
+            let mut inference = SchemaInference::default();
+            let top_level_ref = inference.type_reference_for(&shape);
+
+            let mut writer = Writer::new(v1_0::Text, output)?;
+            for (_name, nested_type) in inference.nested_types {
+                writer.write_element(&Element::from(nested_type).with_annotations(["type"]))?;
+            }
+            let top_level_type = Struct::builder()
+                .with_field("name", Element::symbol(type_name.as_str()))
+                .with_field("type", top_level_ref)
+                .build();
+            writer.write_element(&Element::from(top_level_type).with_annotations(["type"]))?;
+            writer.close()?;
+            Ok(())
+        })
+    }
+}
+
+/// Accumulates every alternative shape a single logical slot (a field, a sequence's elements, or
+/// a stream's top-level values) has been observed to take across however many sample values have
+/// been merged into it.
+#[derive(Default)]
+struct TypeShape {
+    /// Scalar Ion types observed directly in this slot (e.g. `int`, `string`), in first-seen
+    /// order so the emitted `one_of` has a stable, readable ordering.
+    scalars: Vec<IonType>,
+    /// The merged shape of every struct observed in this slot, if any.
+    structure: Option<Box<StructShape>>,
+    /// The merged shape of every list/sexp's elements observed in this slot, if any.
+    sequence: Option<Box<SequenceShape>>,
+    saw_list: bool,
+    saw_sexp: bool,
+    /// Whether `null` (or a typed null, e.g. `null.string`) was observed in this slot.
+    nullable: bool,
+}
+
+impl TypeShape {
+    fn observe(&mut self, element: &Element) {
+        if element.is_null() {
+            self.nullable = true;
+            return;
+        }
+        match element.ion_type() {
+            IonType::Struct => {
+                let s = element.as_struct().expect("Struct-typed element");
+                self.structure.get_or_insert_with(Default::default).observe(s);
+            }
+            IonType::List | IonType::SExp => {
+                if element.ion_type() == IonType::List {
+                    self.saw_list = true;
+                } else {
+                    self.saw_sexp = true;
+                }
+                let sequence = self.sequence.get_or_insert_with(Default::default);
+                for item in element.as_sequence().expect("sequence-typed element").into_iter() {
+                    sequence.element.observe(item);
+                }
+                // An empty list/sexp still marks the slot as "seen as a sequence" (the
+                // `get_or_insert_with` above) even though it contributes no element shape, so
+                // "seen, but no elements ever observed" (`element_constrained` is false, leaving
+                // the element type unconstrained) stays distinct from "never seen" (no sequence
+                // alternative at all).
+                sequence.records_seen += 1;
+            }
+            scalar => {
+                if !self.scalars.contains(&scalar) {
+                    self.scalars.push(scalar);
+                }
+            }
+        }
+    }
+
+    /// The number of mutually exclusive alternatives this slot has been observed to take
+    /// (ignoring nullability, which is layered on separately via `nullable::`).
+    fn alternative_count(&self) -> usize {
+        self.scalars.len()
+            + self.structure.is_some() as usize
+            + self.sequence.is_some() as usize
+    }
+}
+
+#[derive(Default)]
+struct StructShape {
+    /// `(field name, merged shape, times observed present)`, in first-seen order.
+    fields: Vec<(String, TypeShape, usize)>,
+    records_seen: usize,
+}
+
+impl StructShape {
+    fn observe(&mut self, s: &Struct) {
+        self.records_seen += 1;
+        for (name, value) in s.iter() {
+            let name = name.text().unwrap_or_default();
+            match self.fields.iter_mut().find(|(n, _, _)| n == name) {
+                Some((_, shape, present_count)) => {
+                    shape.observe(value);
+                    *present_count += 1;
+                }
+                None => {
+                    let mut shape = TypeShape::default();
+                    shape.observe(value);
+                    self.fields.push((name.to_string(), shape, 1));
+                }
+            }
+        }
+    }
+}
+
+#[derive(Default)]
+struct SequenceShape {
+    element: TypeShape,
+    records_seen: usize,
+}
+
+impl SequenceShape {
+    /// A sequence whose elements were sampled but which never held an element is left with an
+    /// unconstrained element type, per the empty-list edge case.
+    fn element_constrained(&self) -> bool {
+        self.element.alternative_count() > 0 || self.element.nullable
+    }
+}
+
+/// Resolves [`TypeShape`]s into Ion Schema type references, hoisting every observed struct shape
+/// out into its own named nested type the same way code generation accumulates `nested_types`
+/// under a `CodeGenContext` rather than inlining them.
+#[derive(Default)]
+struct SchemaInference {
+    nested_types: Vec<(String, Struct)>,
+}
+
+impl SchemaInference {
+    fn fresh_nested_type_name(&mut self) -> String {
+        format!("NestedType{}", self.nested_types.len() + 1)
+    }
+
+    /// Builds the type reference for `shape`, registering a nested type definition (and returning
+    /// a reference to it by name) for any struct shape it contains.
+    fn type_reference_for(&mut self, shape: &TypeShape) -> Element {
+        let mut alternatives = Vec::new();
+        for scalar in &shape.scalars {
+            alternatives.push(Element::symbol(isl_builtin_type_name(*scalar)));
+        }
+        if let Some(structure) = &shape.structure {
+            alternatives.push(self.register_struct_type(structure));
+        }
+        if let Some(sequence) = &shape.sequence {
+            alternatives.push(self.sequence_type_reference(shape, sequence));
+        }
+
+        let reference = match alternatives.len() {
+            // Never observed a non-null value for this slot; `nullable` must be set, or there was
+            // no data at all for it (an empty input stream).
+            0 => Element::symbol("$null"),
+            1 => alternatives.into_iter().next().unwrap(),
+            _ => Element::from(
+                Struct::builder()
+                    .with_field("one_of", Element::from(List::from_iter(alternatives)))
+                    .build(),
+            ),
+        };
+
+        if shape.nullable {
+            reference.with_annotations(["nullable"])
+        } else {
+            reference
+        }
+    }
+
+    fn sequence_type_reference(&mut self, shape: &TypeShape, sequence: &SequenceShape) -> Element {
+        let sequence_type_name = if shape.saw_list || !shape.saw_sexp {
+            "list"
+        } else {
+            "sexp"
+        };
+        let mut builder =
+            Struct::builder().with_field("type", Element::symbol(sequence_type_name));
+        if sequence.element_constrained() {
+            let element_ref = self.type_reference_for(&sequence.element);
+            builder = builder.with_field("element", element_ref);
+        }
+        Element::from(builder.build())
+    }
+
+    /// Registers `structure` as a new named nested type (recursing into its fields first, so
+    /// doubly-nested structs are registered before the type that references them) and returns a
+    /// by-name reference to it.
+    fn register_struct_type(&mut self, structure: &StructShape) -> Element {
+        let mut fields_builder = Struct::builder();
+        for (name, field_shape, present_count) in &structure.fields {
+            let field_reference = self.type_reference_for(field_shape);
+            let field_reference = if *present_count < structure.records_seen {
+                Element::from(
+                    Struct::builder()
+                        .with_field("type", field_reference)
+                        .with_field("occurs", Element::symbol("optional"))
+                        .build(),
+                )
+            } else {
+                field_reference
+            };
+            fields_builder = fields_builder.with_field(name.as_str(), field_reference);
+        }
+
+        let name = self.fresh_nested_type_name();
+        let type_def = Struct::builder()
+            .with_field("name", Element::symbol(name.as_str()))
+            .with_field("type", Element::symbol("struct"))
+            .with_field("fields", Element::from(fields_builder.build()))
+            .build();
+        self.nested_types.push((name.clone(), type_def));
+        Element::symbol(name)
+    }
+}
+
+/// Maps an observed scalar [`IonType`] to the Ion Schema built-in type name that matches it.
+fn isl_builtin_type_name(ion_type: IonType) -> &'static str {
+    match ion_type {
+        IonType::Null => "$null",
+        IonType::Bool => "bool",
+        IonType::Int => "int",
+        IonType::Float => "float",
+        IonType::Decimal => "decimal",
+        IonType::Timestamp => "timestamp",
+        IonType::Symbol => "symbol",
+        IonType::String => "string",
+        IonType::Clob => "clob",
+        IonType::Blob => "blob",
+        IonType::List => "list",
+        IonType::SExp => "sexp",
+        IonType::Struct => "struct",
+    }
+}
@@ -18,8 +18,12 @@ pub(crate) mod java {
     pub(crate) const SCALAR: &str = include_template!("java/scalar.templ");
     pub(crate) const SEQUENCE: &str = include_template!("java/sequence.templ");
     pub(crate) const ENUM: &str = include_template!("java/enum.templ");
+    pub(crate) const RECORD: &str = include_template!("java/record.templ");
     pub(crate) const UTIL_MACROS: &str = include_template!("java/util_macros.templ");
     pub(crate) const NESTED_TYPE: &str = include_template!("java/nested_type.templ");
+    /// A constraint-checking macro for the opt-in `--with-validation` code-generation mode (see
+    /// `CodeGenerator::with_validation`), rendered alongside `UTIL_MACROS`.
+    pub(crate) const VALIDATE: &str = include_template!("java/validate.templ");
 }
 
 /// Represents rust template constants
@@ -28,8 +32,44 @@ pub(crate) mod rust {
     pub(crate) const SCALAR: &str = include_template!("rust/scalar.templ");
     pub(crate) const SEQUENCE: &str = include_template!("rust/sequence.templ");
     pub(crate) const ENUM: &str = include_template!("rust/enum.templ");
+    pub(crate) const TUPLE: &str = include_template!("rust/tuple.templ");
     pub(crate) const UTIL_MACROS: &str = include_template!("rust/util_macros.templ");
     pub(crate) const RESULT: &str = include_template!("rust/result.templ");
     pub(crate) const NESTED_TYPE: &str = include_template!("rust/nested_type.templ");
     pub(crate) const IMPORT: &str = include_template!("rust/import.templ");
+    /// A constraint-checking macro for the opt-in `--with-validation` code-generation mode (see
+    /// `CodeGenerator::with_validation`), rendered alongside `UTIL_MACROS`.
+    pub(crate) const VALIDATE: &str = include_template!("rust/validate.templ");
+}
+
+/// Represents python template constants
+pub(crate) mod python {
+    pub(crate) const DATACLASS: &str = include_template!("python/dataclass.templ");
+    pub(crate) const SCALAR: &str = include_template!("python/scalar.templ");
+    pub(crate) const SEQUENCE: &str = include_template!("python/sequence.templ");
+    pub(crate) const NESTED_TYPE: &str = include_template!("python/nested_type.templ");
+    pub(crate) const IMPORT: &str = include_template!("python/import.templ");
+}
+
+/// Represents typescript template constants
+pub(crate) mod typescript {
+    pub(crate) const INTERFACE: &str = include_template!("typescript/interface.templ");
+    pub(crate) const SCALAR: &str = include_template!("typescript/scalar.templ");
+    pub(crate) const SEQUENCE: &str = include_template!("typescript/sequence.templ");
+    pub(crate) const ENUM: &str = include_template!("typescript/enum.templ");
+    pub(crate) const NESTED_TYPE: &str = include_template!("typescript/nested_type.templ");
+    pub(crate) const IMPORT: &str = include_template!("typescript/import.templ");
+    /// A `isX(value): value is X` type guard predicate, rendered alongside each generated
+    /// interface so consumers can narrow an `unknown` value (e.g. parsed JSON/Ion) to the
+    /// generated type without a separate hand-written runtime check.
+    pub(crate) const TYPE_GUARD: &str = include_template!("typescript/type_guard.templ");
+}
+
+/// Represents kotlin template constants
+pub(crate) mod kotlin {
+    pub(crate) const DATA_CLASS: &str = include_template!("kotlin/data_class.templ");
+    pub(crate) const SCALAR: &str = include_template!("kotlin/scalar.templ");
+    pub(crate) const SEQUENCE: &str = include_template!("kotlin/sequence.templ");
+    pub(crate) const ENUM: &str = include_template!("kotlin/enum.templ");
+    pub(crate) const NESTED_TYPE: &str = include_template!("kotlin/nested_type.templ");
 }
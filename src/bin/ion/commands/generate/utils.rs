@@ -3,6 +3,7 @@ use crate::commands::generate::model::{
 };
 use crate::commands::generate::result::{invalid_abstract_data_type_error, CodeGenError};
 use convert_case::{Case, Casing};
+use serde::Serialize;
 use std::fmt::{Display, Formatter};
 
 pub trait Language {
@@ -18,10 +19,23 @@ pub trait Language {
     ///     In Java, this will return a string casing `name` to  [Case::UpperCamel]
     fn file_name_for_type(name: &str) -> String;
 
+    /// Returns the case convention this language uses to render field identifiers, modeled on
+    /// serde's `rename_all` (see <https://serde.rs/container-attrs.html#rename_all>).
+    /// e.g. Java defaults to [FieldNameCase::Camel], Rust defaults to [FieldNameCase::Snake].
+    fn field_name_case() -> FieldNameCase;
+
     /// Maps the given ISL type to a target type name
     /// Returns None when the given ISL type is `struct`, `list` or `sexp` as open-ended types are not supported currently.
     fn target_type(ion_schema_type: &IonSchemaType) -> Option<String>;
 
+    /// Returns `name` unchanged unless it collides with one of this language's reserved words
+    /// (keywords/literals that can't be used as an identifier), in which case it returns an
+    /// escaped form that is still a valid identifier, e.g. a schema type or field named `class`
+    /// or `yield`. Applied to every generated type name, module/namespace segment, and file name
+    /// so a schema can be code-generated without manual renaming, the way uniffi's generators
+    /// escape target-language keywords for their Python/Ruby/Kotlin backends.
+    fn escape_reserved_word(name: &str) -> String;
+
     /// Provides given target type as sequence
     /// e.g.
     ///     target_type = "Foo" returns "java.util.ArrayList<Foo>"
@@ -51,6 +65,16 @@ pub trait Language {
     ///     In Java, Template::Struct -> "class"
     fn template_name(template: &Template) -> String;
 
+    /// Returns this language's conventional name for the method a generated type uses to read an
+    /// instance of itself from an Ion reader, following the parser/unparser split.
+    /// e.g. In Rust, `read_from`. In Java, `readFrom`.
+    fn read_method_name() -> String;
+
+    /// Returns this language's conventional name for the method a generated type uses to write an
+    /// instance of itself to an Ion writer, following the parser/unparser split.
+    /// e.g. In Rust, `write_to`. In Java, `writeTo`.
+    fn write_method_name() -> String;
+
     /// Returns the namespace separator for programming language
     /// e.g. In Java, it returns "::"
     ///      In Rust, it returns "."
@@ -77,8 +101,150 @@ pub trait Language {
     fn target_type_as_optional(
         target_type: FullyQualifiedTypeReference,
     ) -> FullyQualifiedTypeReference;
+
+    /// Returns the `FullyQualifiedTypeReference` that represents `target_type` when the *value*
+    /// itself may be `null` (ISL's `$int`/`$string`/... nullable built-in types), as distinct from
+    /// [target_type_as_optional](Self::target_type_as_optional) (which represents a field that may
+    /// be absent entirely). Defaults to returning `target_type` unchanged, which is correct for
+    /// any language whose target type is already a reference type that can hold `null`/`None`.
+    /// e.g. Rust overrides this to `Option<T>`, since its primitive targets (`i64`, `bool`, ...)
+    /// can't otherwise represent `null`; Java overrides this to box a primitive target (e.g. `int`
+    /// becomes `Integer`) without adding `java.util.Optional`.
+    fn target_type_as_nullable(
+        target_type: FullyQualifiedTypeReference,
+    ) -> FullyQualifiedTypeReference {
+        target_type
+    }
+
+    /// Returns the `FullyQualifiedTypeReference` that represents `target_type` behind whatever
+    /// indirection this language needs to break a reference cycle (e.g. a tree node whose field
+    /// points back to itself), so the generated type has finite size. Defaults to returning
+    /// `target_type` unchanged, which is correct for any language whose target type is already a
+    /// heap reference (Java, Python, TypeScript all generate reference types, so a cyclic field
+    /// is already indirect). Rust overrides this to `Box<T>`, since its struct fields are stored
+    /// inline.
+    fn target_type_as_boxed(
+        target_type: FullyQualifiedTypeReference,
+    ) -> FullyQualifiedTypeReference {
+        target_type
+    }
+
+    /// Provides the given key/value types as a map in the target programming language
+    /// e.g.
+    ///     key_type = "String", value_type = "Foo" returns "java.util.HashMap<String, Foo>"
+    ///     key_type = "String", value_type = "Foo" returns "HashMap<String, Foo>"
+    #[allow(dead_code)]
+    fn target_type_as_map(
+        key_type: FullyQualifiedTypeReference,
+        value_type: FullyQualifiedTypeReference,
+    ) -> FullyQualifiedTypeReference;
+
+    /// Returns any import statements/declarations the generated file needs at the top, e.g. for a
+    /// runtime support module. Most languages need none of these, so the default is empty.
+    fn imports() -> Vec<String> {
+        Vec::new()
+    }
+
+    /// Returns the open/close brackets used to render a parameterized type's generic arguments,
+    /// e.g. Java/Rust use `("<", ">")`, Python's `typing` generics use `("[", "]")`.
+    fn generic_parameter_brackets() -> (&'static str, &'static str) {
+        ("<", ">")
+    }
+
+    /// Derive/decorator attributes this language wants on every generated type by default (e.g. a
+    /// Rust target injecting `#[derive(Serialize, Deserialize)]`), before any `--config`-supplied
+    /// overrides are layered on top. Most languages need none of these, so the default is empty.
+    fn default_derives() -> Vec<String> {
+        Vec::new()
+    }
+
+    /// Annotations this language wants on every generated type by default (e.g. a Java target
+    /// injecting `@JsonProperty`/Lombok annotations), before any `--config`-supplied overrides are
+    /// layered on top. Most languages need none of these, so the default is empty.
+    fn default_annotations() -> Vec<String> {
+        Vec::new()
+    }
+}
+
+/// Case convention used to render a field identifier, modeled on serde's `rename_all`
+/// (see <https://serde.rs/container-attrs.html#rename_all>).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FieldNameCase {
+    Camel,
+    Pascal,
+    Snake,
+    ScreamingSnake,
+    Kebab,
 }
 
+impl FieldNameCase {
+    /// Converts the given ISL field name (e.g. `foo_bar`) to this case convention.
+    pub fn convert(&self, original_name: &str) -> String {
+        let case = match self {
+            FieldNameCase::Camel => Case::Camel,
+            FieldNameCase::Pascal => Case::Pascal,
+            FieldNameCase::Snake => Case::Snake,
+            FieldNameCase::ScreamingSnake => Case::ScreamingSnake,
+            FieldNameCase::Kebab => Case::Kebab,
+        };
+        original_name.to_case(case)
+    }
+}
+
+/// The Ion encoding a generated type's `read`/`write` methods should target, mirroring ion-rs's
+/// `TextFormat::Pretty`/`TextFormat::Compact`/`v1_0::Binary` encodings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Default)]
+pub enum Format {
+    #[default]
+    PrettyText,
+    CompactText,
+    Binary,
+}
+
+impl Display for Format {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Format::PrettyText => "pretty",
+            Format::CompactText => "compact",
+            Format::Binary => "binary",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// The digest algorithm a generated type's (opt-in) Ion Hash method should use, analogous to
+/// [Format] for the output encoding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Default)]
+pub enum DigestAlgorithm {
+    #[default]
+    Sha256,
+    Sha1,
+    Sha512,
+}
+
+impl Display for DigestAlgorithm {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            DigestAlgorithm::Sha256 => "sha256",
+            DigestAlgorithm::Sha1 => "sha1",
+            DigestAlgorithm::Sha512 => "sha512",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// Java keywords and literals (see <https://docs.oracle.com/javase/specs/jls/se17/html/jls-3.html#jls-3.9>)
+/// that can't be used as an identifier and so must be escaped by [JavaLanguage::escape_reserved_word].
+const JAVA_RESERVED_WORDS: &[&str] = &[
+    "abstract", "assert", "boolean", "break", "byte", "case", "catch", "char", "class", "const",
+    "continue", "default", "do", "double", "else", "enum", "extends", "final", "finally", "float",
+    "for", "goto", "if", "implements", "import", "instanceof", "int", "interface", "long",
+    "native", "new", "package", "private", "protected", "public", "return", "short", "static",
+    "strictfp", "super", "switch", "synchronized", "this", "throw", "throws", "transient", "try",
+    "void", "volatile", "while", "true", "false", "null", "var", "yield", "record", "sealed",
+    "permits",
+];
+
 pub struct JavaLanguage;
 
 impl Language for JavaLanguage {
@@ -91,23 +257,40 @@ impl Language for JavaLanguage {
     }
 
     fn file_name_for_type(name: &str) -> String {
-        name.to_case(Case::UpperCamel)
+        JavaLanguage::escape_reserved_word(&name.to_case(Case::UpperCamel))
+    }
+
+    fn field_name_case() -> FieldNameCase {
+        FieldNameCase::Camel
     }
 
     fn target_type(ion_schema_type: &IonSchemaType) -> Option<String> {
-        use IonSchemaType::*;
-        Some(
-            match ion_schema_type {
-                Int => "int",
-                String | Symbol => "String",
-                Float => "double",
-                Bool => "boolean",
-                Blob | Clob => "byte[]",
-                List | SExp | Struct => return None,
-                SchemaDefined(name) => name,
-            }
-            .to_string(),
-        )
+        use IonSchemaTypeKind::*;
+        let name = match ion_schema_type.kind() {
+            Int => "int",
+            String | Symbol | Text => "String",
+            Float => "double",
+            Bool => "boolean",
+            Blob | Clob | Lob => "byte[]",
+            Decimal | Number => "java.math.BigDecimal",
+            Timestamp => "com.amazon.ion.Timestamp",
+            Document => "com.amazon.ion.IonValue",
+            List | SExp | Struct => return None,
+            SchemaDefined(name) => name,
+        }
+        .to_string();
+        Some(match ion_schema_type.kind() {
+            SchemaDefined(_) => JavaLanguage::escape_reserved_word(&name),
+            _ => name,
+        })
+    }
+
+    fn escape_reserved_word(name: &str) -> String {
+        if JAVA_RESERVED_WORDS.contains(&name) {
+            format!("{name}_")
+        } else {
+            name.to_string()
+        }
     }
 
     fn target_type_as_sequence(
@@ -139,10 +322,30 @@ impl Language for JavaLanguage {
     fn is_built_in_type(type_name: String) -> bool {
         matches!(
             type_name.as_str(),
-            "int" | "String" | "boolean" | "byte[]" | "double"
+            "int" | "String"
+                | "boolean"
+                | "byte[]"
+                | "double"
+                | "java.math.BigDecimal"
+                | "com.amazon.ion.Timestamp"
+                | "com.amazon.ion.IonValue"
         )
     }
 
+    fn target_type_as_map(
+        key_type: FullyQualifiedTypeReference,
+        value_type: FullyQualifiedTypeReference,
+    ) -> FullyQualifiedTypeReference {
+        FullyQualifiedTypeReference {
+            type_name: vec![
+                "java".to_string(),
+                "util".to_string(),
+                "HashMap".to_string(),
+            ],
+            parameters: vec![key_type, value_type],
+        }
+    }
+
     fn fully_qualified_type_ref(name: &FullyQualifiedTypeReference) -> String {
         name.type_name.join(".")
     }
@@ -152,9 +355,19 @@ impl Language for JavaLanguage {
             Template::Struct => "class".to_string(),
             Template::Scalar => "scalar".to_string(),
             Template::Sequence => "sequence".to_string(),
+            Template::Enum => "enum".to_string(),
+            Template::Tuple => "record".to_string(),
         }
     }
 
+    fn read_method_name() -> String {
+        "readFrom".to_string()
+    }
+
+    fn write_method_name() -> String {
+        "writeTo".to_string()
+    }
+
     fn namespace_separator() -> &'static str {
         "."
     }
@@ -164,7 +377,9 @@ impl Language for JavaLanguage {
         type_name: &String,
         namespace: &mut Vec<String>,
     ) {
-        namespace.push(type_name.to_case(Case::UpperCamel))
+        namespace.push(JavaLanguage::escape_reserved_word(
+            &type_name.to_case(Case::UpperCamel),
+        ))
     }
 
     fn target_type_as_optional(
@@ -192,6 +407,26 @@ impl Language for JavaLanguage {
             },
         }
     }
+
+    fn target_type_as_nullable(
+        target_type: FullyQualifiedTypeReference,
+    ) -> FullyQualifiedTypeReference {
+        // Unlike `target_type_as_optional` (an absent field, wrapped in `java.util.Optional`), a
+        // nullable *value* is represented the way Java already represents "no value" on a
+        // reference type: `null`. So a primitive target just needs boxing (`int` -> `Integer`) to
+        // be able to hold `null` at all; anything already a reference type is left as-is.
+        match JavaLanguage::wrapper_class(&target_type.string_representation::<JavaLanguage>()) {
+            Some(wrapper_name) => FullyQualifiedTypeReference {
+                type_name: vec![wrapper_name],
+                parameters: vec![],
+            },
+            None => target_type,
+        }
+    }
+
+    // `target_type_as_boxed` is left at its default (identity): every Java target type, wrapper
+    // classes included, is already a reference, so a field that cyclically refers back to its own
+    // type doesn't need any extra indirection to have finite size.
 }
 
 impl JavaLanguage {
@@ -227,6 +462,19 @@ impl Display for JavaLanguage {
     }
 }
 
+/// Rust's strict and reserved-for-future-use keywords (see
+/// <https://doc.rust-lang.org/reference/keywords.html>) that can't be used as an identifier
+/// as-is and so must be escaped by [RustLanguage::escape_reserved_word]. Excludes `self`/`Self`/
+/// `super`/`crate`/`extern`, which [RustLanguage::escape_reserved_word] special-cases since raw
+/// identifiers (`r#...`) can't be used for them either.
+const RUST_RESERVED_WORDS: &[&str] = &[
+    "as", "break", "const", "continue", "dyn", "else", "enum", "false", "fn", "for", "if", "impl",
+    "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref", "return", "static", "struct",
+    "trait", "true", "type", "unsafe", "use", "where", "while", "async", "await", "try",
+    "abstract", "become", "box", "do", "final", "macro", "override", "priv", "typeof", "unsized",
+    "virtual", "yield",
+];
+
 pub struct RustLanguage;
 
 impl Language for RustLanguage {
@@ -242,20 +490,42 @@ impl Language for RustLanguage {
         "ion_generated_code".to_string()
     }
 
+    fn field_name_case() -> FieldNameCase {
+        FieldNameCase::Snake
+    }
+
     fn target_type(ion_schema_type: &IonSchemaType) -> Option<String> {
-        use IonSchemaType::*;
-        Some(
-            match ion_schema_type {
-                Int => "i64",
-                String | Symbol => "String",
-                Float => "f64",
-                Bool => "bool",
-                Blob | Clob => "Vec<u8>",
-                List | SExp | Struct => return None,
-                SchemaDefined(name) => name,
-            }
-            .to_string(),
-        )
+        use IonSchemaTypeKind::*;
+        let name = match ion_schema_type.kind() {
+            Int => "i64",
+            String | Symbol | Text => "String",
+            Float => "f64",
+            Bool => "bool",
+            Blob | Clob | Lob => "Vec<u8>",
+            Decimal | Number => "rust_decimal::Decimal",
+            Timestamp => "ion_rs::Timestamp",
+            Document => "ion_rs::Element",
+            List | SExp | Struct => return None,
+            SchemaDefined(name) => name,
+        }
+        .to_string();
+        Some(match ion_schema_type.kind() {
+            SchemaDefined(_) => RustLanguage::escape_reserved_word(&name),
+            _ => name,
+        })
+    }
+
+    fn escape_reserved_word(name: &str) -> String {
+        // `self`/`Self`/`super`/`crate`/`extern` can't be used as raw identifiers (`r#self` is
+        // still a syntax error), so fall back to the same trailing-underscore convention the
+        // other backends use for those few words.
+        if matches!(name, "self" | "Self" | "super" | "crate" | "extern") {
+            format!("{name}_")
+        } else if RUST_RESERVED_WORDS.contains(&name) {
+            format!("r#{name}")
+        } else {
+            name.to_string()
+        }
     }
 
     fn target_type_as_sequence(
@@ -270,7 +540,13 @@ impl Language for RustLanguage {
     fn is_built_in_type(type_name: String) -> bool {
         matches!(
             type_name.as_str(),
-            "i64" | "String" | "bool" | "Vec<u8>" | "f64"
+            "i64" | "String"
+                | "bool"
+                | "Vec<u8>"
+                | "f64"
+                | "rust_decimal::Decimal"
+                | "ion_rs::Timestamp"
+                | "ion_rs::Element"
         )
     }
 
@@ -283,9 +559,19 @@ impl Language for RustLanguage {
             Template::Struct => "struct".to_string(),
             Template::Scalar => "scalar".to_string(),
             Template::Sequence => "sequence".to_string(),
+            Template::Enum => "enum".to_string(),
+            Template::Tuple => "tuple".to_string(),
         }
     }
 
+    fn read_method_name() -> String {
+        "read_from".to_string()
+    }
+
+    fn write_method_name() -> String {
+        "write_to".to_string()
+    }
+
     fn namespace_separator() -> &'static str {
         "::"
     }
@@ -314,19 +600,65 @@ impl Language for RustLanguage {
             // So that the final namespace path for `NestedType` will become `foo::nested_type::NestedType`
             namespace.pop(); // Remove the parent struct/enum
         }
-        namespace.push(type_name.to_case(Case::Snake)); // Add this type's module name to the namespace path
-        namespace.push(type_name.to_case(Case::UpperCamel)) // Add this type itself to the namespace path
+        namespace.push(RustLanguage::escape_reserved_word(
+            &type_name.to_case(Case::Snake),
+        )); // Add this type's module name to the namespace path
+        namespace.push(RustLanguage::escape_reserved_word(
+            &type_name.to_case(Case::UpperCamel),
+        )) // Add this type itself to the namespace path
     }
 
     fn target_type_as_optional(
         target_type: FullyQualifiedTypeReference,
     ) -> FullyQualifiedTypeReference {
-        // TODO: un-comment following block for optional support in Rust, once the templates are changes accordingly
-        // FullyQualifiedTypeReference {
-        //     type_name: vec!["Option".to_string()],
-        //     parameters: vec![target_type],
-        // }
-        target_type
+        FullyQualifiedTypeReference {
+            type_name: vec!["Option".to_string()],
+            parameters: vec![target_type],
+        }
+    }
+
+    fn target_type_as_nullable(
+        target_type: FullyQualifiedTypeReference,
+    ) -> FullyQualifiedTypeReference {
+        FullyQualifiedTypeReference {
+            type_name: vec!["Option".to_string()],
+            parameters: vec![target_type],
+        }
+    }
+
+    fn target_type_as_boxed(
+        target_type: FullyQualifiedTypeReference,
+    ) -> FullyQualifiedTypeReference {
+        FullyQualifiedTypeReference {
+            type_name: vec!["Box".to_string()],
+            parameters: vec![target_type],
+        }
+    }
+
+    fn target_type_as_map(
+        key_type: FullyQualifiedTypeReference,
+        value_type: FullyQualifiedTypeReference,
+    ) -> FullyQualifiedTypeReference {
+        FullyQualifiedTypeReference {
+            type_name: vec!["HashMap".to_string()],
+            parameters: vec![key_type, value_type],
+        }
+    }
+
+    fn imports() -> Vec<String> {
+        vec!["use serde::{Deserialize, Serialize};".to_string()]
+    }
+
+    // Every generated Rust type carries `#[derive(Serialize, Deserialize)]` by default (layered
+    // under the `rust_derives()` value-semantics set at the `derives` call site in
+    // `CodeGenerator::convert_isl_type_def_to_data_model_node`), so the struct round-trips through
+    // `serde_json`/`serde`-based pipelines without hand-editing. The per-field `#[serde(rename =
+    // "...")]`/`#[serde(default)]`/`#[serde(skip_serializing_if = "Option::is_none")]` attributes
+    // this enables are driven by `FieldReference::original_name`/`generated_name`/`presence`/
+    // `nullable`, which the `rust::STRUCT` Tera template reads directly -- no further per-field
+    // state needs to be precomputed here.
+    fn default_derives() -> Vec<String> {
+        vec!["Serialize".to_string(), "Deserialize".to_string()]
     }
 }
 
@@ -336,6 +668,516 @@ impl Display for RustLanguage {
     }
 }
 
+/// Generates Python dataclasses from a resolved data model, alongside `JavaLanguage`/
+/// `RustLanguage`/`TypeScriptLanguage` so `--language` covers a uniffi-style matrix of targets
+/// from the same Ion Schema input.
+pub struct PythonLanguage;
+
+/// Python keywords and soft keywords (see
+/// <https://docs.python.org/3/reference/lexical_analysis.html#keywords>) that can't be used as
+/// an identifier and so must be escaped by [PythonLanguage::escape_reserved_word].
+const PYTHON_RESERVED_WORDS: &[&str] = &[
+    "False", "None", "True", "and", "as", "assert", "async", "await", "break", "class",
+    "continue", "def", "del", "elif", "else", "except", "finally", "for", "from", "global", "if",
+    "import", "in", "is", "lambda", "nonlocal", "not", "or", "pass", "raise", "return", "try",
+    "while", "with", "yield",
+];
+
+impl Language for PythonLanguage {
+    fn file_extension() -> String {
+        "py".to_string()
+    }
+
+    fn name() -> String {
+        "python".to_string()
+    }
+
+    fn file_name_for_type(name: &str) -> String {
+        PythonLanguage::escape_reserved_word(&name.to_case(Case::Snake))
+    }
+
+    fn field_name_case() -> FieldNameCase {
+        FieldNameCase::Snake
+    }
+
+    fn target_type(ion_schema_type: &IonSchemaType) -> Option<String> {
+        use IonSchemaTypeKind::*;
+        let name = match ion_schema_type.kind() {
+            Int => "int",
+            String | Symbol | Text => "str",
+            Float => "float",
+            Bool => "bool",
+            Blob | Clob | Lob => "bytes",
+            Decimal | Number => "decimal.Decimal",
+            Timestamp => "datetime",
+            Document => "typing.Any",
+            List | SExp | Struct => return None,
+            SchemaDefined(name) => name,
+        }
+        .to_string();
+        Some(match ion_schema_type.kind() {
+            SchemaDefined(_) => PythonLanguage::escape_reserved_word(&name),
+            _ => name,
+        })
+    }
+
+    fn escape_reserved_word(name: &str) -> String {
+        if PYTHON_RESERVED_WORDS.contains(&name) {
+            format!("{name}_")
+        } else {
+            name.to_string()
+        }
+    }
+
+    fn target_type_as_sequence(
+        target_type: FullyQualifiedTypeReference,
+    ) -> FullyQualifiedTypeReference {
+        FullyQualifiedTypeReference {
+            type_name: vec!["List".to_string()],
+            parameters: vec![target_type],
+        }
+    }
+
+    fn is_built_in_type(type_name: String) -> bool {
+        matches!(
+            type_name.as_str(),
+            "int" | "str"
+                | "bool"
+                | "bytes"
+                | "float"
+                | "decimal.Decimal"
+                | "datetime"
+                | "typing.Any"
+        )
+    }
+
+    fn fully_qualified_type_ref(name: &FullyQualifiedTypeReference) -> String {
+        name.type_name.join(".")
+    }
+
+    fn template_name(template: &Template) -> String {
+        match template {
+            Template::Struct => "dataclass".to_string(),
+            Template::Scalar => "scalar".to_string(),
+            Template::Sequence => "sequence".to_string(),
+            Template::Enum => "enum".to_string(),
+            // `ordered_elements` is not yet given a dedicated Python template; a dataclass with
+            // positional fields renders an acceptable tuple-shaped type in the meantime.
+            Template::Tuple => "dataclass".to_string(),
+        }
+    }
+
+    fn read_method_name() -> String {
+        "read_from".to_string()
+    }
+
+    fn write_method_name() -> String {
+        "write_to".to_string()
+    }
+
+    fn namespace_separator() -> &'static str {
+        "."
+    }
+
+    fn add_type_to_namespace(
+        _is_nested_type: bool,
+        type_name: &String,
+        namespace: &mut Vec<String>,
+    ) {
+        namespace.push(PythonLanguage::escape_reserved_word(
+            &type_name.to_case(Case::UpperCamel),
+        ))
+    }
+
+    fn target_type_as_optional(
+        target_type: FullyQualifiedTypeReference,
+    ) -> FullyQualifiedTypeReference {
+        FullyQualifiedTypeReference {
+            type_name: vec!["Optional".to_string()],
+            parameters: vec![target_type],
+        }
+    }
+
+    fn target_type_as_nullable(
+        target_type: FullyQualifiedTypeReference,
+    ) -> FullyQualifiedTypeReference {
+        // Python has no separate boxed/primitive distinction the way Java does, so a nullable
+        // value is represented the same way an absent field is: `Optional[T]`.
+        FullyQualifiedTypeReference {
+            type_name: vec!["Optional".to_string()],
+            parameters: vec![target_type],
+        }
+    }
+
+    // `target_type_as_boxed` is left at its default (identity): Python attributes are already
+    // references to heap-allocated objects, so a field that cyclically refers back to its own
+    // type doesn't need any extra indirection to have finite size.
+
+    fn target_type_as_map(
+        key_type: FullyQualifiedTypeReference,
+        value_type: FullyQualifiedTypeReference,
+    ) -> FullyQualifiedTypeReference {
+        FullyQualifiedTypeReference {
+            type_name: vec!["Dict".to_string()],
+            parameters: vec![key_type, value_type],
+        }
+    }
+
+    fn imports() -> Vec<String> {
+        vec![
+            "from dataclasses import dataclass".to_string(),
+            "from typing import List, Dict, Optional".to_string(),
+            "import decimal".to_string(),
+            "from datetime import datetime".to_string(),
+        ]
+    }
+
+    fn generic_parameter_brackets() -> (&'static str, &'static str) {
+        ("[", "]")
+    }
+}
+
+impl Display for PythonLanguage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "python")
+    }
+}
+
+/// Generates TypeScript interfaces from a resolved data model, alongside [JavaLanguage],
+/// [RustLanguage], and [PythonLanguage].
+pub struct TypeScriptLanguage;
+
+/// TypeScript/JavaScript reserved words (ECMAScript keywords plus the strict-mode-reserved and
+/// future-reserved words; see <https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Lexical_grammar#keywords>)
+/// that can't be used as an identifier and so must be escaped by
+/// [TypeScriptLanguage::escape_reserved_word].
+const TYPESCRIPT_RESERVED_WORDS: &[&str] = &[
+    "break", "case", "catch", "class", "const", "continue", "debugger", "default", "delete", "do",
+    "else", "enum", "export", "extends", "false", "finally", "for", "function", "if", "import",
+    "in", "instanceof", "new", "null", "return", "super", "switch", "this", "throw", "true",
+    "try", "typeof", "var", "void", "while", "with", "as", "implements", "interface", "let",
+    "package", "private", "protected", "public", "static", "yield", "await", "async", "type",
+    "namespace", "module", "declare",
+];
+
+impl Language for TypeScriptLanguage {
+    fn file_extension() -> String {
+        "ts".to_string()
+    }
+
+    fn name() -> String {
+        "typescript".to_string()
+    }
+
+    fn file_name_for_type(name: &str) -> String {
+        TypeScriptLanguage::escape_reserved_word(&name.to_case(Case::Kebab))
+    }
+
+    fn field_name_case() -> FieldNameCase {
+        FieldNameCase::Camel
+    }
+
+    fn target_type(ion_schema_type: &IonSchemaType) -> Option<String> {
+        use IonSchemaTypeKind::*;
+        let name = match ion_schema_type.kind() {
+            Int | Float => "number",
+            String | Symbol | Text => "string",
+            Bool => "boolean",
+            Blob | Clob | Lob => "Uint8Array",
+            // TypeScript/JS has no built-in arbitrary-precision decimal type, and this crate
+            // doesn't take on an external dependency like `decimal.js` to provide one (see
+            // `CodeGenConfig::from_file`'s similar stance on TOML support), so a decimal (and,
+            // for the same reason, the wider `number` ISL type that can also hold a decimal)
+            // round-trips as its exact string representation instead of losing precision to
+            // `number`.
+            Decimal | Number => "string",
+            Timestamp => "Date",
+            Document => "unknown",
+            List | SExp | Struct => return None,
+            SchemaDefined(name) => name,
+        }
+        .to_string();
+        Some(match ion_schema_type.kind() {
+            SchemaDefined(_) => TypeScriptLanguage::escape_reserved_word(&name),
+            _ => name,
+        })
+    }
+
+    fn escape_reserved_word(name: &str) -> String {
+        if TYPESCRIPT_RESERVED_WORDS.contains(&name) {
+            format!("{name}_")
+        } else {
+            name.to_string()
+        }
+    }
+
+    fn target_type_as_sequence(
+        target_type: FullyQualifiedTypeReference,
+    ) -> FullyQualifiedTypeReference {
+        // The shared `string_representation` renderer only knows how to emit `Name<Params>`
+        // generics, so we model TypeScript's postfix `T[]` as a synthetic `Array<T>` instead.
+        FullyQualifiedTypeReference {
+            type_name: vec!["Array".to_string()],
+            parameters: vec![target_type],
+        }
+    }
+
+    fn is_built_in_type(type_name: String) -> bool {
+        matches!(
+            type_name.as_str(),
+            "number" | "string" | "boolean" | "Uint8Array" | "Date" | "unknown"
+        )
+    }
+
+    fn fully_qualified_type_ref(name: &FullyQualifiedTypeReference) -> String {
+        name.type_name.join(".")
+    }
+
+    fn template_name(template: &Template) -> String {
+        match template {
+            Template::Struct => "interface".to_string(),
+            Template::Scalar => "scalar".to_string(),
+            Template::Sequence => "sequence".to_string(),
+            Template::Enum => "enum".to_string(),
+            // `ordered_elements` is not yet given a dedicated TypeScript template; an interface with
+            // positional fields renders an acceptable tuple-shaped type in the meantime.
+            Template::Tuple => "interface".to_string(),
+        }
+    }
+
+    fn read_method_name() -> String {
+        "readFrom".to_string()
+    }
+
+    fn write_method_name() -> String {
+        "writeTo".to_string()
+    }
+
+    fn namespace_separator() -> &'static str {
+        "."
+    }
+
+    fn add_type_to_namespace(
+        _is_nested_type: bool,
+        type_name: &String,
+        namespace: &mut Vec<String>,
+    ) {
+        namespace.push(TypeScriptLanguage::escape_reserved_word(
+            &type_name.to_case(Case::UpperCamel),
+        ))
+    }
+
+    fn target_type_as_optional(
+        target_type: FullyQualifiedTypeReference,
+    ) -> FullyQualifiedTypeReference {
+        // TODO: there's no clean `Optional<T>`-equivalent to thread through this data model for
+        // union types yet (TypeScript would want `T | undefined`, which this renderer, built
+        // around `Name<Params>`-shaped generics, has nowhere to put).
+        target_type
+    }
+
+    // `target_type_as_nullable` is left at its default (identity): the same gap documented on
+    // `target_type_as_optional` above applies here too, and `number`/`string`/`boolean` are
+    // already nullable via `| null` in TypeScript once that union-type support exists.
+
+    // `target_type_as_boxed` is also left at its default (identity): TypeScript interfaces are
+    // already reference types, so a field that cyclically refers back to its own type doesn't
+    // need any extra indirection to have finite size.
+
+    fn target_type_as_map(
+        key_type: FullyQualifiedTypeReference,
+        value_type: FullyQualifiedTypeReference,
+    ) -> FullyQualifiedTypeReference {
+        FullyQualifiedTypeReference {
+            type_name: vec!["Map".to_string()],
+            parameters: vec![key_type, value_type],
+        }
+    }
+}
+
+impl Display for TypeScriptLanguage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "typescript")
+    }
+}
+
+/// Generates Kotlin `data class`es from a resolved data model, alongside [JavaLanguage],
+/// [RustLanguage], [PythonLanguage], and [TypeScriptLanguage]. Like [JavaLanguage], Kotlin types
+/// are rooted under a `--namespace`-supplied package; unlike Java, a `data class` already
+/// generates structural `equals`/`hashCode`/`toString`/`copy` for every field, so (unlike Java)
+/// this backend never needs to annotate generated types with those -- the target language itself
+/// provides them for free.
+pub struct KotlinLanguage;
+
+/// Kotlin's hard keywords (see <https://kotlinlang.org/docs/keyword-reference.html#hard-keywords>)
+/// that can't be used as an identifier as-is and so must be escaped by
+/// [KotlinLanguage::escape_reserved_word]. Excludes the modifier/soft keywords (`data`, `value`,
+/// ...), which remain legal identifiers in Kotlin.
+const KOTLIN_RESERVED_WORDS: &[&str] = &[
+    "as", "break", "class", "continue", "do", "else", "false", "for", "fun", "if", "in",
+    "interface", "is", "null", "object", "package", "return", "super", "this", "throw", "true",
+    "try", "typealias", "typeof", "val", "var", "when", "while",
+];
+
+impl Language for KotlinLanguage {
+    fn file_extension() -> String {
+        "kt".to_string()
+    }
+
+    fn name() -> String {
+        "kotlin".to_string()
+    }
+
+    fn file_name_for_type(name: &str) -> String {
+        KotlinLanguage::escape_reserved_word(&name.to_case(Case::UpperCamel))
+    }
+
+    fn field_name_case() -> FieldNameCase {
+        FieldNameCase::Camel
+    }
+
+    fn target_type(ion_schema_type: &IonSchemaType) -> Option<String> {
+        use IonSchemaTypeKind::*;
+        let name = match ion_schema_type.kind() {
+            Int => "Int",
+            String | Symbol | Text => "String",
+            Float => "Double",
+            Bool => "Boolean",
+            Blob | Clob | Lob => "ByteArray",
+            Decimal | Number => "java.math.BigDecimal",
+            Timestamp => "com.amazon.ion.Timestamp",
+            Document => "com.amazon.ion.IonValue",
+            List | SExp | Struct => return None,
+            SchemaDefined(name) => name,
+        }
+        .to_string();
+        Some(match ion_schema_type.kind() {
+            SchemaDefined(_) => KotlinLanguage::escape_reserved_word(&name),
+            _ => name,
+        })
+    }
+
+    fn escape_reserved_word(name: &str) -> String {
+        if KOTLIN_RESERVED_WORDS.contains(&name) {
+            format!("{name}_")
+        } else {
+            name.to_string()
+        }
+    }
+
+    fn target_type_as_sequence(
+        target_type: FullyQualifiedTypeReference,
+    ) -> FullyQualifiedTypeReference {
+        FullyQualifiedTypeReference {
+            type_name: vec!["List".to_string()],
+            parameters: vec![target_type],
+        }
+    }
+
+    fn is_built_in_type(type_name: String) -> bool {
+        matches!(
+            type_name.as_str(),
+            "Int" | "String"
+                | "Boolean"
+                | "ByteArray"
+                | "Double"
+                | "java.math.BigDecimal"
+                | "com.amazon.ion.Timestamp"
+                | "com.amazon.ion.IonValue"
+        )
+    }
+
+    fn target_type_as_map(
+        key_type: FullyQualifiedTypeReference,
+        value_type: FullyQualifiedTypeReference,
+    ) -> FullyQualifiedTypeReference {
+        FullyQualifiedTypeReference {
+            type_name: vec!["Map".to_string()],
+            parameters: vec![key_type, value_type],
+        }
+    }
+
+    fn fully_qualified_type_ref(name: &FullyQualifiedTypeReference) -> String {
+        name.type_name.join(".")
+    }
+
+    fn template_name(template: &Template) -> String {
+        match template {
+            Template::Struct => "data_class".to_string(),
+            Template::Scalar => "scalar".to_string(),
+            Template::Sequence => "sequence".to_string(),
+            Template::Enum => "enum".to_string(),
+            // `ordered_elements` is not yet given a dedicated Kotlin template; a data class with
+            // positional fields renders an acceptable tuple-shaped type in the meantime.
+            Template::Tuple => "data_class".to_string(),
+        }
+    }
+
+    fn read_method_name() -> String {
+        "readFrom".to_string()
+    }
+
+    fn write_method_name() -> String {
+        "writeTo".to_string()
+    }
+
+    fn namespace_separator() -> &'static str {
+        "."
+    }
+
+    fn add_type_to_namespace(
+        _is_nested_type: bool,
+        type_name: &String,
+        namespace: &mut Vec<String>,
+    ) {
+        namespace.push(KotlinLanguage::escape_reserved_word(
+            &type_name.to_case(Case::UpperCamel),
+        ))
+    }
+
+    fn target_type_as_optional(
+        target_type: FullyQualifiedTypeReference,
+    ) -> FullyQualifiedTypeReference {
+        // The shared `string_representation` renderer only knows how to emit `Name<Params>`
+        // generics (see `TypeScriptLanguage::target_type_as_sequence`'s synthetic `Array<T>` for
+        // the same constraint), so Kotlin's postfix `T?` is modeled as a single-element type name
+        // with no parameters: `string_representation` renders a parameter-less reference as its
+        // `type_name` joined as-is, so `vec![format!("{inner}?")]` renders to exactly `T?`.
+        FullyQualifiedTypeReference {
+            type_name: vec![format!(
+                "{}?",
+                target_type.string_representation::<KotlinLanguage>()
+            )],
+            parameters: vec![],
+        }
+    }
+
+    fn target_type_as_nullable(
+        target_type: FullyQualifiedTypeReference,
+    ) -> FullyQualifiedTypeReference {
+        // Kotlin's `Int`/`Boolean`/`Double` are already reference-like types that support `?`
+        // directly (no separate boxed/primitive split the way Java has), so a nullable value is
+        // represented the same way an absent field is: the same postfix `T?`.
+        FullyQualifiedTypeReference {
+            type_name: vec![format!(
+                "{}?",
+                target_type.string_representation::<KotlinLanguage>()
+            )],
+            parameters: vec![],
+        }
+    }
+
+    // `target_type_as_boxed` is left at its default (identity): Kotlin data classes are already
+    // JVM reference types, so a field that cyclically refers back to its own type doesn't need
+    // any extra indirection to have finite size.
+}
+
+impl Display for KotlinLanguage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "kotlin")
+    }
+}
+
 /// Represents all the supported templates for code generation.
 /// These templates will be used by [tera] templating engine to render the generated code with appropriate context value.
 /// _Note: These template variants are based on Rust programming language.
@@ -346,6 +1188,8 @@ pub enum Template {
     Struct,   // Represents a template for a Rust struct or Java class with Ion struct value
     Sequence, // Represents a template for a Rust struct or Java class with Ion sequence value
     Scalar,   // Represents a template for a Rust struct or Java class with Ion scalar value
+    Enum, // Represents a template for a Rust enum or Java sealed class/interface with an Ion union or `valid_values` enum value
+    Tuple, // Represents a template for a Rust tuple struct or Java record with an Ion `ordered_elements` value
 }
 
 impl TryFrom<&DataModelNode> for Template {
@@ -361,6 +1205,12 @@ impl TryFrom<&DataModelNode> for Template {
                     Ok(Template::Sequence)
                 }
                 AbstractDataType::Structure(_) => Ok(Template::Struct),
+                AbstractDataType::Union(_) => Ok(Template::Enum),
+                AbstractDataType::Enum(_) => Ok(Template::Enum),
+                // TODO: a dedicated `Template::Map` is needed to emit a target-language map
+                // literal directly; render as a struct template in the meantime.
+                AbstractDataType::Map(_) => Ok(Template::Struct),
+                AbstractDataType::Tuple(_) => Ok(Template::Tuple),
             }
         } else {
             invalid_abstract_data_type_error(
@@ -371,10 +1221,30 @@ impl TryFrom<&DataModelNode> for Template {
 }
 
 /// Represents an Ion schema type which could either be one of the [built-int types] or a user defined type.
+/// Also tracks whether the ISL type reference was one of the `$`-prefixed nullable built-in forms
+/// (e.g. `$int`, `$string`), which permit `null` in addition to the base type, via [is_nullable](Self::is_nullable).
 ///
 /// [built-in types]: `<https://amazon-ion.github.io/ion-schema/docs/isl-2-0/spec#built-in-types>`
-// TODO: Add enum variants for missing built-in ISL types.
-pub enum IonSchemaType {
+pub struct IonSchemaType {
+    kind: IonSchemaTypeKind,
+    nullable: bool,
+}
+
+impl IonSchemaType {
+    /// The built-in or user-defined type this ISL type reference names, independent of nullability.
+    pub fn kind(&self) -> &IonSchemaTypeKind {
+        &self.kind
+    }
+
+    /// Returns true if this ISL type reference was one of the `$`-prefixed nullable built-in
+    /// forms (e.g. `$int`), meaning the value itself may be `null` in addition to being `kind()`.
+    pub fn is_nullable(&self) -> bool {
+        self.nullable
+    }
+}
+
+// TODO: Add an enum variant for the untyped `$null` built-in type.
+pub enum IonSchemaTypeKind {
     Int,
     String,
     Symbol,
@@ -382,6 +1252,16 @@ pub enum IonSchemaType {
     Bool,
     Blob,
     Clob,
+    Decimal,
+    Timestamp,
+    // The ISL union of `int`/`float`/`decimal`.
+    Number,
+    // The ISL union of `string`/`symbol`.
+    Text,
+    // The ISL union of `blob`/`clob`.
+    Lob,
+    // Matches any single top-level Ion value, with no further constraint on its type.
+    Document,
     SExp,
     List,
     Struct,
@@ -390,8 +1270,21 @@ pub enum IonSchemaType {
 
 impl From<&str> for IonSchemaType {
     fn from(value: &str) -> Self {
-        use IonSchemaType::*;
-        match value {
+        use IonSchemaTypeKind::*;
+        if let Some(base) = value.strip_prefix('$') {
+            if base.is_empty() || base == "null" {
+                unimplemented!("The untyped `$null` built-in type is not supported yet!")
+            }
+            // e.g. `$int` permits `null` in addition to `int`, so this is the same `kind` as
+            // `int` with `nullable` flipped on, rather than a mapping of its own.
+            let IonSchemaType { kind, .. } = IonSchemaType::from(base);
+            return IonSchemaType {
+                kind,
+                nullable: true,
+            };
+        }
+
+        let kind = match value {
             "int" => Int,
             "string" => String,
             "symbol" => Symbol,
@@ -399,19 +1292,21 @@ impl From<&str> for IonSchemaType {
             "bool" => Bool,
             "blob" => Blob,
             "clob" => Clob,
-            _ if &value[..1] == "$" => {
-                unimplemented!("Built in types with nulls are not supported yet!")
-            }
-            "number" | "text" | "lob" | "document" | "nothing" => {
-                unimplemented!("Complex types are not supported yet!")
-            }
-            "decimal" | "timestamp" => {
-                unimplemented!("Decimal, Number and Timestamp aren't support yet!")
-            }
+            "decimal" => Decimal,
+            "timestamp" => Timestamp,
+            "number" => Number,
+            "text" => Text,
+            "lob" => Lob,
+            "document" => Document,
+            "nothing" => unimplemented!("The `nothing` built-in type is not supported yet!"),
             "struct" => Struct,
             "list" => List,
             "sexp" => SExp,
             _ => SchemaDefined(value.to_case(Case::UpperCamel)),
+        };
+        IonSchemaType {
+            kind,
+            nullable: false,
         }
     }
 }
@@ -427,3 +1322,55 @@ impl From<&String> for IonSchemaType {
         value.as_str().into()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_decimal_and_timestamp() {
+        assert!(matches!(
+            IonSchemaType::from("decimal").kind(),
+            IonSchemaTypeKind::Decimal
+        ));
+        assert!(matches!(
+            IonSchemaType::from("timestamp").kind(),
+            IonSchemaTypeKind::Timestamp
+        ));
+    }
+
+    #[test]
+    fn parses_nullable_built_in_types() {
+        let nullable_int = IonSchemaType::from("$int");
+        assert!(nullable_int.is_nullable());
+        assert!(matches!(nullable_int.kind(), IonSchemaTypeKind::Int));
+
+        let non_nullable_int = IonSchemaType::from("int");
+        assert!(!non_nullable_int.is_nullable());
+    }
+
+    #[test]
+    #[should_panic]
+    fn untyped_null_is_not_yet_supported() {
+        IonSchemaType::from("$null");
+    }
+
+    #[test]
+    fn escapes_reserved_words_per_language() {
+        assert_eq!(JavaLanguage::escape_reserved_word("class"), "class_");
+        assert_eq!(JavaLanguage::escape_reserved_word("Foo"), "Foo");
+
+        assert_eq!(RustLanguage::escape_reserved_word("type"), "r#type");
+        assert_eq!(RustLanguage::escape_reserved_word("self"), "self_");
+        assert_eq!(RustLanguage::escape_reserved_word("foo"), "foo");
+
+        assert_eq!(PythonLanguage::escape_reserved_word("class"), "class_");
+        assert_eq!(PythonLanguage::escape_reserved_word("Foo"), "Foo");
+
+        assert_eq!(
+            TypeScriptLanguage::escape_reserved_word("interface"),
+            "interface_"
+        );
+        assert_eq!(TypeScriptLanguage::escape_reserved_word("Foo"), "Foo");
+    }
+}
@@ -1,17 +1,27 @@
-use crate::commands::{CommandIo, IonCliCommand, WithIonCliArgument};
+use crate::commands::{check_unstable_feature_opt_in, CommandIo, IonCliCommand, WithIonCliArgument};
 use anyhow::Result;
+use blake2::{Blake2b512, Blake2s256};
 use clap::builder::PossibleValue;
 use clap::{value_parser, Arg, ArgAction, ArgMatches, Command, ValueEnum};
+use data_encoding::{BASE32, BASE64};
 use ion_rs::ion_hash::IonHasher;
 use ion_rs::*;
+use md5::Md5;
+use sha1::Sha1;
 use sha2::{Sha256, Sha512};
 use sha3::{Sha3_256, Sha3_512};
 use std::fmt;
 use std::io::Write;
 
-// Macro to eliminate repetitive code for each hash algorithm.
+/// `-Z` feature gating the legacy digest algorithms (SHA-1, MD5) for interop with older systems.
+/// These are additionally gated (on top of `hash`) because they're cryptographically broken and
+/// shouldn't be reached for without a deliberate choice.
+const LEGACY_DIGESTS_FEATURE: &str = "hash-legacy-digests";
+
+// Macro to eliminate repetitive code for each hash algorithm. `$len` is the digest's output
+// length in bytes, used by `--list` to describe each algorithm without having to hash anything.
 macro_rules! supported_hash_functions {
-    ($($name:literal => $hash:ident),+$(,)?) => {
+    ($($name:literal => $hash:ident => $len:literal),+$(,)?) => {
         #[derive(Copy, Clone, PartialEq, Eq, Default, Debug)]
         enum DigestType {
             #[default]
@@ -27,6 +37,18 @@ macro_rules! supported_hash_functions {
                     $(DigestType::$hash => Ok($hash::hash_element(&element)?.to_vec()),)+
                 }
             }
+
+            /// The digest's output length in bytes.
+            fn output_len(&self) -> usize {
+                match self {
+                    $(DigestType::$hash => $len,)+
+                }
+            }
+
+            /// Whether this algorithm requires the [`LEGACY_DIGESTS_FEATURE`] opt-in.
+            fn is_legacy(&self) -> bool {
+                matches!(self, DigestType::Sha1 | DigestType::Md5)
+            }
         }
         impl ValueEnum for DigestType {
             fn value_variants<'a>() -> &'a [Self] {
@@ -43,10 +65,58 @@ macro_rules! supported_hash_functions {
 }
 
 supported_hash_functions! {
-    "sha-256" => Sha256,
-    "sha-512" => Sha512,
-    "sha3-256" => Sha3_256,
-    "sha3-512" => Sha3_512,
+    "sha-256" => Sha256 => 32,
+    "sha-512" => Sha512 => 64,
+    "sha3-256" => Sha3_256 => 32,
+    "sha3-512" => Sha3_512 => 64,
+    "blake2b-512" => Blake2b512 => 64,
+    "blake2s-256" => Blake2s256 => 32,
+    "sha-1" => Sha1 => 20,
+    "md5" => Md5 => 16,
+}
+
+/// How a computed digest should be rendered as text. Ignored when `--blob` is set.
+#[derive(Copy, Clone, PartialEq, Eq, Default, Debug)]
+enum DigestEncoding {
+    #[default]
+    Hex,
+    Base64,
+    Base32,
+}
+
+impl DigestEncoding {
+    fn encode(&self, digest: &[u8]) -> String {
+        match self {
+            DigestEncoding::Hex => digest.iter().fold(
+                String::with_capacity(digest.len() * 2),
+                |mut string, byte| {
+                    use fmt::Write;
+                    write!(&mut string, "{:02x}", byte).expect("infallible");
+                    string
+                },
+            ),
+            DigestEncoding::Base64 => BASE64.encode(digest),
+            DigestEncoding::Base32 => BASE32.encode(digest),
+        }
+    }
+}
+
+impl ValueEnum for DigestEncoding {
+    fn value_variants<'a>() -> &'a [Self] {
+        &[
+            DigestEncoding::Hex,
+            DigestEncoding::Base64,
+            DigestEncoding::Base32,
+        ]
+    }
+
+    fn to_possible_value(&self) -> Option<PossibleValue> {
+        match self {
+            DigestEncoding::Hex => Some("hex".into()),
+            DigestEncoding::Base64 => Some("base64".into()),
+            DigestEncoding::Base32 => Some("base32".into()),
+        }
+    }
 }
 
 pub struct HashCommand;
@@ -60,8 +130,8 @@ impl IonCliCommand for HashCommand {
         "Calculates a hash of Ion values using the Ion Hash algorithm."
     }
 
-    fn is_stable(&self) -> bool {
-        false
+    fn unstable_features(&self) -> &'static [&'static str] {
+        &["hash"]
     }
 
     fn is_porcelain(&self) -> bool {
@@ -72,32 +142,65 @@ impl IonCliCommand for HashCommand {
         command
             .arg(
                 Arg::new("hash")
-                    .required(true)
+                    .required_unless_present("list")
                     .value_parser(value_parser!(DigestType)),
             )
+            .arg(
+                Arg::new("list")
+                    .long("list")
+                    .action(ArgAction::SetTrue)
+                    .help("List every supported digest algorithm and its output length, then exit."),
+            )
             .with_output()
             .with_input()
-            // TODO: If we want to support other output formats, add flags for them
-            //       and an ArgGroup to ensure only one is selected.
-            //       Default right now is to emit base16 strings of the digest.
             .arg(
                 Arg::new("blob")
                     .long("blob")
                     .help("Emit the digest(s) as Ion blob values.")
-                    .action(ArgAction::SetTrue),
+                    .action(ArgAction::SetTrue)
+                    .conflicts_with("encoding"),
+            )
+            .arg(
+                Arg::new("encoding")
+                    .long("encoding")
+                    .default_value("hex")
+                    .value_parser(value_parser!(DigestEncoding))
+                    .help("Text encoding to use for the emitted digest(s).")
+                    .conflicts_with("blob"),
             )
     }
 
     fn run(&self, _command_path: &mut Vec<String>, args: &ArgMatches) -> Result<()> {
+        if args.get_flag("list") {
+            for digest_type in DigestType::VARIANTS {
+                println!(
+                    "{} ({} bytes)",
+                    digest_type
+                        .to_possible_value()
+                        .expect("every variant has a name")
+                        .get_name(),
+                    digest_type.output_len()
+                );
+            }
+            return Ok(());
+        }
+
+        let hasher = args
+            .get_one::<DigestType>("hash")
+            .expect("clap ensures that there is a valid argument unless --list was given");
+
+        if hasher.is_legacy() {
+            check_unstable_feature_opt_in("hash <legacy digest>", &[LEGACY_DIGESTS_FEATURE], args);
+        }
+
+        let encoding = args
+            .get_one::<DigestEncoding>("encoding")
+            .copied()
+            .unwrap_or_default();
+
         CommandIo::new(args)?.for_each_input(|output, input| {
             let mut reader = Reader::new(AnyEncoding, input.into_source())?;
 
-            let hasher = if let Some(hasher) = args.get_one::<DigestType>("hash") {
-                hasher
-            } else {
-                unreachable!("clap ensures that there is a valid argument")
-            };
-
             if args.get_flag("blob") {
                 let mut writer = Writer::new(v1_0::Text.with_format(TextFormat::Lines), output)?;
                 for elem in reader.elements() {
@@ -110,15 +213,7 @@ impl IonCliCommand for HashCommand {
                 for elem in reader.elements() {
                     let elem = elem?;
                     let digest = hasher.hash_it(&elem)?;
-                    let digest_string = digest.iter().fold(
-                        String::with_capacity(digest.len() * 2),
-                        |mut string, byte| {
-                            use fmt::Write;
-                            write!(&mut string, "{:02x}", byte).expect("infallible");
-                            string
-                        },
-                    );
-                    output.write_all(digest_string.as_bytes())?;
+                    output.write_all(encoding.encode(&digest).as_bytes())?;
                     output.write_all("\n".as_bytes())?;
                 }
             }
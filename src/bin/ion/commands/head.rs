@@ -1,6 +1,6 @@
 use anyhow::Result;
 use clap::{value_parser, Arg, ArgMatches, Command};
-use ion_rs::{AnyEncoding, Reader};
+use ion_rs::{AnyEncoding, IonInput, Reader, SystemReader, SystemStreamItem};
 
 use crate::commands::{CommandIo, IonCliCommand, WithIonCliArgument};
 use crate::transcribe::write_n_as;
@@ -32,6 +32,7 @@ impl IonCliCommand for HeadCommand {
             .with_output()
             .with_format()
             .with_ion_version()
+            .with_limit()
             .arg(
                 Arg::new("values")
                     .long("values")
@@ -41,6 +42,15 @@ impl IonCliCommand for HeadCommand {
                     .default_value("10")
                     .help("Specifies the number of output top-level values."),
             )
+            .arg(
+                Arg::new("offsets")
+                    .long("offsets")
+                    .num_args(0)
+                    .help(
+                        "Print each value's byte offset span `start..end` in the input instead \
+                         of re-serializing the value itself.",
+                    ),
+            )
     }
 
     fn run(&self, _command_path: &mut Vec<String>, args: &ArgMatches) -> Result<()> {
@@ -48,13 +58,48 @@ impl IonCliCommand for HeadCommand {
         // https://github.com/amazon-ion/ion-cli/issues/48
 
         let num_values = *args.get_one::<usize>("values").unwrap();
+        let offsets = args.get_flag("offsets");
 
         CommandIo::new(args)?.for_each_input(|output, input| {
-            let mut reader = Reader::new(AnyEncoding, input.into_source())?;
-            let encoding = *output.encoding();
-            let format = *output.format();
-            write_n_as(&mut reader, output, encoding, format, num_values)?;
+            if offsets {
+                let mut reader = SystemReader::new(AnyEncoding, input.into_source());
+                write_n_offsets(&mut reader, output, num_values)?;
+            } else {
+                let mut reader = Reader::new(AnyEncoding, input.into_source())?;
+                let encoding = *output.encoding();
+                let format = *output.format();
+                write_n_as(&mut reader, output, encoding, format, num_values)?;
+            }
             Ok(())
         })
     }
 }
+
+/// Prints the byte offset span `start..end` of each of the first `count` top-level values in
+/// `reader`, one per line, reusing the same `SystemReader` span machinery `stats` uses to measure
+/// value sizes. Useful for seeking into large binary Ion files or building an external index
+/// without re-serializing (or even fully materializing) the values themselves.
+fn write_n_offsets<Input: IonInput>(
+    reader: &mut SystemReader<AnyEncoding, Input>,
+    mut output: impl std::io::Write,
+    count: usize,
+) -> Result<usize> {
+    use SystemStreamItem::*;
+
+    let mut written = 0;
+    while written < count {
+        match reader.next_item()? {
+            EndOfStream(_) => break,
+            VersionMarker(_) | EncodingDirective(_) | SymbolTable(_) => continue,
+            system_value @ Value(_) => {
+                if let Some(range) = system_value.raw_stream_item().map(|v| v.span().range()) {
+                    writeln!(output, "{}..{}", range.start, range.end)?;
+                }
+                written += 1;
+            }
+            // SystemStreamItem is non_exhaustive
+            unsupported => panic!("Unsupported system stream item: {unsupported:?}"),
+        }
+    }
+    Ok(written)
+}
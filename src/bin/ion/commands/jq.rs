@@ -1,28 +1,32 @@
+use crate::commands::jq::diagnostics::Diagnostic;
 use crate::commands::jq::ion_math::DecimalMath;
 use crate::commands::{CommandIo, IonCliCommand, WithIonCliArgument};
 use crate::input::CommandInput;
 use crate::output::{CommandOutput, CommandOutputWriter};
-use anyhow::bail;
-use bigdecimal::ToPrimitive;
-use clap::{arg, ArgMatches, Command};
+use anyhow::{bail, Context};
+use bigdecimal::{BigDecimal, ToPrimitive};
+use clap::{arg, Arg, ArgAction, ArgMatches, Command};
 use ion_rs::{
-    AnyEncoding, Element, ElementReader, IonData, IonType, List, Reader, Sequence, Value,
+    AnyEncoding, Decimal, Element, ElementReader, IonData, IonType, List, Reader, Sequence, Value,
 };
-use itertools::Itertools;
 use jaq_core::path::Opt;
 use jaq_core::val::Range;
-use jaq_core::{Ctx, Filter, Native, RcIter, ValR, ValX};
+use jaq_core::{Ctx, Filter, Native, RcIter, ValR, ValT, ValX};
 use std::cmp::Ordering;
 use std::fmt::{Display, Formatter};
+use std::io::Write;
 use std::iter::Empty;
 use std::ops::{Add, Deref, Div, Mul, Neg, Rem, Sub};
 use std::str::FromStr;
 
+mod diagnostics;
+mod ion_functions;
+
 pub struct JqCommand;
 
 impl IonCliCommand for JqCommand {
-    fn is_stable(&self) -> bool {
-        false
+    fn unstable_features(&self) -> &'static [&'static str] {
+        &["jq"]
     }
 
     fn is_porcelain(&self) -> bool {
@@ -41,6 +45,36 @@ impl IonCliCommand for JqCommand {
         command
             .arg(arg!(<filter> "A `jq` filter expression to evaluate"))
             .arg(arg!(-s --slurp "Read all inputs into an array and use it as the single input value"))
+            .arg(arg!(-n --"null-input" "Run the filter once against `null` instead of reading any input"))
+            .arg(arg!(-R --"raw-input" "Read each line of input as an Ion string instead of parsing it as Ion"))
+            .arg(arg!(-r --"raw-output" "If the filter's result is a string or symbol, print its raw text instead of quoted Ion"))
+            .arg(
+                Arg::new("arg")
+                    .long("arg")
+                    .action(ArgAction::Append)
+                    .num_args(2)
+                    .value_names(["name", "value"])
+                    .help("Binds `value` (a string) to the variable `$name`. May be repeated."),
+            )
+            .arg(
+                Arg::new("argjson")
+                    .long("argjson")
+                    .action(ArgAction::Append)
+                    .num_args(2)
+                    .value_names(["name", "ion"])
+                    .help("Binds `ion` (parsed as Ion) to the variable `$name`. May be repeated."),
+            )
+            .arg(
+                Arg::new("rawfile")
+                    .long("rawfile")
+                    .action(ArgAction::Append)
+                    .num_args(2)
+                    .value_names(["name", "path"])
+                    .help(
+                        "Binds the contents of the file at `path` (as a string) to the variable \
+                         `$name`. May be repeated.",
+                    ),
+            )
             .with_input()
             .with_output()
             .with_format()
@@ -49,59 +83,171 @@ impl IonCliCommand for JqCommand {
 
     fn run(&self, _command_path: &mut Vec<String>, args: &ArgMatches) -> anyhow::Result<()> {
         let slurp = args.get_flag("slurp");
+        let null_input = args.get_flag("null-input");
+        let raw_input = args.get_flag("raw-input");
+        let raw_output = args.get_flag("raw-output");
 
         let jq_expr = args.get_one::<String>("filter").unwrap().as_str();
-        let filter = compile_jq_filter(jq_expr);
+        let (var_names, var_values) = named_vars(args)?;
+        let filter = compile_jq_filter(jq_expr, &var_names)?;
+
+        if null_input {
+            // `-n` evaluates the filter exactly once, against a single `null`, without touching
+            // STDIN/the `--input` files at all -- `write_output` is the same escape hatch
+            // `inspect --hex=<literal>` uses for input coming from somewhere other than a file.
+            return CommandIo::new(args)?.write_output(|output| {
+                let null = JaqElement::from(Element::from(Value::Null(IonType::Null)));
+                run_one(&filter, output, null, jq_expr, raw_output, &var_values)
+            });
+        }
 
         CommandIo::new(args)?.for_each_input(|output, input| {
             let _format = output.format();
             let _encoding = output.encoding();
-            evaluate_jq_expr(input, output, &filter, slurp)?;
+            evaluate_jq_expr(
+                input,
+                output,
+                &filter,
+                jq_expr,
+                slurp,
+                raw_input,
+                raw_output,
+                &var_values,
+            )?;
             Ok(())
         })
     }
 }
 
-fn compile_jq_filter(jq_expr: &str) -> Filter<Native<JaqElement>> {
+/// Parses `--arg`/`--argjson`/`--rawfile` into a parallel list of variable names and the
+/// `JaqElement` each is bound to. `$name` lookups inside a compiled filter resolve by position,
+/// so `compile_jq_filter` declares globals (via `with_global_vars`) in this exact order and
+/// `filter_and_print`/`filter_and_print_raw` supply `Ctx::new` with the matching values in the
+/// same order; which of the three flags contributed a given name doesn't matter as long as the
+/// two lists stay parallel.
+fn named_vars(args: &ArgMatches) -> anyhow::Result<(Vec<String>, Vec<JaqElement>)> {
+    let mut names = Vec::new();
+    let mut values = Vec::new();
+
+    if let Some(pairs) = args.get_many::<String>("arg") {
+        for pair in pairs.collect::<Vec<_>>().chunks(2) {
+            names.push(pair[0].clone());
+            values.push(JaqElement::from(Element::from(pair[1].clone())));
+        }
+    }
+    if let Some(pairs) = args.get_many::<String>("argjson") {
+        for pair in pairs.collect::<Vec<_>>().chunks(2) {
+            let element = Element::read_one(pair[1].as_bytes())
+                .with_context(|| format!("--argjson {}: invalid Ion {:?}", pair[0], pair[1]))?;
+            names.push(pair[0].clone());
+            values.push(JaqElement::from(element));
+        }
+    }
+    if let Some(pairs) = args.get_many::<String>("rawfile") {
+        for pair in pairs.collect::<Vec<_>>().chunks(2) {
+            let contents = std::fs::read_to_string(&pair[1])
+                .with_context(|| format!("--rawfile {}: could not read {:?}", pair[0], pair[1]))?;
+            names.push(pair[0].clone());
+            values.push(JaqElement::from(Element::from(contents)));
+        }
+    }
+
+    Ok((names, values))
+}
+
+fn compile_jq_filter(
+    jq_expr: &str,
+    var_names: &[String],
+) -> anyhow::Result<Filter<Native<JaqElement>>> {
     use jaq_core::load::{Arena, File, Loader};
     let program = File {
         code: jq_expr, // a jq expression like ".[]"
         path: (),      // For error reporting, but not currently used by this program
     };
 
-    // If we wanted to define our own Ion-centric stdlib methods, we'd do something like:
-    //    Loader::new(jaq_std::defs().chain(jaq_ion::defs()))
-    let loader = Loader::new(jaq_std::defs());
+    // `ion_functions::ion_defs` layers Ion-specific convenience defs (type predicates/selectors,
+    // `as_symbol`, `isannotated`) over `jaq_std`'s.
+    let loader = Loader::new(jaq_std::defs().chain(ion_functions::ion_defs()));
     let arena = Arena::default();
 
-    // parse the filter
-    let modules = loader.load(&arena, program).unwrap();
+    // Parse the filter. `jaq_core`'s load errors don't expose a stable, documented way to recover
+    // the byte span of the fault in this version, so for now we can only render the failure
+    // message itself; see `Diagnostic`'s doc comment for what's missing to draw a caret here too.
+    let modules = loader.load(&arena, program).map_err(|errors| {
+        let rendered = errors
+            .iter()
+            .map(|(_, e)| Diagnostic::error(format!("{e:?}")).render(jq_expr))
+            .collect::<Vec<_>>()
+            .join("\n");
+        anyhow::anyhow!("ion jq: could not parse filter\n{rendered}")
+    })?;
 
     // compile the filter
     jaq_core::Compiler::default()
-        // Similar to `defs()` above, this would be our opportunity to extend the built-in filters
-        .with_funs(jaq_std::funs::<JaqElement>())
+        // Declares `$name` for every `--arg`/`--argjson`/`--rawfile` the user passed, in the same
+        // order `named_vars` built `var_names` -- `filter_and_print`/`filter_and_print_raw` bind
+        // the matching values to these positions via `Ctx::new`.
+        .with_global_vars(var_names.iter().map(String::as_str))
+        // `sort`/`unique`/`sort_by`/`unique_by`/`group_by` aren't part of `jaq_std::funs` since
+        // they need a total order `ValT` doesn't require -- `ion_funs` supplies Ion-aware ones
+        // backed by `JaqElement`'s `Ord` impl. `ion_functions::ion_funs` supplies natives for
+        // Ion-only constructs (annotations, symbols, timestamps, lobs) standard jq can't express.
+        .with_funs(
+            jaq_std::funs::<JaqElement>()
+                .chain(ion_funs::funs())
+                .chain(ion_functions::ion_funs()),
+        )
         .compile(modules)
-        .unwrap()
+        .map_err(|errors| {
+            let rendered = errors
+                .iter()
+                .map(|(_, e)| Diagnostic::error(format!("{e:?}")).render(jq_expr))
+                .collect::<Vec<_>>()
+                .join("\n");
+            anyhow::anyhow!("ion jq: could not compile filter\n{rendered}")
+        })
 }
 
 fn evaluate_jq_expr(
     input: CommandInput,
     output: &mut CommandOutput,
     filter: &Filter<Native<JaqElement>>,
+    jq_expr: &str,
     slurp: bool,
+    raw_input: bool,
+    raw_output: bool,
+    var_values: &[JaqElement],
 ) -> anyhow::Result<()> {
+    if raw_input {
+        return evaluate_jq_expr_raw_input(
+            input, output, filter, jq_expr, slurp, raw_output, var_values,
+        );
+    }
+
     let mut reader = Reader::new(AnyEncoding, input.into_source())?;
+
+    if raw_output {
+        if slurp {
+            let slurped = JaqElement::from(List::from(reader.read_all_elements()?));
+            filter_and_print_raw(filter, output, slurped, jq_expr, var_values)?;
+        } else {
+            for item in reader.elements() {
+                filter_and_print_raw(filter, output, item?.into(), jq_expr, var_values)?;
+            }
+        }
+        return Ok(());
+    }
+
     let mut writer = output.as_writer()?;
 
     if slurp {
         let all_input_elements = reader.read_all_elements()?;
         let slurped = List::from(all_input_elements).into();
-        filter_and_print(filter, &mut writer, slurped)?;
+        filter_and_print(filter, &mut writer, slurped, jq_expr, var_values)?;
     } else {
         for item in reader.elements() {
             let item: JaqElement = item?.into();
-            filter_and_print(filter, &mut writer, item)?;
+            filter_and_print(filter, &mut writer, item, jq_expr, var_values)?;
         }
     }
 
@@ -109,21 +255,114 @@ fn evaluate_jq_expr(
     Ok(())
 }
 
+/// Like [`evaluate_jq_expr`], but for `--raw-input`: each line of the input becomes a plain Ion
+/// string instead of being parsed as Ion, or (with `--slurp`) the whole input becomes one string.
+fn evaluate_jq_expr_raw_input(
+    input: CommandInput,
+    output: &mut CommandOutput,
+    filter: &Filter<Native<JaqElement>>,
+    jq_expr: &str,
+    slurp: bool,
+    raw_output: bool,
+    var_values: &[JaqElement],
+) -> anyhow::Result<()> {
+    use std::io::{BufRead, BufReader, Read};
+    let mut reader = BufReader::new(input.into_source());
+
+    if slurp {
+        let mut contents = String::new();
+        reader.read_to_string(&mut contents)?;
+        let item = JaqElement::from(Element::from(contents));
+        return run_one(filter, output, item, jq_expr, raw_output, var_values);
+    }
+
+    if raw_output {
+        for line in reader.lines() {
+            let item = JaqElement::from(Element::from(line?));
+            filter_and_print_raw(filter, output, item, jq_expr, var_values)?;
+        }
+    } else {
+        let mut writer = output.as_writer()?;
+        for line in reader.lines() {
+            let item = JaqElement::from(Element::from(line?));
+            filter_and_print(filter, &mut writer, item, jq_expr, var_values)?;
+        }
+        writer.close()?;
+    }
+    Ok(())
+}
+
+/// Runs `filter` once against a single `item` and writes its result(s), picking between the
+/// normal Ion-encoded writer and [`filter_and_print_raw`] based on `--raw-output`. Used by
+/// `--null-input`, which (unlike [`evaluate_jq_expr`]) only ever evaluates the filter once.
+fn run_one(
+    filter: &Filter<Native<JaqElement>>,
+    output: &mut CommandOutput,
+    item: JaqElement,
+    jq_expr: &str,
+    raw_output: bool,
+    var_values: &[JaqElement],
+) -> anyhow::Result<()> {
+    if raw_output {
+        filter_and_print_raw(filter, output, item, jq_expr, var_values)
+    } else {
+        let mut writer = output.as_writer()?;
+        filter_and_print(filter, &mut writer, item, jq_expr, var_values)?;
+        writer.close()?;
+        Ok(())
+    }
+}
+
 fn filter_and_print(
     filter: &Filter<Native<JaqElement>>,
     writer: &mut CommandOutputWriter,
     item: JaqElement,
+    jq_expr: &str,
+    var_values: &[JaqElement],
 ) -> anyhow::Result<()> {
     const EMPTY_ITER: RcIter<Empty<Result<JaqElement, String>>> = RcIter::new(core::iter::empty());
 
     let inputs = &EMPTY_ITER; // filter evaluation starts here, no other contextual inputs exist
-    let ctx = Ctx::new([], inputs); // manages variables etc., use one per filter execution
+    let ctx = Ctx::new(var_values.to_vec(), inputs); // manages variables etc., use one per filter execution
     let out = filter.run((ctx, item));
 
     for value in out {
         match value {
             Ok(element) => writer.write(&element.0)?,
-            Err(e) => bail!("ion jq: {e}"),
+            // `jaq_core::Error<V>` carries only the offending value, not a source location, so
+            // this diagnostic has no span and renders without a caret.
+            Err(e) => bail!("{}", Diagnostic::error(format!("{e}")).render(jq_expr)),
+        };
+    }
+    Ok(())
+}
+
+/// Like [`filter_and_print`], but for `--raw-output`: a string or symbol result is written as its raw text
+/// (no surrounding quotes); anything else still falls back to normal Ion text via `Display`. This
+/// bypasses `CommandOutputWriter` entirely and writes straight to `output`, so (unlike the normal
+/// path) the requested `--format`/binary encoding has no effect on `--raw-output` results -- jq
+/// itself has no binary output mode for `-r` to agree or disagree with, so printing plain Ion text
+/// is the pragmatic choice here.
+fn filter_and_print_raw(
+    filter: &Filter<Native<JaqElement>>,
+    output: &mut CommandOutput,
+    item: JaqElement,
+    jq_expr: &str,
+    var_values: &[JaqElement],
+) -> anyhow::Result<()> {
+    const EMPTY_ITER: RcIter<Empty<Result<JaqElement, String>>> = RcIter::new(core::iter::empty());
+
+    let inputs = &EMPTY_ITER;
+    let ctx = Ctx::new(var_values.to_vec(), inputs);
+    let out = filter.run((ctx, item));
+
+    for value in out {
+        match value {
+            Ok(element) => match element.as_str() {
+                Some(text) => writeln!(output, "{text}")?,
+                None => writeln!(output, "{element}")?,
+            },
+            Err(e) => bail!("{}", Diagnostic::error(format!("{e}")).render(jq_expr)),
         };
     }
     Ok(())
@@ -240,7 +479,7 @@ impl Add for JaqElement {
     fn add(self, _rhs: Self) -> Self::Output {
         let (lhv, rhv) = (self.into_value(), _rhs.into_value());
 
-        use ion_math::{DecimalMath, ToFloat};
+        use ion_math::{DecimalMath, TimestampMath, ToFloat};
         use Value::*;
 
         let elt: Element = match (lhv, rhv) {
@@ -273,6 +512,21 @@ impl Add for JaqElement {
             (Decimal(a), Decimal(b)) => a.add(b).into(),
             (Decimal(a), Int(b)) | (Int(b), Decimal(a)) => a.add(b).into(),
 
+            // A timestamp plus a duration in seconds (an Int or Decimal) shifts it forward.
+            (Timestamp(t), Int(n)) | (Int(n), Timestamp(t)) => {
+                let seconds = n.clone().into_decimal();
+                match t.clone().shift(seconds) {
+                    Ok(shifted) => shifted.into(),
+                    Err(_) => return jaq_binary_error(Timestamp(t), Int(n), "cannot be added"),
+                }
+            }
+            (Timestamp(t), Decimal(d)) | (Decimal(d), Timestamp(t)) => {
+                match t.clone().shift(d.clone()) {
+                    Ok(shifted) => shifted.into(),
+                    Err(_) => return jaq_binary_error(Timestamp(t), Decimal(d), "cannot be added"),
+                }
+            }
+
             // Only try potentially lossy Float conversions when we've run out of the other options
             (a @ Int(_) | a @ Decimal(_), Float(b)) => (a.to_f64().unwrap() + b).into(),
             (Float(a), b @ Int(_) | b @ Decimal(_)) => (a + b.to_f64().unwrap()).into(),
@@ -294,14 +548,18 @@ impl Sub for JaqElement {
     fn sub(self, _rhs: Self) -> Self::Output {
         let (lhv, rhv) = (self.into_value(), _rhs.into_value());
 
-        use ion_math::{DecimalMath, ToFloat};
+        use ion_math::{negate_decimal, DecimalMath, TimestampMath, ToFloat};
         use Value::*;
 
-        // b.iter.contains() will make these implementations O(N^2).
-        // Neither Element nor Value implement Hash or Ord, so faster lookup isn't available
-        // Perhaps someday we can do something more clever with ionhash or IonOrd?
+        // Neither Element nor Value implement Hash, so a HashSet is out, but IonData already
+        // gives us a total Ord (see `cmp`/`partial_cmp` below) -- build a BTreeSet of the RHS's
+        // canonical ordering once and test each LHS element against it, which is O(N log N)
+        // rather than the O(N^2) of `b.iter().contains(i)` per element.
         fn remove_elements(a: Sequence, b: &Sequence) -> impl Iterator<Item = Element> + '_ {
-            a.into_iter().filter(|i| !b.iter().contains(i))
+            let excluded: std::collections::BTreeSet<IonData<&Element>> =
+                b.iter().map(IonData::from).collect();
+            a.into_iter()
+                .filter(move |i| !excluded.contains(&IonData::from(i)))
         }
 
         let elt: Element = match (lhv, rhv) {
@@ -316,6 +574,24 @@ impl Sub for JaqElement {
             (Decimal(a), Int(b)) => a.sub(b).into(),
             (Int(a), Decimal(b)) => a.sub(b).into(),
 
+            // Timestamp subtraction: two timestamps give the Decimal number of seconds between
+            // them; a timestamp and a duration in seconds shifts it backward.
+            (Timestamp(a), Timestamp(b)) => match a.clone().diff_seconds(b.clone()) {
+                Ok(seconds) => seconds.into(),
+                Err(_) => return jaq_binary_error(Timestamp(a), Timestamp(b), "cannot be subtracted"),
+            },
+            (Timestamp(t), Int(n)) => {
+                let seconds = negate_decimal(n.clone().into_decimal());
+                match t.clone().shift(seconds) {
+                    Ok(shifted) => shifted.into(),
+                    Err(_) => return jaq_binary_error(Timestamp(t), Int(n), "cannot be subtracted"),
+                }
+            }
+            (Timestamp(t), Decimal(d)) => match t.clone().shift(negate_decimal(d.clone())) {
+                Ok(shifted) => shifted.into(),
+                Err(_) => return jaq_binary_error(Timestamp(t), Decimal(d), "cannot be subtracted"),
+            },
+
             // Only try potentially lossy Float conversions when we've run out of the other options
             (a @ Int(_) | a @ Decimal(_), Float(b)) => (a.to_f64().unwrap() - b).into(),
             (Float(a), b @ Int(_) | b @ Decimal(_)) => (a - b.to_f64().unwrap()).into(),
@@ -386,7 +662,7 @@ impl Div for JaqElement {
     fn div(self, _rhs: Self) -> Self::Output {
         let (lhv, rhv) = (self.into_value(), _rhs.into_value());
 
-        use ion_math::{DecimalMath, ToFloat};
+        use ion_math::{DecimalMath, ExactRatio, ToFloat, DEFAULT_DIVISION_PRECISION};
         use Value::*;
 
         let elt: Element = match (lhv, rhv) {
@@ -406,12 +682,49 @@ impl Div for JaqElement {
             }
             .into(),
 
-            // Number types, only lossless operations
-            (Int(a), Int(b)) => (a.expect_i128().unwrap() / b.expect_i128().unwrap()).into(),
+            // Number types. Unlike `+ - *`, division between `Int`/`Decimal` operands isn't
+            // necessarily exactly representable as a `Decimal` (e.g. `1 / 3`), so it's routed
+            // through `ExactRatio` instead of straight to `BigDecimal`: the result comes back
+            // exact when it terminates, and rounded to `DEFAULT_DIVISION_PRECISION` significant
+            // digits -- rather than silently truncated, or degraded to a lossy `f64` -- when it
+            // doesn't. An exact result whose coefficient doesn't fit `Decimal`'s `i128` range is a
+            // real error (see `ExactRatio::into_decimal`'s doc comment), surfaced here rather than
+            // panicking.
+            (Int(a), Int(b)) => match ExactRatio::from_int(a.clone())
+                .div(ExactRatio::from_int(b.clone()))
+                .into_decimal(DEFAULT_DIVISION_PRECISION)
+            {
+                Ok(d) => d.into(),
+                Err(e) => return jaq_binary_error(Int(a), Int(b), &format!("cannot be divided: {e}")),
+            },
             (Float(a), Float(b)) => (a / b).into(),
-            (Decimal(a), Decimal(b)) => a.div(b).into(),
-            (Decimal(a), Int(b)) => a.div(b).into(),
-            (Int(a), Decimal(b)) => a.div(b).into(),
+            (Decimal(a), Decimal(b)) => match ExactRatio::from_decimal(a.clone())
+                .div(ExactRatio::from_decimal(b.clone()))
+                .into_decimal(DEFAULT_DIVISION_PRECISION)
+            {
+                Ok(d) => d.into(),
+                Err(e) => {
+                    return jaq_binary_error(Decimal(a), Decimal(b), &format!("cannot be divided: {e}"))
+                }
+            },
+            (Decimal(a), Int(b)) => match ExactRatio::from_decimal(a.clone())
+                .div(ExactRatio::from_int(b.clone()))
+                .into_decimal(DEFAULT_DIVISION_PRECISION)
+            {
+                Ok(d) => d.into(),
+                Err(e) => {
+                    return jaq_binary_error(Decimal(a), Int(b), &format!("cannot be divided: {e}"))
+                }
+            },
+            (Int(a), Decimal(b)) => match ExactRatio::from_int(a.clone())
+                .div(ExactRatio::from_decimal(b.clone()))
+                .into_decimal(DEFAULT_DIVISION_PRECISION)
+            {
+                Ok(d) => d.into(),
+                Err(e) => {
+                    return jaq_binary_error(Int(a), Decimal(b), &format!("cannot be divided: {e}"))
+                }
+            },
 
             // Only try potentially lossy Float conversions when we've run out of the other options
             (a @ Int(_) | a @ Decimal(_), Float(b)) => (a.to_f64().unwrap() / b).into(),
@@ -430,16 +743,44 @@ impl Rem for JaqElement {
     fn rem(self, _rhs: Self) -> Self::Output {
         let (lhv, rhv) = (self.into_value(), _rhs.into_value());
 
-        use ion_math::{DecimalMath, ToFloat};
+        use ion_math::{ExactRatio, ToFloat, DEFAULT_DIVISION_PRECISION};
         use Value::*;
 
         let elt: Element = match (lhv, rhv) {
             // Number types, only lossless operations
             (Int(a), Int(b)) => (a.expect_i128().unwrap() % b.expect_i128().unwrap()).into(),
             (Float(a), Float(b)) => (a % b).into(),
-            (Decimal(a), Decimal(b)) => a.rem(b).into(),
-            (Decimal(a), Int(b)) => a.rem(b).into(),
-            (Int(a), Decimal(b)) => a.rem(b).into(),
+            // Routed through `ExactRatio` (the same exact-rational backend `/` above uses) rather
+            // than straight `BigDecimal`, so a remainder is never less precise than the division
+            // it corresponds to, and an unrepresentable exact result is a proper error rather than
+            // a panic (see `ExactRatio::into_decimal`'s doc comment).
+            (Decimal(a), Decimal(b)) => match ExactRatio::from_decimal(a.clone())
+                .rem(ExactRatio::from_decimal(b.clone()))
+                .into_decimal(DEFAULT_DIVISION_PRECISION)
+            {
+                Ok(d) => d.into(),
+                Err(e) => {
+                    return jaq_binary_error(Decimal(a), Decimal(b), &format!("cannot be divided (remainder): {e}"))
+                }
+            },
+            (Decimal(a), Int(b)) => match ExactRatio::from_decimal(a.clone())
+                .rem(ExactRatio::from_int(b.clone()))
+                .into_decimal(DEFAULT_DIVISION_PRECISION)
+            {
+                Ok(d) => d.into(),
+                Err(e) => {
+                    return jaq_binary_error(Decimal(a), Int(b), &format!("cannot be divided (remainder): {e}"))
+                }
+            },
+            (Int(a), Decimal(b)) => match ExactRatio::from_int(a.clone())
+                .rem(ExactRatio::from_decimal(b.clone()))
+                .into_decimal(DEFAULT_DIVISION_PRECISION)
+            {
+                Ok(d) => d.into(),
+                Err(e) => {
+                    return jaq_binary_error(Int(a), Decimal(b), &format!("cannot be divided (remainder): {e}"))
+                }
+            },
 
             // Only try potentially lossy Float conversions when we've run out of the other options
             (a @ Int(_) | a @ Decimal(_), Float(b)) => (a.to_f64().unwrap() % b).into(),
@@ -481,8 +822,22 @@ impl Display for JaqElement {
 }
 
 impl jaq_core::ValT for JaqElement {
-    // Going from numeric text to an Element
+    // Going from numeric text to an Element. Picks the most precise Ion type that exactly
+    // represents `n`: an `Int` for a plain integer literal that fits in an `i128`, a `Decimal`
+    // for any other literal whose coefficient fits in an `i128` (e.g. `0.1` or `1e400`), and a
+    // lossy `Float` otherwise -- e.g. a 40-digit integer literal is not kept exact.
     fn from_num(n: &str) -> ValR<Self> {
+        if !n.contains(['.', 'e', 'E']) {
+            if let Ok(i) = n.parse::<i128>() {
+                return Ok(Element::from(i).into());
+            }
+        }
+        if let Ok(big_decimal) = BigDecimal::from_str(n) {
+            let (coefficient, exponent) = big_decimal.into_bigint_and_exponent();
+            if let Some(coefficient) = coefficient.to_i128() {
+                return Ok(Element::from(Decimal::new(coefficient, -exponent)).into());
+            }
+        }
         match f64::from_str(n) {
             Ok(f) => Ok(Element::from(f).into()),
             Err(_) => jaq_error(format!("invalid number: {n}")),
@@ -557,38 +912,305 @@ impl jaq_core::ValT for JaqElement {
         Ok(JaqElement::from(elt))
     }
 
-    // Behavior for slicing containers.
-    fn range(self, _range: Range<&Self>) -> ValR<Self> {
-        todo!()
+    // Behavior for slicing containers: `.[start:end]`. A negative bound counts from the end, the
+    // same way `index_i128` (above) resolves a single negative index; a bound that is still out of
+    // bounds clamps to the container's start or end instead of producing `null`.
+    fn range(self, range: Range<&Self>) -> ValR<Self> {
+        use ion_rs::Value::*;
+        use ion_math::DecimalMath;
+
+        fn to_bound(v: Option<&Value>) -> Option<i128> {
+            match v {
+                Some(Int(i)) => i.as_i128(),
+                Some(Float(f)) => Some(*f as i128),
+                Some(Decimal(d)) => d.clone().into_big_decimal().to_i128(),
+                _ => None,
+            }
+        }
+
+        // Normalizes a (possibly negative, possibly out-of-range) `start`/`end` pair into a valid
+        // `start..end` `usize` span over a container of length `len`, the way `.[-1:]`/`.[:100]`
+        // clamp instead of erroring in jq.
+        fn bounds(start: Option<i128>, end: Option<i128>, len: usize) -> (usize, usize) {
+            let len_i = len as i128;
+            let norm = |i: i128| if i < 0 { (len_i + i).max(0) } else { i };
+            let clamp = |i: i128| i.max(0).min(len_i) as usize;
+            let s = clamp(norm(start.unwrap_or(0)));
+            let e = clamp(norm(end.unwrap_or(len_i))).max(s);
+            (s, e)
+        }
+
+        let start = to_bound(range.start.map(|e| e.value()));
+        let end = to_bound(range.end.map(|e| e.value()));
+
+        let elt: Element = match self.into_value() {
+            List(seq) => {
+                let items: Vec<Element> = seq.into_iter().collect();
+                let (s, e) = bounds(start, end, items.len());
+                ion_rs::List::from_iter(items[s..e].iter().cloned()).into()
+            }
+            SExp(seq) => {
+                let items: Vec<Element> = seq.into_iter().collect();
+                let (s, e) = bounds(start, end, items.len());
+                ion_rs::SExp::from_iter(items[s..e].iter().cloned()).into()
+            }
+            String(text) => {
+                let chars: Vec<char> = text.text().chars().collect();
+                let (s, e) = bounds(start, end, chars.len());
+                chars[s..e].iter().collect::<std::string::String>().into()
+            }
+            other => return jaq_unary_error(other, "cannot be sliced"),
+        };
+
+        Ok(JaqElement::from(elt))
     }
 
-    // Map a function over `self`'s child values
+    // Map a function over `self`'s child values, rebuilding the same kind of container from
+    // whichever value each invocation of `f` produces last (mirroring how jq's `_modify`/`|=`
+    // folds a path update's possibly-multiple outputs down to one), or dropping the child
+    // entirely when `f` produces no output at all -- this is how `del(.[])`/`map(empty)` remove
+    // elements rather than erroring.
     fn map_values<'a, I: Iterator<Item = ValX<'a, Self>>>(
         self,
-        _opt: Opt,
-        _f: impl Fn(Self) -> I,
+        opt: Opt,
+        f: impl Fn(Self) -> I,
     ) -> ValX<'a, Self> {
-        todo!()
+        use ion_rs::Value::*;
+
+        fn last_output<'a, I: Iterator<Item = ValX<'a, JaqElement>>>(
+            iter: I,
+        ) -> Result<Option<JaqElement>, jaq_core::Exn<'a, JaqElement>> {
+            let mut last = None;
+            for result in iter {
+                last = Some(result?);
+            }
+            Ok(last)
+        }
+
+        match self.into_value() {
+            List(seq) => {
+                let mut items = Vec::new();
+                for child in seq {
+                    if let Some(v) = last_output(f(JaqElement::from(child)))? {
+                        items.push(v.into_inner());
+                    }
+                }
+                Ok(JaqElement::from(ion_rs::List::from_iter(items)))
+            }
+            SExp(seq) => {
+                let mut items = Vec::new();
+                for child in seq {
+                    if let Some(v) = last_output(f(JaqElement::from(child)))? {
+                        items.push(v.into_inner());
+                    }
+                }
+                Ok(JaqElement::from(ion_rs::SExp::from_iter(items)))
+            }
+            Struct(strukt) => {
+                let mut builder = ion_rs::Struct::builder();
+                for (name, child) in strukt {
+                    if let Some(v) = last_output(f(JaqElement::from(child)))? {
+                        builder = builder.with_field(name, v.into_inner());
+                    }
+                }
+                Ok(JaqElement::from(builder.build()))
+            }
+            other => match opt {
+                Opt::Optional => Ok(JaqElement::from(other)),
+                Opt::Essential => jaq_error(format!("{other} cannot be iterated over"))
+                    .map_err(Into::into),
+            },
+        }
     }
 
-    // Map a function over the child value found at the given index
+    // Map a function over the single child value found at a struct key or sequence index,
+    // reinserting its (last) output in place, or deleting that key/index if `f` produces no
+    // output. A missing key/out-of-bounds index is a no-op under `Opt::Optional` (e.g. `.a?|=f`).
+    // Under `Opt::Essential` an out-of-bounds index is an error, while a missing struct key is
+    // added with `f`'s (last) output for `null`.
     fn map_index<'a, I: Iterator<Item = ValX<'a, Self>>>(
         self,
-        _index: &Self,
-        _opt: Opt,
-        _f: impl Fn(Self) -> I,
+        index: &Self,
+        opt: Opt,
+        f: impl Fn(Self) -> I,
     ) -> ValX<'a, Self> {
-        todo!()
+        use ion_rs::Value::*;
+
+        fn last_output<'a, I: Iterator<Item = ValX<'a, JaqElement>>>(
+            iter: I,
+        ) -> Result<Option<JaqElement>, jaq_core::Exn<'a, JaqElement>> {
+            let mut last = None;
+            for result in iter {
+                last = Some(result?);
+            }
+            Ok(last)
+        }
+
+        /// Resolves a (possibly negative) index against a sequence of length `len`, the same way
+        /// `index_i128` (above) does for plain `.[i]` indexing.
+        fn resolve(len: usize, i: i128) -> Option<usize> {
+            let i = if i < 0 { len as i128 + i } else { i };
+            (i >= 0 && i < len as i128).then_some(i as usize)
+        }
+
+        match (self.into_value(), index.value()) {
+            (List(seq), Int(i)) => {
+                let mut items: Vec<Element> = seq.into_iter().collect();
+                let Some(idx) = i.as_i128().and_then(|i| resolve(items.len(), i)) else {
+                    return match opt {
+                        Opt::Optional => Ok(JaqElement::from(ion_rs::List::from_iter(items))),
+                        Opt::Essential => jaq_error("index out of bounds").map_err(Into::into),
+                    };
+                };
+                match last_output(f(JaqElement::from(items[idx].clone())))? {
+                    Some(v) => items[idx] = v.into_inner(),
+                    None => {
+                        items.remove(idx);
+                    }
+                }
+                Ok(JaqElement::from(ion_rs::List::from_iter(items)))
+            }
+            (SExp(seq), Int(i)) => {
+                let mut items: Vec<Element> = seq.into_iter().collect();
+                let Some(idx) = i.as_i128().and_then(|i| resolve(items.len(), i)) else {
+                    return match opt {
+                        Opt::Optional => Ok(JaqElement::from(ion_rs::SExp::from_iter(items))),
+                        Opt::Essential => jaq_error("index out of bounds").map_err(Into::into),
+                    };
+                };
+                match last_output(f(JaqElement::from(items[idx].clone())))? {
+                    Some(v) => items[idx] = v.into_inner(),
+                    None => {
+                        items.remove(idx);
+                    }
+                }
+                Ok(JaqElement::from(ion_rs::SExp::from_iter(items)))
+            }
+            (Struct(strukt), idx_val) => {
+                let key = match idx_val {
+                    String(s) => Some(s.text()),
+                    Symbol(s) => s.text(),
+                    _ => None,
+                };
+                let Some(key) = key else {
+                    return jaq_error(format!("cannot index struct with {}", idx_val.ion_type()))
+                        .map_err(Into::into);
+                };
+                let mut builder = ion_rs::Struct::builder();
+                let mut found = false;
+                for (name, value) in strukt {
+                    if !found && name.text() == Some(key) {
+                        found = true;
+                        if let Some(v) = last_output(f(JaqElement::from(value)))? {
+                            builder = builder.with_field(name, v.into_inner());
+                        }
+                    } else {
+                        builder = builder.with_field(name, value);
+                    }
+                }
+                if !found {
+                    match opt {
+                        Opt::Optional => {}
+                        Opt::Essential => {
+                            if let Some(v) =
+                                last_output(f(JaqElement::from(Element::from(Null(IonType::Null)))))?
+                            {
+                                builder = builder.with_field(key, v.into_inner());
+                            }
+                        }
+                    }
+                }
+                Ok(JaqElement::from(builder.build()))
+            }
+            (other, idx_val) => {
+                let (alpha, beta) = (other.ion_type(), idx_val.ion_type());
+                jaq_error(format!("cannot index {alpha} with {beta}")).map_err(Into::into)
+            }
+        }
     }
 
-    // Map a function over a range of child values
+    // Map a function over a `.[start:end]` slice, splicing its (last) output -- flattened if it's
+    // itself a sequence, inserted as a single element otherwise, or nothing at all if `f` produces
+    // no output -- back into the container in place of the original slice.
     fn map_range<'a, I: Iterator<Item = ValX<'a, Self>>>(
         self,
-        _range: Range<&Self>,
-        _opt: Opt,
-        _f: impl Fn(Self) -> I,
+        range: Range<&Self>,
+        opt: Opt,
+        f: impl Fn(Self) -> I,
     ) -> ValX<'a, Self> {
-        todo!()
+        use ion_rs::Value::*;
+        use ion_math::DecimalMath;
+
+        fn last_output<'a, I: Iterator<Item = ValX<'a, JaqElement>>>(
+            iter: I,
+        ) -> Result<Option<JaqElement>, jaq_core::Exn<'a, JaqElement>> {
+            let mut last = None;
+            for result in iter {
+                last = Some(result?);
+            }
+            Ok(last)
+        }
+
+        fn to_bound(v: Option<&Value>) -> Option<i128> {
+            match v {
+                Some(Int(i)) => i.as_i128(),
+                Some(Float(f)) => Some(*f as i128),
+                Some(Decimal(d)) => d.clone().into_big_decimal().to_i128(),
+                _ => None,
+            }
+        }
+
+        fn bounds(start: Option<i128>, end: Option<i128>, len: usize) -> (usize, usize) {
+            let len_i = len as i128;
+            let norm = |i: i128| if i < 0 { (len_i + i).max(0) } else { i };
+            let clamp = |i: i128| i.max(0).min(len_i) as usize;
+            let s = clamp(norm(start.unwrap_or(0)));
+            let e = clamp(norm(end.unwrap_or(len_i))).max(s);
+            (s, e)
+        }
+
+        fn splice(replacement: Option<JaqElement>) -> Vec<Element> {
+            use ion_rs::Value::*;
+            match replacement {
+                None => Vec::new(),
+                Some(v) => match v.into_inner().into_value() {
+                    List(seq) | SExp(seq) => seq.into_iter().collect(),
+                    other => vec![other.into()],
+                },
+            }
+        }
+
+        let start = to_bound(range.start.map(|e| e.value()));
+        let end = to_bound(range.end.map(|e| e.value()));
+
+        match self.into_value() {
+            List(seq) => {
+                let items: Vec<Element> = seq.into_iter().collect();
+                let (s, e) = bounds(start, end, items.len());
+                let slice = ion_rs::List::from_iter(items[s..e].iter().cloned());
+                let replacement = last_output(f(JaqElement::from(Element::from(slice))))?;
+                let mut out = items[..s].to_vec();
+                out.append(&mut splice(replacement));
+                out.extend(items[e..].iter().cloned());
+                Ok(JaqElement::from(ion_rs::List::from_iter(out)))
+            }
+            SExp(seq) => {
+                let items: Vec<Element> = seq.into_iter().collect();
+                let (s, e) = bounds(start, end, items.len());
+                let slice = ion_rs::SExp::from_iter(items[s..e].iter().cloned());
+                let replacement = last_output(f(JaqElement::from(Element::from(slice))))?;
+                let mut out = items[..s].to_vec();
+                out.append(&mut splice(replacement));
+                out.extend(items[e..].iter().cloned());
+                Ok(JaqElement::from(ion_rs::SExp::from_iter(out)))
+            }
+            other => match opt {
+                Opt::Optional => Ok(JaqElement::from(other)),
+                Opt::Essential => {
+                    jaq_error(format!("{other} cannot be sliced")).map_err(Into::into)
+                }
+            },
+        }
     }
 
     /// From https://jqlang.org/manual/#if-then-else-end
@@ -646,14 +1268,47 @@ impl jaq_std::ValT for JaqElement {
 pub(crate) mod ion_math {
     use bigdecimal::num_bigint::BigInt;
     use bigdecimal::{BigDecimal, ToPrimitive};
+    use chrono::{DateTime, Duration as ChronoDuration, FixedOffset};
     use ion_rs::decimal::coefficient::Sign;
-    use ion_rs::{Decimal, Int, Value};
+    use ion_rs::{Decimal, Int, Timestamp, Value};
+    use std::cmp::Ordering;
+    use std::fmt::{Display, Formatter};
+
+    /// Why a lossless conversion back to [`Decimal`] failed.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub(crate) enum ArithmeticError {
+        /// The computed coefficient doesn't fit in the `i128` range `Decimal`/`Int` are backed
+        /// by.
+        CoefficientOutOfRange,
+        /// The operand wasn't a number at all; carries a description of what was found instead.
+        NotANumber(String),
+    }
+
+    impl Display for ArithmeticError {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            match self {
+                ArithmeticError::CoefficientOutOfRange => {
+                    write!(f, "result is too large to represent exactly")
+                }
+                ArithmeticError::NotANumber(found) => write!(f, "{found} is not a number"),
+            }
+        }
+    }
 
     /// We can't provide math traits for Decimal directly, so we have a helper trait
     pub(crate) trait DecimalMath: Sized {
         fn into_big_decimal(self) -> BigDecimal;
         fn into_decimal(self) -> Decimal;
 
+        /// Like [`into_decimal`](Self::into_decimal), but reports a coefficient that doesn't fit
+        /// in `Decimal`'s `i128` range as an [`ArithmeticError`] instead of silently saturating or
+        /// (as `into_decimal` used to) panicking.
+        fn try_into_decimal(self) -> Result<Decimal, ArithmeticError>;
+
+        /// Like [`into_decimal`](Self::into_decimal), but an out-of-range coefficient clamps to
+        /// `i128::MAX`/`i128::MIN` (preserving sign) instead of erroring.
+        fn saturating_into_decimal(self) -> Decimal;
+
         fn add(self, v2: impl DecimalMath) -> Decimal {
             (self.into_big_decimal() + v2.into_big_decimal()).into_decimal()
         }
@@ -688,6 +1343,14 @@ pub(crate) mod ion_math {
         fn into_decimal(self) -> Decimal {
             self
         }
+
+        fn try_into_decimal(self) -> Result<Decimal, ArithmeticError> {
+            Ok(self)
+        }
+
+        fn saturating_into_decimal(self) -> Decimal {
+            self
+        }
     }
 
     impl DecimalMath for Int {
@@ -700,6 +1363,16 @@ pub(crate) mod ion_math {
             let data = self.expect_i128().unwrap(); // error case is unreachable with current ion-rs
             Decimal::new(data, 0)
         }
+
+        fn try_into_decimal(self) -> Result<Decimal, ArithmeticError> {
+            self.expect_i128()
+                .map(|data| Decimal::new(data, 0))
+                .map_err(|_| ArithmeticError::CoefficientOutOfRange)
+        }
+
+        fn saturating_into_decimal(self) -> Decimal {
+            self.into_decimal()
+        }
     }
 
     impl DecimalMath for BigDecimal {
@@ -708,12 +1381,376 @@ pub(crate) mod ion_math {
         }
 
         fn into_decimal(self) -> Decimal {
+            self.saturating_into_decimal()
+        }
+
+        fn try_into_decimal(self) -> Result<Decimal, ArithmeticError> {
+            let (coeff, exponent) = self.into_bigint_and_exponent();
+            coeff
+                .to_i128()
+                .map(|data| Decimal::new(data, -exponent))
+                .ok_or(ArithmeticError::CoefficientOutOfRange)
+        }
+
+        fn saturating_into_decimal(self) -> Decimal {
             let (coeff, exponent) = self.into_bigint_and_exponent();
-            let data = coeff.to_i128().unwrap();
+            let negative = coeff < BigInt::from(0);
+            let data = coeff
+                .to_i128()
+                .unwrap_or(if negative { i128::MIN } else { i128::MAX });
             Decimal::new(data, -exponent)
         }
     }
 
+    /// Significant digits kept when a division's exact result doesn't terminate (e.g. `1 / 3`).
+    /// This mirrors the precision jq itself gets from IEEE 754 doubles (~15-17 significant
+    /// digits) generously rounded up, so dividing Ion's arbitrary-precision numbers doesn't read
+    /// as less precise than dividing ordinary jq numbers would.
+    pub(crate) const DEFAULT_DIVISION_PRECISION: u32 = 34;
+
+    /// How [`ExactRatio::into_decimal_with`]/[`ExactRatio::round_dp`] resolve an inexact result,
+    /// i.e. one that doesn't terminate at the requested precision.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub(crate) enum RoundingMode {
+        /// Round to nearest, ties away from zero (what [`ExactRatio::into_decimal`] has always
+        /// done, kept as the crate-wide default).
+        HalfUp,
+        /// Round to nearest, ties to the nearest even last digit -- the "banker's rounding" IEEE
+        /// 754 and most decimal arithmetic standards use by default, since it doesn't bias the
+        /// sum of many rounded values upward the way `HalfUp` does.
+        HalfEven,
+        /// Truncate toward zero; never increases the magnitude of the result.
+        Down,
+        /// Round toward positive infinity.
+        Ceiling,
+        /// Round toward negative infinity.
+        Floor,
+    }
+
+    /// Bundles the precision and tie-breaking rule for a rounding operation, so callers that care
+    /// about deterministic, standards-compliant results (e.g. financial arithmetic) can request
+    /// something other than [`ExactRatio`]'s historical default of `HalfUp` at
+    /// [`DEFAULT_DIVISION_PRECISION`] significant digits.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub(crate) struct DecimalContext {
+        pub(crate) precision: u32,
+        pub(crate) rounding: RoundingMode,
+    }
+
+    impl Default for DecimalContext {
+        fn default() -> Self {
+            DecimalContext {
+                precision: DEFAULT_DIVISION_PRECISION,
+                rounding: RoundingMode::HalfUp,
+            }
+        }
+    }
+
+    /// An exact ratio of arbitrary-precision integers, reduced to lowest terms with a positive
+    /// denominator. `Int`/`Decimal` division is the one numeric operation that isn't necessarily
+    /// representable as an Ion `Decimal` (e.g. `1 / 3`), so unlike [`DecimalMath`] -- whose `+ -
+    /// *` are already exact -- `div` below routes through this type instead of going straight to
+    /// `BigDecimal`, which has no notion of "repeating" and would otherwise have to guess a
+    /// precision internally. This keeps the truncation decision visible at the call site.
+    #[derive(Clone)]
+    pub(crate) struct ExactRatio {
+        numerator: BigInt,
+        denominator: BigInt,
+    }
+
+    impl ExactRatio {
+        fn new(numerator: BigInt, denominator: BigInt) -> Self {
+            let (numerator, denominator) = if denominator < BigInt::from(0) {
+                (-numerator, -denominator)
+            } else {
+                (numerator, denominator)
+            };
+            let divisor = gcd(numerator.clone(), denominator.clone());
+            if divisor == BigInt::from(0) {
+                ExactRatio { numerator, denominator }
+            } else {
+                ExactRatio {
+                    numerator: numerator / divisor.clone(),
+                    denominator: denominator / divisor,
+                }
+            }
+        }
+
+        pub(crate) fn from_int(i: Int) -> Self {
+            let data = i.expect_i128().unwrap(); // error case is unreachable with current ion-rs
+            ExactRatio::new(BigInt::from(data), BigInt::from(1))
+        }
+
+        pub(crate) fn from_decimal(d: Decimal) -> Self {
+            let (coeff, exponent) = d.into_big_decimal().into_bigint_and_exponent();
+            if exponent >= 0 {
+                ExactRatio::new(coeff, BigInt::from(10).pow(exponent as u64))
+            } else {
+                ExactRatio::new(coeff * BigInt::from(10).pow((-exponent) as u64), BigInt::from(1))
+            }
+        }
+
+        pub(crate) fn div(self, other: Self) -> Self {
+            ExactRatio::new(
+                self.numerator * other.denominator,
+                self.denominator * other.numerator,
+            )
+        }
+
+        /// `self - trunc(self / other) * other`, i.e. the exact remainder of `div` above, with
+        /// the same truncate-toward-zero convention `%` uses for ordinary integers.
+        pub(crate) fn rem(self, other: Self) -> Self {
+            let truncated_quotient = (self.numerator.clone() * other.denominator.clone())
+                / (self.denominator.clone() * other.numerator.clone());
+            let scaled_other = ExactRatio::new(truncated_quotient, BigInt::from(1)).mul(other);
+            self.sub(scaled_other)
+        }
+
+        fn add(self, other: Self) -> Self {
+            let numerator = self.numerator * other.denominator.clone()
+                + other.numerator * self.denominator.clone();
+            ExactRatio::new(numerator, self.denominator * other.denominator)
+        }
+
+        fn sub(self, other: Self) -> Self {
+            let numerator = self.numerator * other.denominator.clone()
+                - other.numerator * self.denominator.clone();
+            ExactRatio::new(numerator, self.denominator * other.denominator)
+        }
+
+        fn mul(self, other: Self) -> Self {
+            ExactRatio::new(
+                self.numerator * other.numerator,
+                self.denominator * other.denominator,
+            )
+        }
+
+        /// True if this ratio's reduced denominator is only made up of factors of 2 and 5, i.e.
+        /// it has a terminating decimal expansion (like `1/8`) rather than a repeating one (like
+        /// `1/3`).
+        fn has_terminating_decimal(&self) -> bool {
+            let mut remainder = self.denominator.clone();
+            for factor in [BigInt::from(2), BigInt::from(5)] {
+                while &remainder % &factor == BigInt::from(0) {
+                    remainder = remainder / &factor;
+                }
+            }
+            remainder == BigInt::from(1)
+        }
+
+        /// Converts this ratio to the Ion `Decimal` that exactly represents it if its denominator
+        /// is only made up of factors of 2 and 5; otherwise rounds half-up to `precision`
+        /// significant digits, which is the only case where this operation is lossy. Equivalent
+        /// to [`into_decimal_with`](Self::into_decimal_with) with [`DecimalContext::default`].
+        ///
+        /// Errors (rather than silently saturating, the way the rounded branch already does) when
+        /// the *exact* terminating-decimal coefficient doesn't fit `i128` -- unlike a rounded
+        /// result, an exact one that got clamped would be silently wrong, not just imprecise.
+        pub(crate) fn into_decimal(self, precision: u32) -> Result<Decimal, ArithmeticError> {
+            self.into_decimal_with(DecimalContext {
+                precision,
+                rounding: RoundingMode::HalfUp,
+            })
+        }
+
+        /// Like [`into_decimal`](Self::into_decimal), but rounds an inexact result according to
+        /// `context.rounding` instead of always rounding half-up.
+        pub(crate) fn into_decimal_with(
+            self,
+            context: DecimalContext,
+        ) -> Result<Decimal, ArithmeticError> {
+            if self.has_terminating_decimal() {
+                return self.into_exact_decimal();
+            }
+            Ok(self.round_to_precision(context.precision, context.rounding))
+        }
+
+        /// Rounds this ratio to exactly `decimal_places` digits after the point (unlike
+        /// [`into_decimal_with`](Self::into_decimal_with), which counts *significant* digits),
+        /// per `rounding`. Rounding always produces a representable coefficient (it clamps instead
+        /// of overflowing -- see [`round_scaled`]), so unlike `into_decimal`/`into_decimal_with`
+        /// this can't fail.
+        pub(crate) fn round_dp(self, decimal_places: u32, rounding: RoundingMode) -> Decimal {
+            let negative = (self.numerator < BigInt::from(0)) != (self.denominator < BigInt::from(0));
+            let numerator = self.numerator.abs();
+            let denominator = self.denominator.abs();
+            round_scaled(negative, numerator, denominator, decimal_places, rounding)
+        }
+
+        /// Scales `numerator`/`denominator` up to a power of ten denominator, which is always
+        /// possible here since [`has_terminating_decimal`](Self::has_terminating_decimal) has
+        /// already confirmed the denominator's only prime factors are 2 and 5.
+        fn into_exact_decimal(self) -> Result<Decimal, ArithmeticError> {
+            let (mut remainder, mut twos, mut fives) = (self.denominator.clone(), 0u32, 0u32);
+            while &remainder % BigInt::from(2) == BigInt::from(0) {
+                remainder = remainder / BigInt::from(2);
+                twos += 1;
+            }
+            while &remainder % BigInt::from(5) == BigInt::from(0) {
+                remainder = remainder / BigInt::from(5);
+                fives += 1;
+            }
+            let scale = twos.max(fives);
+            let multiplier =
+                BigInt::from(2).pow((scale - twos) as u64) * BigInt::from(5).pow((scale - fives) as u64);
+            let coefficient = (self.numerator * multiplier)
+                .to_i128()
+                .ok_or(ArithmeticError::CoefficientOutOfRange)?;
+            Ok(Decimal::new(coefficient, -(scale as i64)))
+        }
+
+        fn round_to_precision(self, precision: u32, rounding: RoundingMode) -> Decimal {
+            let negative = (self.numerator < BigInt::from(0)) != (self.denominator < BigInt::from(0));
+            let numerator = self.numerator.abs();
+            let denominator = self.denominator.abs();
+
+            let integer_digits = {
+                let mut whole = numerator.clone() / denominator.clone();
+                let mut count = 0u32;
+                while whole != BigInt::from(0) {
+                    whole = whole / BigInt::from(10);
+                    count += 1;
+                }
+                count.max(1)
+            };
+            // A value below 1 has no integer digits; the zeros between its point and its first
+            // significant digit don't count toward `precision`.
+            let fraction_digits = if numerator >= denominator {
+                precision.saturating_sub(integer_digits)
+            } else {
+                let mut leading_zeros = 0u32;
+                let mut scaled = numerator.clone() * BigInt::from(10);
+                while scaled != BigInt::from(0) && scaled < denominator {
+                    scaled = scaled * BigInt::from(10);
+                    leading_zeros += 1;
+                }
+                precision + leading_zeros
+            };
+            round_scaled(negative, numerator, denominator, fraction_digits, rounding)
+        }
+    }
+
+    /// Rounds the exact ratio `numerator.abs() / denominator.abs()` (with `negative` carrying the
+    /// sign separately, since both inputs have already been normalized to non-negative) to
+    /// `fraction_digits` digits after the point, per `rounding`. Shared by
+    /// [`ExactRatio::round_to_precision`] (which first works out how many fraction digits are left
+    /// after `precision`'s significant digits) and [`ExactRatio::round_dp`] (which fixes
+    /// `fraction_digits` directly).
+    ///
+    /// Works at one extra guard digit of precision so the rounding decision isn't just a blind
+    /// truncation, and additionally tracks whether the division beyond the guard digit is exact --
+    /// `HalfEven` needs that to tell a genuine tie (e.g. `2.5`) from a value that merely rounds to
+    /// `5` at the guard digit (e.g. `2.500001`), which `HalfEven` rounds up whatever the parity.
+    fn round_scaled(
+        negative: bool,
+        numerator: BigInt,
+        denominator: BigInt,
+        fraction_digits: u32,
+        rounding: RoundingMode,
+    ) -> Decimal {
+        let scaled_numerator = numerator * BigInt::from(10).pow((fraction_digits + 1) as u64);
+        let guard_quotient = scaled_numerator.clone() / denominator.clone();
+        let is_exact = scaled_numerator % denominator == BigInt::from(0);
+
+        let last_digit = (&guard_quotient % BigInt::from(10)).to_i64().unwrap_or(0);
+        let mut quotient = guard_quotient / BigInt::from(10);
+        let quotient_is_odd = (&quotient % BigInt::from(2)).to_i64().unwrap_or(0) != 0;
+
+        let round_up = match rounding {
+            RoundingMode::Down => false,
+            RoundingMode::HalfUp => last_digit >= 5,
+            RoundingMode::HalfEven => match last_digit.cmp(&5) {
+                Ordering::Greater => true,
+                Ordering::Equal => !is_exact || quotient_is_odd,
+                Ordering::Less => false,
+            },
+            RoundingMode::Ceiling => !negative && (last_digit > 0 || !is_exact),
+            RoundingMode::Floor => negative && (last_digit > 0 || !is_exact),
+        };
+        if round_up {
+            quotient = quotient + BigInt::from(1);
+        }
+
+        let coefficient = quotient.to_i128().unwrap_or(i128::MAX);
+        let coefficient = if negative { -coefficient } else { coefficient };
+        Decimal::new(coefficient, -(fraction_digits as i64))
+    }
+
+    /// The Euclidean algorithm; used to reduce [`ExactRatio`] to lowest terms.
+    fn gcd(a: BigInt, b: BigInt) -> BigInt {
+        let (mut a, mut b) = (a, b);
+        while b != BigInt::from(0) {
+            let remainder = &a % &b;
+            a = b;
+            b = remainder;
+        }
+        a.abs()
+    }
+
+    /// Timestamp arithmetic for `+`/`-`. An Ion timestamp carries a local offset (or no offset at
+    /// all, for values like `2023-01-01T`) and a precision no finer than the fields actually
+    /// written (year, day, second, or some number of fractional-second digits) -- neither of
+    /// which `chrono::DateTime` tracks on its own, so both operations below go back through a
+    /// fresh `Timestamp` afterwards rather than handing back a bare `chrono` value.
+    pub(crate) trait TimestampMath: Sized {
+        /// `self - other`, as a `Decimal` count of seconds with sub-second precision preserved.
+        /// Both sides are normalized to an absolute instant first, so it doesn't matter whether
+        /// either timestamp declares an explicit offset.
+        fn diff_seconds(self, other: Self) -> Result<Decimal, String>;
+
+        /// Shifts `self` forward by `seconds` (a negative `seconds` shifts it backward, which is
+        /// how `Timestamp - Int|Decimal` is implemented).
+        ///
+        /// `Timestamp::try_from(DateTime<FixedOffset>)` always produces a timestamp with that
+        /// offset explicitly set and second-or-finer precision -- so a value originally written
+        /// with an unknown offset (e.g. `2023-01-01T00:00-00:00`) comes back with an explicit `+00:00` after a
+        /// shift, and one originally written with only day precision comes back with at least
+        /// second precision. Ion's data model has no notion of "shift this still-unknown offset"
+        /// or "a duration that only moves a timestamp by whole days," so widening to the
+        /// precision `chrono` naturally expresses the result at is the pragmatic choice here.
+        fn shift(self, seconds: Decimal) -> Result<Self, String>;
+    }
+
+    impl TimestampMath for Timestamp {
+        fn diff_seconds(self, other: Self) -> Result<Decimal, String> {
+            let a = to_chrono(&self)?;
+            let b = to_chrono(&other)?;
+            let nanos = (a - b)
+                .num_nanoseconds()
+                .ok_or_else(|| "timestamp difference too large to represent".to_string())?;
+            Ok(Decimal::new(nanos as i128, -9))
+        }
+
+        fn shift(self, seconds: Decimal) -> Result<Self, String> {
+            let base = to_chrono(&self)?;
+            let nanos = seconds_to_nanos(seconds)?;
+            let shifted = base + ChronoDuration::nanoseconds(nanos);
+            Timestamp::try_from(shifted)
+                .map_err(|_| "shifted timestamp is out of range".to_string())
+        }
+    }
+
+    fn to_chrono(t: &Timestamp) -> Result<DateTime<FixedOffset>, String> {
+        DateTime::<FixedOffset>::try_from(t.clone())
+            .map_err(|_| "timestamp cannot be represented as an absolute instant".to_string())
+    }
+
+    /// Converts an arbitrary-precision, possibly-fractional `Decimal` count of seconds into whole
+    /// nanoseconds, rounding half-up on any precision finer than a nanosecond.
+    fn seconds_to_nanos(seconds: Decimal) -> Result<i64, String> {
+        let nanos = seconds.into_big_decimal() * BigDecimal::from(1_000_000_000);
+        nanos
+            .round(0)
+            .to_i64()
+            .ok_or_else(|| "timestamp shift amount is too large to represent".to_string())
+    }
+
+    /// `-decimal`, since [`Decimal`] has no `Neg` impl of its own; used to turn a `shift` forward
+    /// into a `shift` backward for `Timestamp - Int|Decimal`.
+    pub(crate) fn negate_decimal(d: Decimal) -> Decimal {
+        (-d.into_big_decimal()).into_decimal()
+    }
+
     /// A helper trait to allow conversion of various Ion value types into f64. This is inherently a
     /// lossy conversion for most possible expressible Decimal and Integer values even inside f64's
     /// range of expression, so we accept that and move on. The only `None` case for any of these
@@ -752,4 +1789,226 @@ pub(crate) mod ion_math {
             }
         }
     }
+
+    /// Exactly compares any mix of `Int`/`Decimal`/`Float`, unlike [`ToFloat`] (which is lossy by
+    /// design -- see its doc comment) or `JaqElement`'s `Ord`/`PartialOrd` impls (which delegate to
+    /// `IonData`'s own cross-type Ion ordering rather than a numeric one). `Int`/`Decimal` operands
+    /// promote to `BigDecimal` losslessly; a finite `Float` promotes via its exact binary value
+    /// (`BigDecimal::try_from(f64)`, not a `to_string` round trip, which could lose or fabricate
+    /// digits). Returns `None` if either `Value` isn't a number.
+    ///
+    /// `NaN` has no defined relationship to any other number, so by convention it sorts as the
+    /// greatest value (and equal to itself, so sorting stays a well-defined total order); `+Inf`/
+    /// `-Inf` compare as greater/less than every finite number, per IEEE 754.
+    pub(crate) fn total_cmp(a: &Value, b: &Value) -> Option<Ordering> {
+        #[derive(PartialEq, Eq, PartialOrd, Ord)]
+        enum Magnitude {
+            NegInf,
+            Finite(BigInt, i64), // (coefficient, scale) as `into_bigint_and_exponent` returns them
+            PosInf,
+            NaN,
+        }
+
+        fn classify(v: &Value) -> Option<Magnitude> {
+            match v {
+                Value::Int(i) => {
+                    let bd = i.clone().into_big_decimal();
+                    let (coeff, scale) = bd.into_bigint_and_exponent();
+                    Some(Magnitude::Finite(coeff, scale))
+                }
+                Value::Decimal(d) => {
+                    let bd = d.clone().into_big_decimal();
+                    let (coeff, scale) = bd.into_bigint_and_exponent();
+                    Some(Magnitude::Finite(coeff, scale))
+                }
+                Value::Float(f) if f.is_nan() => Some(Magnitude::NaN),
+                Value::Float(f) if *f == f64::INFINITY => Some(Magnitude::PosInf),
+                Value::Float(f) if *f == f64::NEG_INFINITY => Some(Magnitude::NegInf),
+                Value::Float(f) => {
+                    let bd = BigDecimal::try_from(*f).ok()?;
+                    let (coeff, scale) = bd.into_bigint_and_exponent();
+                    Some(Magnitude::Finite(coeff, scale))
+                }
+                _ => None,
+            }
+        }
+
+        // `Magnitude::Finite`'s derived `Ord` compares `(coefficient, scale)` lexicographically,
+        // which isn't the same as comparing the numeric values it represents (e.g. `1` at scale 0
+        // vs `10` at scale 1 are equal numbers but unequal pairs) -- normalize both operands to a
+        // shared scale first so the derived comparison is exact.
+        match (classify(a)?, classify(b)?) {
+            (Magnitude::Finite(ca, sa), Magnitude::Finite(cb, sb)) => {
+                let scale = sa.max(sb);
+                let scaled_a = ca * BigInt::from(10).pow((scale - sa) as u64);
+                let scaled_b = cb * BigInt::from(10).pow((scale - sb) as u64);
+                Some(scaled_a.cmp(&scaled_b))
+            }
+            (ma, mb) => Some(ma.cmp(&mb)),
+        }
+    }
+
+    /// Strips insignificant trailing zeros from `d`'s coefficient, reducing its scale toward (but
+    /// not past) zero -- e.g. `0.120` (coefficient 120, scale 3) becomes `0.12` (coefficient 12,
+    /// scale 2), while `120` (coefficient 120, scale 0) is left alone. Ion's `Decimal` preserves
+    /// the scale a value was written (or computed) with, so `0.12` and `0.120` compare and hash
+    /// unequal by design -- trailing zeros are significant-digit data, not noise. This is for the
+    /// opposite, opt-in case: a caller that wants the *numeric* value rather than the exact digits,
+    /// e.g. deduplicating computed results. See [`NumericKey`] for a matching equality/hash.
+    pub(crate) fn normalize(d: Decimal) -> Decimal {
+        let (mut coefficient, mut scale) = d.into_big_decimal().into_bigint_and_exponent();
+        while scale > 0 && &coefficient % BigInt::from(10) == BigInt::from(0) {
+            coefficient /= BigInt::from(10);
+            scale -= 1;
+        }
+        Decimal::new(coefficient, -scale)
+    }
+
+    /// An opt-in numeric-equality/hash key for `Decimal`: two decimals that represent the same
+    /// number (e.g. `0.12` and `0.120`) produce equal keys and equal hashes, unlike `Decimal`'s own
+    /// `Eq`/`Hash`, which treat them as distinct (per Ion's significant-digit semantics). Built
+    /// from [`normalize`]'s reduced coefficient/scale, so the comparison stays exact rather than
+    /// going through a lossy float.
+    #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    pub(crate) struct NumericKey(BigInt, i64);
+
+    impl NumericKey {
+        pub(crate) fn new(d: Decimal) -> Self {
+            let (coefficient, scale) = normalize(d).into_big_decimal().into_bigint_and_exponent();
+            NumericKey(coefficient, scale)
+        }
+    }
+
+    /// Convenience wrapper around [`NumericKey`] for comparing two decimals directly, without the
+    /// caller having to build a key for each side.
+    pub(crate) fn numeric_eq(a: Decimal, b: Decimal) -> bool {
+        NumericKey::new(a) == NumericKey::new(b)
+    }
+}
+
+/// Ion-aware `sort`/`unique`/`group_by` builtins, and the `sort_by`/`unique_by` key comparison
+/// they share. `jaq_core`'s `ValT` doesn't require a total order, so `jaq_std` has no generic
+/// implementation of these -- each embedder supplies its own, and `JaqElement` already has one
+/// (`Ord`, driven by `IonData`; see its `cmp` above), matching Ion's own notion of canonical
+/// equivalence rather than e.g. textual comparison. Registered into the compiler via `with_funs`
+/// in `compile_jq_filter`.
+pub(crate) mod ion_funs {
+    use super::JaqElement;
+    use ion_rs::{Element, List, Value};
+    use jaq_core::box_iter::box_once;
+    use jaq_core::{Ctx, Native, RunPtr};
+    use jaq_std::Filter;
+
+    fn sequence_items(v: JaqElement) -> Result<Vec<Element>, jaq_core::Error<JaqElement>> {
+        match v.into_value() {
+            Value::List(seq) | Value::SExp(seq) => Ok(seq.into_iter().collect()),
+            other => Err(jaq_core::Error::new(JaqElement::from(Element::from(other)))),
+        }
+    }
+
+    /// Runs a native function's single filter argument against `v`, keeping only its first
+    /// output -- `sort_by`/`unique_by`/`group_by`'s key filter is only ever used to compute one
+    /// key per element, so any further outputs are ignored. A filter with no output uses the
+    /// element itself as its key.
+    fn key_of(
+        arg: &jaq_core::Filter<Native<JaqElement>>,
+        ctx: &Ctx<JaqElement>,
+        v: &JaqElement,
+    ) -> Result<JaqElement, jaq_core::Error<JaqElement>> {
+        arg.run((ctx.clone(), v.clone()))
+            .next()
+            .unwrap_or_else(|| Ok(v.clone()))
+    }
+
+    fn sorted_by_key(mut keyed: Vec<(JaqElement, Element)>) -> Vec<Element> {
+        keyed.sort_by(|(a, _), (b, _)| a.cmp(b));
+        keyed.into_iter().map(|(_, v)| v).collect()
+    }
+
+    fn keyed_items(
+        args: &[jaq_core::Filter<Native<JaqElement>>],
+        ctx: &Ctx<JaqElement>,
+        items: Vec<Element>,
+    ) -> Result<Vec<(JaqElement, Element)>, jaq_core::Error<JaqElement>> {
+        let mut keyed = Vec::with_capacity(items.len());
+        for item in items {
+            let key = key_of(&args[0], ctx, &JaqElement::from(item.clone()))?;
+            keyed.push((key, item));
+        }
+        Ok(keyed)
+    }
+
+    pub(crate) fn funs() -> impl Iterator<Item = Filter<Native<JaqElement>>> {
+        let sort: RunPtr<JaqElement> = |_, (_, v)| {
+            let result = sequence_items(v).map(|mut items| {
+                items.sort_by(|a, b| JaqElement::from(a.clone()).cmp(&JaqElement::from(b.clone())));
+                JaqElement::from(List::from_iter(items))
+            });
+            box_once(result)
+        };
+
+        let unique: RunPtr<JaqElement> = |_, (_, v)| {
+            let result = sequence_items(v).map(|mut items| {
+                items.sort_by(|a, b| JaqElement::from(a.clone()).cmp(&JaqElement::from(b.clone())));
+                items.dedup_by(|a, b| JaqElement::from(a.clone()) == JaqElement::from(b.clone()));
+                JaqElement::from(List::from_iter(items))
+            });
+            box_once(result)
+        };
+
+        let sort_by: RunPtr<JaqElement> = |args, (ctx, v)| {
+            let result = sequence_items(v).and_then(|items| {
+                let keyed = keyed_items(args, &ctx, items)?;
+                Ok(JaqElement::from(List::from_iter(sorted_by_key(keyed))))
+            });
+            box_once(result)
+        };
+
+        let unique_by: RunPtr<JaqElement> = |args, (ctx, v)| {
+            let result = sequence_items(v).and_then(|items| {
+                let mut keyed = keyed_items(args, &ctx, items)?;
+                keyed.sort_by(|(a, _), (b, _)| a.cmp(b));
+                keyed.dedup_by(|(a, _), (b, _)| a == b);
+                let items = keyed.into_iter().map(|(_, v)| v).collect();
+                Ok(JaqElement::from(List::from_iter(items)))
+            });
+            box_once(result)
+        };
+
+        let group_by: RunPtr<JaqElement> = |args, (ctx, v)| {
+            let result = sequence_items(v).and_then(|items| {
+                let mut keyed = keyed_items(args, &ctx, items)?;
+                keyed.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+                let mut groups: Vec<List> = Vec::new();
+                let mut current_key: Option<JaqElement> = None;
+                let mut current_group: Vec<Element> = Vec::new();
+                for (key, item) in keyed {
+                    if current_key.as_ref() != Some(&key) {
+                        if !current_group.is_empty() {
+                            groups.push(List::from_iter(std::mem::take(&mut current_group)));
+                        }
+                        current_key = Some(key);
+                    }
+                    current_group.push(item);
+                }
+                if !current_group.is_empty() {
+                    groups.push(List::from_iter(current_group));
+                }
+
+                let elements = groups.into_iter().map(Element::from);
+                Ok(JaqElement::from(List::from_iter(elements)))
+            });
+            box_once(result)
+        };
+
+        [
+            ("sort", Box::new([]), Native::new(sort)),
+            ("unique", Box::new([]), Native::new(unique)),
+            ("sort_by", Box::new(["f"]), Native::new(sort_by)),
+            ("unique_by", Box::new(["f"]), Native::new(unique_by)),
+            ("group_by", Box::new(["f"]), Native::new(group_by)),
+        ]
+        .into_iter()
+    }
 }
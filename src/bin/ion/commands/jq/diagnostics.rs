@@ -0,0 +1,82 @@
+use std::fmt::{Display, Formatter};
+use std::ops::Range;
+
+/// How serious a [`Diagnostic`] is: an `Error` means the filter could not be run to completion, a
+/// `Warning` flags something recoverable (e.g. a lossy coercion) that didn't stop evaluation.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+impl Display for Severity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        })
+    }
+}
+
+/// A single diagnostic produced while compiling or running a `jq` filter, renderable against the
+/// original filter program the way a compiler points at source.
+///
+/// `span` is the byte range in the program string that the diagnostic is about, if one is known.
+/// Not every failure carries one: `jaq_core`'s runtime errors are plain values with no source
+/// location attached, and `compile_jq_filter` renders parse/compile errors from their message
+/// alone, so both kinds of failure are rendered as a severity + message, with no caret.
+pub struct Diagnostic {
+    severity: Severity,
+    message: String,
+    span: Option<Range<usize>>,
+}
+
+impl Diagnostic {
+    pub fn error(message: impl Into<String>) -> Self {
+        Diagnostic {
+            severity: Severity::Error,
+            message: message.into(),
+            span: None,
+        }
+    }
+
+    pub fn warning(message: impl Into<String>) -> Self {
+        Diagnostic {
+            severity: Severity::Warning,
+            message: message.into(),
+            span: None,
+        }
+    }
+
+    pub fn with_span(mut self, span: Range<usize>) -> Self {
+        self.span = Some(span);
+        self
+    }
+
+    /// Renders this diagnostic against `program`, the original filter text. When a span is
+    /// present, the offending line is printed with a `^^^` underline beneath the relevant columns;
+    /// otherwise only the severity and message are shown.
+    pub fn render(&self, program: &str) -> String {
+        let Some(span) = &self.span else {
+            return format!("{}: {}", self.severity, self.message);
+        };
+
+        let start = span.start.min(program.len());
+        let end = span.end.clamp(start, program.len());
+
+        let line_start = program[..start].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = program[end..]
+            .find('\n')
+            .map_or(program.len(), |i| end + i);
+        let line = &program[line_start..line_end];
+
+        let column = start - line_start;
+        let underline_len = (end - start).max(1);
+        let underline = format!("{}{}", " ".repeat(column), "^".repeat(underline_len));
+
+        format!(
+            "{}: {}\n  {line}\n  {underline}",
+            self.severity, self.message
+        )
+    }
+}
@@ -1,14 +1,33 @@
 use crate::commands::jq::JaqElement;
-use ion_rs::{Element, IonType};
+use chrono::{DateTime, Datelike, FixedOffset};
+use ion_rs::{Element, IonType, Value};
 use jaq_core::{box_iter::box_once, Native, RunPtr, ValT};
 use jaq_std::Filter;
 
-/// Helper to create error for invalid input type
+/// Helper to create an error for a filter given an input of the wrong Ion type.
 fn input_error(expected: &str) -> jaq_core::Exn<'_, JaqElement> {
-    jaq_core::Error::str(format!("{} filter requires a string input", expected)).into()
+    input_error_kind(expected, "string")
 }
 
-/// Ion-specific jq function definitions (filters implemented as definitions)
+/// Like [`input_error`], but for filters that require something other than a string input (e.g.
+/// `year`/`offset` require a timestamp, `blob`/`clob` require their own type).
+fn input_error_kind<'a>(expected: &'a str, kind: &'a str) -> jaq_core::Exn<'a, JaqElement> {
+    jaq_core::Error::str(format!("{expected} filter requires a {kind} input")).into()
+}
+
+/// Converts a timestamp to an absolute instant for field accessors (`year`/`offset`) that need
+/// to borrow `chrono`'s calendar math. Widens the same way `ion_math::TimestampMath::shift` does
+/// -- see that trait's doc comment -- so `offset` on a timestamp with no declared offset reports
+/// whatever offset `chrono` resolves it to rather than `null`.
+fn to_chrono(t: &ion_rs::Timestamp) -> Result<DateTime<FixedOffset>, jaq_core::Exn<'_, JaqElement>> {
+    DateTime::<FixedOffset>::try_from(t.clone()).map_err(|_| {
+        jaq_core::Error::str("timestamp cannot be represented as an absolute instant").into()
+    })
+}
+
+/// Ion-specific jq function definitions (filters implemented as definitions in terms of the
+/// native functions below, the same way `jaq_std::defs` layers convenience defs over `jaq_std`'s
+/// natives).
 pub fn ion_defs() -> impl Iterator<Item = jaq_core::load::parse::Def<&'static str>> {
     const ION_DEFS: &str = r#"
 # Ion type predicates
@@ -27,6 +46,15 @@ def decimals: select(isdecimal);
 def to_symbol: if type == "string" then symbol else error("to_symbol requires string input") end;
 def to_sexp: if type == "array" then sexp else error("to_sexp requires array input") end;
 def to_timestamp: if type == "string" then timestamp else error("to_timestamp requires string input") end;
+
+# Unlike `to_symbol`, `as_symbol` never errors -- it stringifies its input first, so it can be
+# used to symbol-ize the output of an arbitrary pipeline rather than only a literal string.
+def as_symbol: if issymbol then . else tostring | symbol end;
+
+# `isannotated` mirrors the other `is*` predicates above; `annotations`/`with_annotations`/
+# `strip_annotations` are native (see `ion_funs`) since they need direct access to the element's
+# annotation list rather than anything expressible in terms of `ion_type`.
+def isannotated: (annotations | length) > 0;
 "#;
 
     jaq_core::load::parse(ION_DEFS, |p| p.defs())
@@ -34,11 +62,32 @@ def to_timestamp: if type == "string" then timestamp else error("to_timestamp re
         .into_iter()
 }
 
-/// Ion-specific native jq functions
+/// Ion-specific native jq functions. Registered alongside `jaq_std::funs` and `ion_funs::funs` in
+/// `compile_jq_filter`.
 pub fn ion_funs() -> impl Iterator<Item = Filter<Native<JaqElement>>> {
-    [timestamp_fn(), sexp_fn(), symbol_fn(), ion_type_fn()].into_iter()
+    [
+        timestamp_fn(),
+        sexp_fn(),
+        symbol_fn(),
+        ion_type_fn(),
+        annotations_fn(),
+        with_annotations_fn(),
+        strip_annotations_fn(),
+        year_fn(),
+        offset_fn(),
+        blob_fn(),
+        clob_fn(),
+    ]
+    .into_iter()
 }
 
+// TODO: `decimal`, `int`, and `float` constructors, mirroring `timestamp_fn`: parse a string
+// input with `Element::read_one` and reject the result unless its `ion_type()` matches. Without
+// them, a pipeline can't produce an arbitrary-precision `decimal` (or a typed `int`/`float`) from
+// a jq string without round-tripping through jq's native f64 number type first, which silently
+// loses precision (e.g. `"1.00000000000000001"` would need to parse straight to `decimal` to
+// avoid that).
+
 /// Creates a timestamp from a string
 fn timestamp_fn() -> Filter<Native<JaqElement>> {
     let run: RunPtr<JaqElement> = |_, (_, v)| match v.as_str() {
@@ -54,7 +103,7 @@ fn timestamp_fn() -> Filter<Native<JaqElement>> {
     ("timestamp", Box::new([]), Native::new(run))
 }
 
-/// Creates an S-expression from an array  
+/// Creates an S-expression from an array
 fn sexp_fn() -> Filter<Native<JaqElement>> {
     let run: RunPtr<JaqElement> = |_, (_, v)| match v.values().collect::<Result<Vec<_>, _>>() {
         Ok(items) => {
@@ -88,3 +137,103 @@ fn ion_type_fn() -> Filter<Native<JaqElement>> {
 
     ("ion_type", Box::new([]), Native::new(run))
 }
+
+/// Returns the current value's annotations as an array of strings. An annotation without known
+/// text (an unresolved symbol ID) collapses to `"?"` -- the same gap `diff`'s struct-field-name
+/// handling notes for the analogous case.
+fn annotations_fn() -> Filter<Native<JaqElement>> {
+    let run: RunPtr<JaqElement> = |_, (_, v)| {
+        let names: Vec<Element> = v
+            .annotations()
+            .map(|a| Element::from(a.text().unwrap_or("?").to_owned()))
+            .collect();
+        box_once(Ok(JaqElement::from(ion_rs::List::from_iter(names))))
+    };
+
+    ("annotations", Box::new([]), Native::new(run))
+}
+
+/// Replaces the current value's annotations with the strings produced by `f`, e.g.
+/// `with_annotations(["foo", "bar"])`.
+fn with_annotations_fn() -> Filter<Native<JaqElement>> {
+    let run: RunPtr<JaqElement> = |args, (ctx, v)| {
+        let result = (|| {
+            let names = args[0]
+                .run((ctx, v.clone()))
+                .next()
+                .unwrap_or_else(|| Ok(v.clone()))?;
+            let texts: Vec<String> = names
+                .values()
+                .map(|item| item.map(|je| je.as_str().unwrap_or("?").to_owned()))
+                .collect::<Result<_, _>>()?;
+            Ok(JaqElement::from(v.into_inner().with_annotations(texts)))
+        })();
+        box_once(result.map_err(Into::into))
+    };
+
+    ("with_annotations", Box::new(["f"]), Native::new(run))
+}
+
+/// Removes all annotations from the current value.
+fn strip_annotations_fn() -> Filter<Native<JaqElement>> {
+    let run: RunPtr<JaqElement> = |_, (_, v)| {
+        let stripped = v.into_inner().with_annotations(Vec::<String>::new());
+        box_once(Ok(JaqElement::from(stripped)))
+    };
+
+    ("strip_annotations", Box::new([]), Native::new(run))
+}
+
+/// Returns a timestamp's year as an integer.
+fn year_fn() -> Filter<Native<JaqElement>> {
+    let run: RunPtr<JaqElement> = |_, (_, v)| match v.value() {
+        Value::Timestamp(t) => match to_chrono(t) {
+            Ok(dt) => box_once(Ok(JaqElement::from(dt.year() as i64))),
+            Err(e) => box_once(Err(e)),
+        },
+        _ => box_once(Err(input_error_kind("year", "timestamp"))),
+    };
+
+    ("year", Box::new([]), Native::new(run))
+}
+
+/// Returns a timestamp's UTC offset in minutes (negative for timezones west of UTC).
+fn offset_fn() -> Filter<Native<JaqElement>> {
+    let run: RunPtr<JaqElement> = |_, (_, v)| match v.value() {
+        Value::Timestamp(t) => match to_chrono(t) {
+            Ok(dt) => box_once(Ok(JaqElement::from(
+                (dt.offset().local_minus_utc() / 60) as i64,
+            ))),
+            Err(e) => box_once(Err(e)),
+        },
+        _ => box_once(Err(input_error_kind("offset", "timestamp"))),
+    };
+
+    ("offset", Box::new([]), Native::new(run))
+}
+
+/// Returns a blob's byte contents as an array of integers.
+fn blob_fn() -> Filter<Native<JaqElement>> {
+    let run: RunPtr<JaqElement> = |_, (_, v)| match v.value() {
+        Value::Blob(bytes) => {
+            let items: Vec<Element> = bytes.as_slice().iter().map(|b| Element::from(*b as i64)).collect();
+            box_once(Ok(JaqElement::from(ion_rs::List::from_iter(items))))
+        }
+        _ => box_once(Err(input_error_kind("blob", "blob"))),
+    };
+
+    ("blob", Box::new([]), Native::new(run))
+}
+
+/// Returns a clob's byte contents as an array of integers.
+fn clob_fn() -> Filter<Native<JaqElement>> {
+    let run: RunPtr<JaqElement> = |_, (_, v)| match v.value() {
+        Value::Clob(bytes) => {
+            let items: Vec<Element> = bytes.as_slice().iter().map(|b| Element::from(*b as i64)).collect();
+            box_once(Ok(JaqElement::from(ion_rs::List::from_iter(items))))
+        }
+        _ => box_once(Err(input_error_kind("clob", "clob"))),
+    };
+
+    ("clob", Box::new([]), Native::new(run))
+}
@@ -0,0 +1,70 @@
+use crate::commands::IonCliCommand;
+use crate::RootCommand;
+use anyhow::{Context, Result};
+use clap::{Arg, ArgMatches, Command};
+use clap_mangen::Man;
+use std::fs::File;
+use std::io;
+use std::path::Path;
+
+/// Generates roff man pages by walking the full `ion` command/namespace tree, producing one page
+/// per leaf command and namespace (e.g. `ion-schema-validate.1`, `ion-schema.1`).
+pub struct ManpagesCommand;
+
+impl IonCliCommand for ManpagesCommand {
+    fn name(&self) -> &'static str {
+        "manpages"
+    }
+
+    fn about(&self) -> &'static str {
+        "Generates roff man pages for every command and namespace in the ion-cli command tree."
+    }
+
+    fn is_porcelain(&self) -> bool {
+        true
+    }
+
+    fn configure_args(&self, command: Command) -> Command {
+        command.arg(
+            Arg::new("output-dir").long("output-dir").help(
+                "Directory to write one man page per command into \
+                 [default: STDOUT, root command's page only]",
+            ),
+        )
+    }
+
+    fn run(&self, _command_path: &mut Vec<String>, args: &ArgMatches) -> Result<()> {
+        let root = RootCommand.clap_command();
+
+        match args.get_one::<String>("output-dir") {
+            Some(dir) => {
+                std::fs::create_dir_all(dir)
+                    .with_context(|| format!("could not create output directory '{dir}'"))?;
+                write_man_pages(&root, "", Path::new(dir))
+            }
+            None => {
+                Man::new(root).render(&mut io::stdout())?;
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Recursively renders one man page per leaf command and namespace in `command`'s subtree, named
+/// `<command path joined by '-'>.1`, e.g. `ion-schema-validate.1`.
+fn write_man_pages(command: &Command, path_prefix: &str, output_dir: &Path) -> Result<()> {
+    let page_name = if path_prefix.is_empty() {
+        command.get_name().to_string()
+    } else {
+        format!("{path_prefix}-{}", command.get_name())
+    };
+
+    let mut file = File::create(output_dir.join(format!("{page_name}.1")))
+        .with_context(|| format!("could not create man page for '{page_name}'"))?;
+    Man::new(command.clone()).render(&mut file)?;
+
+    for subcommand in command.get_subcommands() {
+        write_man_pages(subcommand, &page_name, output_dir)?;
+    }
+    Ok(())
+}
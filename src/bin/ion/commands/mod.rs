@@ -1,34 +1,53 @@
+use crate::auto_decompress::Preprocessors;
 use crate::file_writer::FileWriter;
 use crate::input::CommandInput;
 use crate::output::CommandOutput;
+use anyhow::anyhow;
 use anyhow::Context;
 use anyhow::Result;
-use clap::builder::ValueParser;
 use clap::{crate_authors, crate_version, Arg, ArgAction, ArgMatches, Command as ClapCommand};
 use std::fs::File;
-use std::io::Write;
+use std::io::{IsTerminal, Read, Write};
 use std::process;
 use termcolor::{ColorChoice, StandardStream, StandardStreamLock};
 
 pub mod cat;
+pub mod completions;
 pub mod count;
+pub mod diff;
 pub mod from;
 #[cfg(feature = "experimental-code-gen")]
 pub mod generate;
 pub mod head;
 pub mod inspect;
+pub mod manpages;
 pub mod primitive;
+pub(crate) mod promotion;
+pub mod query;
 pub mod schema;
+pub(crate) mod structural_recursion;
 pub mod symtab;
 pub mod to;
+pub(crate) mod typed_json;
+pub mod version;
 
 /// Behaviors common to all Ion CLI commands, including both namespaces (groups of commands)
 /// and the commands themselves.
 pub trait IonCliCommand {
     /// Indicates whether this command is stable (as opposed to unstable or experimental).
     /// Namespaces should almost always be stable.
+    ///
+    /// Defaults to `true` exactly when [`Self::unstable_features`] is empty, so commands that
+    /// gate themselves behind a named feature don't also need to override this.
     fn is_stable(&self) -> bool {
-        true
+        self.unstable_features().is_empty()
+    }
+
+    /// Named unstable features this command requires explicit `-Z <name>` opt-in for, modeled on
+    /// rustc's `-Z` unstable options. Defaults to none (stable). Declaring more than one lets a
+    /// command stage out several experimental capabilities independently.
+    fn unstable_features(&self) -> &'static [&'static str] {
+        &[]
     }
 
     /// Whether the output format is machine-readable.
@@ -73,12 +92,11 @@ pub trait IonCliCommand {
             .with_decompression_control()
             .arg(
                 Arg::new(UNSTABLE_FLAG)
-                    .short('X')
+                    .short('Z')
                     .long("unstable")
-                    .default_value("false")
-                    .action(ArgAction::SetTrue)
-                    .value_parser(ValueParser::bool())
-                    .help("Opt in to using an unstable feature of Ion CLI.")
+                    .action(ArgAction::Append)
+                    .value_name("feature")
+                    .help("Opt in to an unstable feature of Ion CLI, by name. May be repeated.")
                     .display_order(usize::MAX)
                     .hide(true),
             );
@@ -88,8 +106,14 @@ pub trait IonCliCommand {
             if about.is_some() {
                 base_command = base_command.about(format!("(UNSTABLE) {}", about.unwrap()))
             }
+            let recognized = self.unstable_features().join(", ");
             base_command = base_command
-                .before_help("WARNING: This command is unstable and requires explicit opt-in using '--unstable' or '-X'.");
+                .before_help(format!("WARNING: This command is unstable and requires explicit opt-in using '-Z <feature>'. Recognized features: {recognized}."))
+                .mut_arg(UNSTABLE_FLAG, move |arg| {
+                    arg.help(format!(
+                        "Opt in to an unstable feature of Ion CLI, by name. May be repeated. This command recognizes: {recognized}"
+                    ))
+                });
         }
         if self.is_porcelain() {
             base_command = base_command.after_help(
@@ -151,31 +175,54 @@ pub trait IonCliCommand {
         let (subcommand_name, subcommand_args) = args.subcommand().unwrap();
         let subcommand = self.get_subcommand(subcommand_name).unwrap();
 
-        match (subcommand.is_stable(), args.get_flag(UNSTABLE_FLAG)) {
-            // Warn if using an unnecessary `-X`
-            (true, true) => eprintln!(
-                "'{}' is stable and does not require opt-in",
-                subcommand_name
-            ),
-            // Error if missing a required `-X`
-            (false, false) => {
-                eprintln!(
-                    "'{}' is unstable and requires explicit opt-in",
-                    subcommand_name
-                );
-                process::exit(1)
-            }
-            _ => {}
-        }
+        check_unstable_feature_opt_in(subcommand_name, subcommand.unstable_features(), args);
 
         command_path.push(subcommand_name.to_owned());
         subcommand.run(command_path, subcommand_args)
     }
 }
 
-/// Argument ID for the '--unstable' / '-X' flag
+/// Argument ID for the '--unstable' / '-Z' flag
 const UNSTABLE_FLAG: &str = "unstable";
 
+/// Verifies that every unstable feature `subcommand_name` requires has been explicitly enabled
+/// via `-Z <feature>`, exiting with the exact missing names otherwise. Also warns about any
+/// enabled feature `subcommand_name` doesn't recognize.
+pub(crate) fn check_unstable_feature_opt_in(
+    subcommand_name: &str,
+    required_features: &'static [&'static str],
+    args: &ArgMatches,
+) {
+    let enabled_features: Vec<&str> = args
+        .get_many::<String>(UNSTABLE_FLAG)
+        .map(|values| values.map(String::as_str).collect())
+        .unwrap_or_default();
+
+    let missing_features: Vec<&str> = required_features
+        .iter()
+        .filter(|name| !enabled_features.contains(name))
+        .copied()
+        .collect();
+
+    if !missing_features.is_empty() {
+        eprintln!(
+            "'{}' is unstable and requires explicit opt-in for feature(s): {}",
+            subcommand_name,
+            missing_features.join(", ")
+        );
+        process::exit(1);
+    }
+
+    for feature in enabled_features {
+        if !required_features.contains(&feature) {
+            eprintln!(
+                "warning: '-Z {}' is not a recognized unstable feature of '{}'",
+                feature, subcommand_name
+            );
+        }
+    }
+}
+
 /// Extension methods for a [`ClapCommand`] which add flags and options that are common to
 /// commands in the Ion CLI.
 pub trait WithIonCliArgument {
@@ -183,6 +230,8 @@ pub trait WithIonCliArgument {
     fn with_output(self) -> Self;
     fn with_format(self) -> Self;
     fn with_decompression_control(self) -> Self;
+    fn with_color(self) -> Self;
+    fn with_limit(self) -> Self;
     fn show_unstable_flag(self) -> Self;
 }
 
@@ -204,6 +253,14 @@ impl WithIonCliArgument for ClapCommand {
                 .short('o')
                 .help("Output file [default: STDOUT]"),
         )
+        .arg(
+            Arg::new("compress")
+                .long("compress")
+                .short('z')
+                .value_parser(["gz", "zst", "xz"])
+                .help("Compress the output using the given codec"),
+        )
+        .with_color()
     }
 
     fn with_format(self) -> Self {
@@ -217,6 +274,29 @@ impl WithIonCliArgument for ClapCommand {
         )
     }
 
+    fn with_color(self) -> Self {
+        self.arg(
+            Arg::new("color")
+                .short('c')
+                .long("color")
+                .default_value("auto")
+                .value_parser(["auto", "always", "never"])
+                .help("When to color output written to STDOUT"),
+        )
+    }
+
+    fn with_limit(self) -> Self {
+        self.arg(
+            Arg::new("limit")
+                .long("limit")
+                .value_parser(crate::limit::parse_limit)
+                .help(
+                    "Stop reading input after this many bytes. Accepts a plain number of bytes \
+                     or a magnitude suffix: K/M/G (powers of 1000) or KiB/MiB/GiB (powers of 1024).",
+                ),
+        )
+    }
+
     /// All commands automatically have the "unstable" opt-in flag. This makes it visible.
     fn show_unstable_flag(self) -> Self {
         self.mut_arg(UNSTABLE_FLAG, |arg| arg.hide(false))
@@ -235,6 +315,19 @@ impl WithIonCliArgument for ClapCommand {
                 // Do not show this flag in `help` for commands that don't take an `--input` flag.
                 .hide(!accepts_input),
         )
+        .arg(
+            Arg::new("preprocessor")
+                .long("preprocessor")
+                .action(ArgAction::Append)
+                .value_name("codec>=<program>[,<arg>...]")
+                .help(
+                    "Registers an external command to decompress the given codec, replacing the \
+                     built-in one. An empty program (e.g. '--preprocessor gz=') disables \
+                     auto-decompression for that codec instead.",
+                )
+                // Do not show this flag in `help` for commands that don't take an `--input` flag.
+                .hide(!accepts_input),
+        )
     }
 }
 
@@ -257,41 +350,100 @@ impl<'a> CommandIo<'a> {
         }
     }
 
+    /// Parses the user's `--preprocessor` overrides (if the command opted in via
+    /// [`WithIonCliArgument::with_decompression_control`]) into a [`Preprocessors`].
+    fn preprocessors(&self) -> Result<Preprocessors> {
+        let values = self
+            .args
+            .get_many::<String>("preprocessor")
+            .unwrap_or_default()
+            .map(String::as_str);
+        Preprocessors::parse(values).map_err(|e| anyhow!(e))
+    }
+
+    /// Resolves the `-c/--color` flag (if present) into the `ColorChoice` STDOUT should use.
+    /// `auto` only colors STDOUT when it's a TTY.
+    fn color_choice(&self) -> ColorChoice {
+        match self.args.get_one::<String>("color").map(String::as_str) {
+            Some("always") => ColorChoice::Always,
+            Some("never") => ColorChoice::Never,
+            _ if std::io::stdout().is_terminal() => ColorChoice::Auto,
+            _ => ColorChoice::Never,
+        }
+    }
+
+    /// Resolves the `-c/--color` flag into the `ColorChoice` a file output destination should
+    /// use. Unlike STDOUT, a file has no TTY for `auto` to detect, so only an explicit
+    /// `--color always` causes a file's output to carry real ANSI escape sequences; everything
+    /// else (including `auto`) leaves file output free of escape codes, as before.
+    fn file_color_choice(&self) -> ColorChoice {
+        match self.args.get_one::<String>("color").map(String::as_str) {
+            Some("always") => ColorChoice::Always,
+            _ => ColorChoice::Never,
+        }
+    }
+
+    /// Returns the byte limit requested via `--limit` (if the command opted in with
+    /// [`WithIonCliArgument::with_limit`] and the user supplied one).
+    fn byte_limit(&self) -> Option<u64> {
+        self.args.get_one::<u64>("limit").copied()
+    }
+
     /// Constructs a new [`CommandInput`] representing STDIN.
     fn command_input_for_stdin(&self) -> Result<CommandInput> {
         const STDIN_NAME: &str = "-";
         let stdin = std::io::stdin().lock();
-        if self.auto_decompression_enabled() {
-            CommandInput::decompress(STDIN_NAME, stdin)
-        } else {
-            CommandInput::without_decompression(STDIN_NAME, stdin)
+        match self.byte_limit() {
+            Some(limit) => self.new_command_input(STDIN_NAME, stdin.take(limit)),
+            None => self.new_command_input(STDIN_NAME, stdin),
         }
     }
 
     /// Constructs a new [`CommandInput`] representing the specified file.
     fn command_input_for_file_name(&self, name: &str) -> Result<CommandInput> {
         let stream = File::open(name)?;
+        match self.byte_limit() {
+            Some(limit) => self.new_command_input(name, stream.take(limit)),
+            None => self.new_command_input(name, stream),
+        }
+    }
+
+    /// Builds a [`CommandInput`] from `stream`, honoring the `--no-auto-decompress` flag and any
+    /// `--preprocessor` overrides.
+    fn new_command_input(
+        &self,
+        name: &str,
+        stream: impl Read + Send + 'static,
+    ) -> Result<CommandInput> {
         if self.auto_decompression_enabled() {
-            CommandInput::decompress(name, stream)
+            CommandInput::decompress(name, stream, &self.preprocessors()?)
         } else {
             CommandInput::without_decompression(name, stream)
         }
     }
 
-    /// Calls the provided closure once for each input source specified by the user.
-    /// For each invocation, provides a handle to the configured output stream.
-    fn for_each_input(
-        &mut self,
-        mut f: impl FnMut(&mut CommandOutput, CommandInput) -> Result<()>,
-    ) -> Result<()> {
-        // These types are provided by the `termcolor` crate. They wrap the normal `io::Stdout` and
-        // `io::StdOutLock` types, making it possible to write colorful text to the output stream when
-        // it's a TTY that understands formatting escape codes. These variables are declared here so
-        // the lifetime will extend through the remainder of the function. Unlike `io::StdoutLock`,
-        // the `StandardStreamLock` does not have a static lifetime.
-        let stdout: StandardStream;
-        let stdout_lock: StandardStreamLock;
-        let mut output = if let Some(output_file) = self.args.get_one::<String>("output") {
+    /// Constructs the output stream configured via `--output`/`--color`: either a [`FileWriter`]
+    /// over the named `--output` file, or STDOUT, honoring the user's `--color` preference (and
+    /// never emitting escape codes when STDOUT isn't an interactive terminal and `--color auto`
+    /// is in effect).
+    //
+    // The caller owns an `Option<StandardStream>` slot and passes it in by mutable reference so
+    // its lifetime extends through the remainder of the caller's function body; `StandardStream`
+    // is provided by the `termcolor` crate and wraps the normal `io::Stdout` type, making it
+    // possible to write colorful text to the output stream when it's a TTY that understands
+    // formatting escape codes. Unlike `io::StdoutLock`, the `StandardStreamLock` this produces
+    // does not have a static lifetime, hence the need to keep the `StandardStream` it borrows
+    // from alive in the caller.
+    fn build_output<'b>(
+        &self,
+        stdout: &'b mut Option<StandardStream>,
+    ) -> Result<CommandOutput<'b>> {
+        let compression = self.args.get_one::<String>("compress").map(|value| {
+            crate::output_compression::OutputCompression::from_flag_value(value)
+                .expect("enforced by clap's value_parser")
+        });
+
+        if let Some(output_file) = self.args.get_one::<String>("output") {
             // If the user has specified an output file, use it.
             let file = File::create(output_file).with_context(|| {
                 format!(
@@ -299,13 +451,44 @@ impl<'a> CommandIo<'a> {
                     output_file
                 )
             })?;
-            CommandOutput::File(FileWriter::new(file))
+            if let Some(codec) = compression {
+                let compressed = crate::output_compression::compress(codec, file)?;
+                Ok(CommandOutput::Compressed(compressed))
+            } else {
+                Ok(CommandOutput::File(FileWriter::with_color_choice(
+                    file,
+                    self.file_color_choice(),
+                )))
+            }
+        } else if let Some(codec) = compression {
+            let compressed = crate::output_compression::compress(codec, std::io::stdout())?;
+            Ok(CommandOutput::Compressed(compressed))
         } else {
-            // Otherwise, write to STDOUT.
-            stdout = StandardStream::stdout(ColorChoice::Always);
-            stdout_lock = stdout.lock();
-            CommandOutput::StdOut(stdout_lock)
-        };
+            *stdout = Some(StandardStream::stdout(self.color_choice()));
+            Ok(CommandOutput::StdOut(stdout.as_ref().unwrap().lock()))
+        }
+    }
+
+    /// Calls the provided closure once for each input source specified by the user.
+    /// For each invocation, provides a handle to the configured output stream.
+    fn for_each_input(
+        &mut self,
+        f: impl FnMut(&mut CommandOutput, CommandInput) -> Result<()>,
+    ) -> Result<()> {
+        self.for_each_input_then(f, |_output| Ok(()))
+    }
+
+    /// Like [`Self::for_each_input`], but also calls `finish` with a handle to the output stream
+    /// after every input has been processed, before the output is flushed. Useful for output
+    /// modes (e.g. `validate --junit`) that buffer results across all inputs and serialize a
+    /// single aggregate report at the end, rather than writing incrementally.
+    fn for_each_input_then(
+        &mut self,
+        mut f: impl FnMut(&mut CommandOutput, CommandInput) -> Result<()>,
+        finish: impl FnOnce(&mut CommandOutput) -> Result<()>,
+    ) -> Result<()> {
+        let mut stdout = None;
+        let mut output = self.build_output(&mut stdout)?;
         if let Some(input_file_names) = self.args.get_many::<String>("input") {
             // Input files were specified, run the converter on each of them in turn
             for input_file_name in input_file_names {
@@ -316,7 +499,21 @@ impl<'a> CommandIo<'a> {
             let input = self.command_input_for_stdin()?;
             f(&mut output, input)?;
         }
+        finish(&mut output)?;
+        output.flush()?;
+        output.finish()?;
+        Ok(())
+    }
+
+    /// Calls the provided closure once with a handle to the configured output stream, without
+    /// reading any input. Useful for commands (like `inspect --hex=<literal>`) whose input comes
+    /// from somewhere other than a file or STDIN but that still want to honor `--output`/`--color`.
+    fn write_output(&mut self, f: impl FnOnce(&mut CommandOutput) -> Result<()>) -> Result<()> {
+        let mut stdout = None;
+        let mut output = self.build_output(&mut stdout)?;
+        f(&mut output)?;
         output.flush()?;
+        output.finish()?;
         Ok(())
     }
 }
@@ -0,0 +1,141 @@
+//! Opt-in value promotion for `from json --promote`: recognizes JSON strings that encode a richer
+//! Ion type than `string` and rewrites them in place. The decimal half mirrors
+//! `timestamp_conversion::convert_timestamps`'s shape, layering one more `ElementMapper` pass onto
+//! the same `structural_recursion` traversal; the key-convention half needs the enclosing struct
+//! field's name, which that traversal doesn't thread through, so it's a small hand-rolled
+//! recursion instead (see [`KeyConvention::promote`]).
+
+use anyhow::Result;
+use base64::{engine::general_purpose as base64_encoder, Engine as _};
+use ion_rs::{Blob, Element, IonType, List, SExp, Struct, Symbol};
+
+use super::structural_recursion::{map_structure, ElementMapper, MapAction};
+
+struct DecimalPromoter;
+
+impl ElementMapper for DecimalPromoter {
+    fn map(&self, element: Element) -> Result<MapAction> {
+        let mapped = element.as_text().and_then(as_decimal).unwrap_or(element);
+        Ok(MapAction::Replace(mapped))
+    }
+}
+
+/// Promotes strings that are exact decimal literals (e.g. `"19.90"`) to Ion `decimal`, preserving
+/// the trailing-zero precision a round trip through `f64` would lose. Leaves everything else,
+/// including plain integer-looking strings, untouched.
+pub fn convert_decimals(element: Element) -> Result<Element> {
+    map_structure(element, &DecimalPromoter)
+}
+
+/// Heuristic for a string that is *exactly* a decimal literal, not merely a number somewhere
+/// inside a longer string: optional `-`, at least one digit, a required `.`, at least one more
+/// digit, and an optional `d`/`D` exponent. Requiring the point rules out plain integer strings,
+/// which don't need `decimal`'s exact-precision guarantee.
+fn is_decimal_like(s: &str) -> bool {
+    let bytes = s.as_bytes();
+    let mut i = 0;
+    if bytes.first() == Some(&b'-') {
+        i += 1;
+    }
+
+    let digits_start = i;
+    while i < bytes.len() && bytes[i].is_ascii_digit() {
+        i += 1;
+    }
+    if i == digits_start || i >= bytes.len() || bytes[i] != b'.' {
+        return false;
+    }
+    i += 1;
+
+    let fraction_start = i;
+    while i < bytes.len() && bytes[i].is_ascii_digit() {
+        i += 1;
+    }
+    if i == fraction_start {
+        return false;
+    }
+    if i == bytes.len() {
+        return true;
+    }
+
+    if bytes[i] != b'd' && bytes[i] != b'D' {
+        return false;
+    }
+    i += 1;
+    if i < bytes.len() && (bytes[i] == b'+' || bytes[i] == b'-') {
+        i += 1;
+    }
+    let exponent_start = i;
+    while i < bytes.len() && bytes[i].is_ascii_digit() {
+        i += 1;
+    }
+    i > exponent_start && i == bytes.len()
+}
+
+fn as_decimal(s: &str) -> Option<Element> {
+    if !is_decimal_like(s) {
+        return None;
+    }
+    Element::read_one(s.as_bytes())
+        .ok()
+        .filter(|e| e.ion_type() == IonType::Decimal)
+}
+
+/// Reinterprets struct fields whose name carries a configured prefix as an Ion `symbol` or
+/// (base64-decoded) `blob`, the way `--promote`'s key convention lets a JSON document mark a value
+/// as a type JSON has no native way to express. `blob_prefix` is checked first, so a caller who
+/// configures e.g. `$` and `$$` doesn't have every blob field also match the symbol prefix.
+pub struct KeyConvention {
+    pub symbol_prefix: String,
+    pub blob_prefix: String,
+}
+
+impl KeyConvention {
+    /// Recurses through `element`, stripping a matching prefix from each struct field name it
+    /// finds and reinterpreting that field's value accordingly. A string whose value doesn't
+    /// actually decode (valid base64, for the blob prefix) is left as a string rather than
+    /// dropped.
+    pub fn promote(&self, element: Element) -> Element {
+        if let Some(s) = element.as_struct() {
+            let mut builder = Struct::builder();
+            for (name, value) in s.fields() {
+                let name = name.text().unwrap_or_default();
+                let value = self.promote(value.clone());
+                if let Some(stripped) = name.strip_prefix(self.blob_prefix.as_str()) {
+                    builder = builder.with_field(stripped, self.as_blob(value));
+                } else if let Some(stripped) = name.strip_prefix(self.symbol_prefix.as_str()) {
+                    builder = builder.with_field(stripped, self.as_symbol(value));
+                } else {
+                    builder = builder.with_field(name, value);
+                }
+            }
+            Element::from(builder.build())
+        } else if let Some(sequence) = element.as_sequence() {
+            let promoted = sequence.elements().map(|e| self.promote(e.clone()));
+            if element.ion_type() == IonType::SExp {
+                Element::from(SExp::from_iter(promoted))
+            } else {
+                Element::from(List::from_iter(promoted))
+            }
+        } else {
+            element
+        }
+    }
+
+    fn as_symbol(&self, value: Element) -> Element {
+        match value.as_string() {
+            Some(text) => Element::from(Symbol::from(text.to_string())),
+            None => value,
+        }
+    }
+
+    fn as_blob(&self, value: Element) -> Element {
+        match value
+            .as_string()
+            .and_then(|text| base64_encoder::STANDARD.decode(text).ok())
+        {
+            Some(bytes) => Element::from(Blob::from(bytes)),
+            None => value,
+        }
+    }
+}
@@ -0,0 +1,106 @@
+use crate::commands::structural_recursion::{select, Selector};
+use crate::commands::{CommandIo, IonCliCommand, WithIonCliArgument};
+use anyhow::Result;
+use clap::{arg, ArgMatches, Command};
+use ion_rs::{v1_0, v1_1, AnyEncoding, Format, IonEncoding, Reader, Writer};
+use std::io::Write as IoWrite;
+
+pub struct QueryCommand;
+
+impl IonCliCommand for QueryCommand {
+    fn name(&self) -> &'static str {
+        "query"
+    }
+
+    fn about(&self) -> &'static str {
+        "Evaluates a path/selector query against Ion input, printing every matching value."
+    }
+
+    fn is_porcelain(&self) -> bool {
+        false
+    }
+
+    fn configure_args(&self, command: Command) -> Command {
+        command
+            .arg(arg!(<selector> "The selector to evaluate, e.g. `.items[?(.price > 10)].name`"))
+            .with_input()
+            .with_output()
+            .with_format()
+            .with_color()
+    }
+
+    fn run(&self, _command_path: &mut Vec<String>, args: &ArgMatches) -> Result<()> {
+        let selector_text = args.get_one::<String>("selector").unwrap().as_str();
+        let selector = Selector::parse(selector_text)?;
+
+        CommandIo::new(args)?.for_each_input(|output, input| {
+            let mut reader = Reader::new(AnyEncoding, input.into_source())?;
+            let encoding = *output.encoding();
+            let format = *output.format();
+            write_matches(&mut reader, output, encoding, format, &selector)
+        })
+    }
+}
+
+/// Constructs the appropriate writer for the given format, then evaluates `selector` against
+/// every top-level value in `reader`, writing each match to the new `Writer`.
+///
+/// This deliberately doesn't go through [`crate::transcribe::write_all_as`]: that helper's
+/// mapper produces exactly one output value per input value, but a selector can produce any
+/// number of matches (including zero) per top-level value, so it needs its own loop.
+fn write_matches<I: ion_rs::IonInput>(
+    reader: &mut Reader<AnyEncoding, I>,
+    output: &mut impl IoWrite,
+    encoding: IonEncoding,
+    format: Format,
+    selector: &Selector,
+) -> Result<usize> {
+    let written = match (encoding, format) {
+        (IonEncoding::Text_1_0, Format::Text(text_format)) => {
+            let mut writer = Writer::new(v1_0::Text.with_format(text_format), output)?;
+            transcribe_matches(&mut writer, reader, selector)
+        }
+        (IonEncoding::Text_1_1, Format::Text(text_format)) => {
+            let mut writer = Writer::new(v1_1::Text.with_format(text_format), output)?;
+            transcribe_matches(&mut writer, reader, selector)
+        }
+        (IonEncoding::Binary_1_0, Format::Binary) => {
+            let mut writer = Writer::new(v1_0::Binary, output)?;
+            transcribe_matches(&mut writer, reader, selector)
+        }
+        (IonEncoding::Binary_1_1, Format::Binary) => {
+            let mut writer = Writer::new(v1_1::Binary, output)?;
+            transcribe_matches(&mut writer, reader, selector)
+        }
+        unrecognized => anyhow::bail!("unsupported format '{:?}'", unrecognized),
+    }?;
+    Ok(written)
+}
+
+/// Reads each top-level `LazyValue` straight off `reader` (rather than first materializing it as
+/// an `Element`, the way [`crate::transcribe::transcribe_n`] does) and evaluates `selector`
+/// against it, writing out whatever matches are found.
+fn transcribe_matches(
+    writer: &mut Writer<impl ion_rs::Encoding, impl IoWrite>,
+    reader: &mut Reader<AnyEncoding, impl ion_rs::IonInput>,
+    selector: &Selector,
+) -> Result<usize> {
+    const FLUSH_EVERY_N: usize = 100;
+    let mut values_since_flush = 0;
+    let mut written = 0;
+
+    while let Some(lazy_value) = reader.next()? {
+        for matched in select(lazy_value, selector)? {
+            writer.write(&matched)?;
+            written += 1;
+            values_since_flush += 1;
+            if values_since_flush == FLUSH_EVERY_N {
+                writer.flush()?;
+                values_since_flush = 0;
+            }
+        }
+    }
+
+    writer.flush()?;
+    Ok(written)
+}
@@ -14,8 +14,8 @@ impl IonCliCommand for CheckCommand {
         "Loads a schema and checks it for problems."
     }
 
-    fn is_stable(&self) -> bool {
-        false
+    fn unstable_features(&self) -> &'static [&'static str] {
+        &["schema-check"]
     }
 
     fn configure_args(&self, command: Command) -> Command {
@@ -0,0 +1,281 @@
+use crate::commands::schema::IonSchemaCommandInput;
+use crate::commands::{CommandIo, IonCliCommand, WithIonCliArgument};
+use anyhow::{bail, Result};
+use clap::{ArgMatches, Command};
+use ion_rs::Value;
+use ion_schema::isl::isl_constraint::{IslConstraint, IslConstraintValue};
+use ion_schema::isl::isl_type_reference::IslTypeRef;
+use ion_schema::isl::util::ValidValue;
+use serde_json::{Map, Value as JsonValue};
+use std::io::Write;
+
+pub struct ConvertCommand;
+
+impl IonCliCommand for ConvertCommand {
+    fn name(&self) -> &'static str {
+        "convert"
+    }
+
+    fn about(&self) -> &'static str {
+        "Converts an Ion Schema type definition to an equivalent JSON Schema (draft 2020-12) document."
+    }
+
+    fn unstable_features(&self) -> &'static [&'static str] {
+        &["schema-convert"]
+    }
+
+    fn configure_args(&self, command: Command) -> Command {
+        command
+            .arg(IonSchemaCommandInput::type_arg())
+            .args(IonSchemaCommandInput::schema_args())
+            .with_output()
+    }
+
+    fn run(&self, _command_path: &mut Vec<String>, args: &ArgMatches) -> Result<()> {
+        let ion_schema_input = IonSchemaCommandInput::read_from_args(args)?;
+        // Guaranteed to be `Some` because `type_arg()` is required.
+        let type_def = ion_schema_input.get_type().unwrap();
+
+        let mut defs = Map::new();
+        let mut warnings = Vec::new();
+        let mut json_schema =
+            constraints_to_json_schema(type_def.constraints(), &mut defs, &mut warnings);
+        if !defs.is_empty() {
+            json_schema.insert("$defs".to_string(), JsonValue::Object(defs));
+        }
+
+        CommandIo::new(args)?.write_output(move |output| {
+            for warning in &warnings {
+                eprintln!("warning: {warning}");
+            }
+            writeln!(
+                output,
+                "{}",
+                serde_json::to_string_pretty(&JsonValue::Object(json_schema))?
+            )?;
+            Ok(())
+        })
+    }
+}
+
+/// Maps a `fields`/`type: struct` type definition's constraints to a JSON Schema object (as a
+/// `serde_json::Map` rather than a fully-formed document, so that the top-level `convert` and the
+/// recursive `$ref`/`$defs` handling below can both reuse it). Any named type reference
+/// encountered along the way is added to `defs` (keyed by type name) so the caller can splice the
+/// accumulated definitions into a `$defs` block. A constraint with no JSON Schema equivalent (e.g.
+/// `annotations`, `precision`) is reported by pushing a message onto `warnings` rather than
+/// silently dropped, so the caller can surface what didn't make it across.
+fn constraints_to_json_schema(
+    constraints: &[IslConstraint],
+    defs: &mut Map<String, JsonValue>,
+    warnings: &mut Vec<String>,
+) -> Map<String, JsonValue> {
+    let mut json_schema = Map::new();
+    for constraint in constraints {
+        match constraint.constraint() {
+            IslConstraintValue::Type(isl_type_ref) => {
+                apply_type_reference(isl_type_ref, &mut json_schema, defs, warnings);
+            }
+            IslConstraintValue::CodepointLength(range) => {
+                apply_usize_range(&mut json_schema, "minLength", "maxLength", range);
+            }
+            IslConstraintValue::ByteLength(range) => {
+                apply_usize_range(&mut json_schema, "minLength", "maxLength", range);
+            }
+            IslConstraintValue::ContainerLength(range) => {
+                apply_usize_range(&mut json_schema, "minItems", "maxItems", range);
+            }
+            IslConstraintValue::Regex(regex) => {
+                json_schema.insert(
+                    "pattern".to_string(),
+                    JsonValue::String(regex.pattern().to_string()),
+                );
+            }
+            IslConstraintValue::ValidValues(valid_values_constraint) => {
+                let enum_values: Vec<JsonValue> = valid_values_constraint
+                    .values()
+                    .iter()
+                    .filter_map(|v| match valid_value_to_json(v) {
+                        Ok(json) => Some(json),
+                        Err(e) => {
+                            warnings.push(e.to_string());
+                            None
+                        }
+                    })
+                    .collect();
+                json_schema.insert("enum".to_string(), JsonValue::Array(enum_values));
+            }
+            IslConstraintValue::Fields(struct_fields, is_closed) => {
+                let mut properties = Map::new();
+                let mut required = Vec::new();
+                for (name, value) in struct_fields.iter() {
+                    let (min, _max) = value.occurs().inclusive_endpoints();
+                    if min > 0 {
+                        required.push(JsonValue::String(name.to_string()));
+                    }
+                    let mut field_schema = Map::new();
+                    apply_type_reference(value.type_reference(), &mut field_schema, defs, warnings);
+                    properties.insert(name.to_string(), JsonValue::Object(field_schema));
+                }
+                json_schema.insert("type".to_string(), JsonValue::String("object".to_string()));
+                json_schema.insert("properties".to_string(), JsonValue::Object(properties));
+                if !required.is_empty() {
+                    json_schema.insert("required".to_string(), JsonValue::Array(required));
+                }
+                if *is_closed {
+                    json_schema.insert("additionalProperties".to_string(), JsonValue::Bool(false));
+                } else {
+                    warnings.push(
+                        "open content (`fields` without `content: closed`) has no JSON Schema \
+                        equivalent that also permits Ion-only field values -- additional \
+                        properties of any JSON type are allowed, which is broader than Ion's open \
+                        content."
+                        .to_string(),
+                    );
+                }
+            }
+            IslConstraintValue::Element(element_type, _) => {
+                let mut items_schema = Map::new();
+                apply_type_reference(element_type, &mut items_schema, defs, warnings);
+                json_schema.insert("type".to_string(), JsonValue::String("array".to_string()));
+                json_schema.insert("items".to_string(), JsonValue::Object(items_schema));
+            }
+            IslConstraintValue::OrderedElements(element_types) => {
+                let items: Vec<JsonValue> = element_types
+                    .iter()
+                    .map(|element_type| {
+                        let mut item_schema = Map::new();
+                        apply_type_reference(element_type, &mut item_schema, defs, warnings);
+                        JsonValue::Object(item_schema)
+                    })
+                    .collect();
+                json_schema.insert("type".to_string(), JsonValue::String("array".to_string()));
+                json_schema.insert("prefixItems".to_string(), JsonValue::Array(items));
+                json_schema.insert("items".to_string(), JsonValue::Bool(false));
+            }
+            other => {
+                warnings.push(format!(
+                    "converting this ISL constraint to JSON Schema is not supported yet, so it \
+                    was dropped: {other:?}"
+                ));
+            }
+        }
+    }
+    json_schema
+}
+
+/// Applies the JSON Schema equivalent of an ISL `type` reference (either the top-level `type`
+/// constraint or a field's type) onto `json_schema`: a `"$ref"` into `$defs` for a named,
+/// non-core type, or a `"type"`/nullability mapping for a core Ion Schema type.
+fn apply_type_reference(
+    isl_type_ref: &IslTypeRef,
+    json_schema: &mut Map<String, JsonValue>,
+    defs: &mut Map<String, JsonValue>,
+    warnings: &mut Vec<String>,
+) {
+    let name = isl_type_ref.name().as_str();
+    // ISL 2.0 core types are nullable only when their name is prefixed with `$`
+    // (e.g. `$string` permits `null`, `string` does not).
+    let (name, nullable) = match name.strip_prefix('$') {
+        Some(unprefixed) => (unprefixed, true),
+        None => (name, false),
+    };
+
+    match core_json_type(name) {
+        Some(json_type) => {
+            if nullable {
+                json_schema.insert(
+                    "type".to_string(),
+                    JsonValue::Array(vec![
+                        JsonValue::String(json_type.to_string()),
+                        JsonValue::String("null".to_string()),
+                    ]),
+                );
+            } else {
+                json_schema.insert("type".to_string(), JsonValue::String(json_type.to_string()));
+            }
+            if let Some(format) = ion_only_format(name) {
+                json_schema.insert("format".to_string(), JsonValue::String(format.to_string()));
+                warnings.push(format!(
+                    "ISL type `{name}` has no JSON Schema analog; degraded to \
+                    {{\"type\":\"string\",\"format\":\"{format}\"}}, which JSON Schema validators \
+                    don't enforce on their own."
+                ));
+            }
+        }
+        // A reference to another named type in the schema, or `any`/`document`, which
+        // `core_json_type` gives no JSON type. We don't have an easy way to resolve and inline a
+        // named type's own definition from here, so it's recorded as an open (accept-anything) placeholder in `$defs` -- enough to
+        // make the `$ref` resolve, but not a full transitive conversion of the referenced type.
+        None => {
+            defs.entry(name.to_string())
+                .or_insert(JsonValue::Bool(true));
+            json_schema.insert(
+                "$ref".to_string(),
+                JsonValue::String(format!("#/$defs/{name}")),
+            );
+        }
+    }
+}
+
+/// Maps an ISL built-in type name to its JSON Schema `type` keyword value. Returns `None` for
+/// `document`/`any` (no single JSON Schema type restriction applies) and for any name that isn't
+/// one of ISL's built-in type names (i.e. it names another type in the schema).
+fn core_json_type(isl_type_name: &str) -> Option<&'static str> {
+    Some(match isl_type_name {
+        "string" | "symbol" | "timestamp" | "blob" | "clob" | "decimal" => "string",
+        "int" => "integer",
+        "float" => "number",
+        "bool" => "boolean",
+        "list" | "sexp" => "array",
+        "struct" => "object",
+        _ => return None,
+    })
+}
+
+/// Ion scalar types with no native JSON representation degrade to a plain JSON string tagged with
+/// a `format` keyword (e.g. `{"type":"string","format":"ion-symbol"}`) rather than an
+/// indistinguishable bare string -- see [`apply_type_reference`]'s warning when this fires.
+fn ion_only_format(isl_type_name: &str) -> Option<&'static str> {
+    Some(match isl_type_name {
+        "symbol" => "ion-symbol",
+        "timestamp" => "ion-timestamp",
+        "blob" => "ion-blob",
+        "clob" => "ion-clob",
+        _ => return None,
+    })
+}
+
+/// Applies a `container_length`/`codepoint_length`/`byte_length` constraint's `UsizeRange` as a
+/// `min*`/`max*` pair of JSON Schema keywords, omitting `max*` when the range has no upper bound.
+fn apply_usize_range(
+    json_schema: &mut Map<String, JsonValue>,
+    min_keyword: &str,
+    max_keyword: &str,
+    range: &ion_schema::isl::ranges::UsizeRange,
+) {
+    let (min, max) = range.inclusive_endpoints();
+    json_schema.insert(min_keyword.to_string(), JsonValue::Number(min.into()));
+    if max < usize::MAX {
+        json_schema.insert(max_keyword.to_string(), JsonValue::Number(max.into()));
+    }
+}
+
+/// Converts a single `valid_values` entry to its JSON representation. Only `symbol` and `string`
+/// values are supported; any other entry is an error, which the caller reports as a warning.
+fn valid_value_to_json(valid_value: &ValidValue) -> Result<JsonValue> {
+    match valid_value {
+        ValidValue::Element(Value::Symbol(symbol_val)) => Ok(JsonValue::String(
+            symbol_val
+                .text()
+                .ok_or_else(|| anyhow::anyhow!("Could not determine `valid_values` entry text"))?
+                .to_string(),
+        )),
+        ValidValue::Element(Value::String(string_val)) => {
+            Ok(JsonValue::String(string_val.text().to_string()))
+        }
+        _ => bail!(
+            "Only `valid_values` constraints with `symbol` or `string` values are supported yet!"
+        ),
+    }
+}
@@ -0,0 +1,108 @@
+use ion_schema::authority::DocumentAuthority;
+use ion_schema::result::{IonSchemaError, IonSchemaResult};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::hash::{Hash, Hasher};
+use std::io::Read;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// How long to wait on a schema authority's response before giving up. Without a bound, an
+/// unreachable or slow authority host would hang `ion schema load`/`validate` indefinitely rather
+/// than failing the way a missing local `--authority` directory would.
+const FETCH_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Where [`HttpDocumentAuthority`] persists fetched schema documents between separate `ion schema`
+/// invocations, keyed by a hash of the resolved URL. A cache directory that can't be created or
+/// written to is not an error -- it just means the next run re-fetches. `--offline` bypasses this
+/// authority (and the network) entirely rather than depending on it already being warm.
+fn default_cache_dir() -> PathBuf {
+    std::env::temp_dir()
+        .join("ion-cli")
+        .join("schema-authority-cache")
+}
+
+/// A [DocumentAuthority] that resolves schema ids against a base URL over HTTP(S), analogous to
+/// [FileSystemDocumentAuthority](ion_schema::authority::FileSystemDocumentAuthority) resolving
+/// them against a directory on disk.
+///
+/// Fetched documents are cached both in memory (so importing the same schema more than once in a
+/// single load, e.g. because multiple types import from it, only issues one network request) and
+/// on disk under `cache_dir` (so repeated imports across separate invocations don't re-fetch
+/// either).
+pub struct HttpDocumentAuthority {
+    base_url: String,
+    cache_dir: PathBuf,
+    cache: Mutex<HashMap<String, Vec<u8>>>,
+}
+
+impl HttpDocumentAuthority {
+    pub fn new(base_url: &str) -> Self {
+        Self::with_cache_dir(base_url, default_cache_dir())
+    }
+
+    pub fn with_cache_dir(base_url: &str, cache_dir: PathBuf) -> Self {
+        HttpDocumentAuthority {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            cache_dir,
+            cache: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn on_disk_cache_file(&self, url: &str) -> PathBuf {
+        let mut hasher = DefaultHasher::new();
+        url.hash(&mut hasher);
+        self.cache_dir.join(format!("{:016x}", hasher.finish()))
+    }
+
+    fn fetch(&self, id: &str) -> IonSchemaResult<Vec<u8>> {
+        let url = format!("{}/{}", self.base_url, id.trim_start_matches('/'));
+        let cache_file = self.on_disk_cache_file(&url);
+        if let Ok(content) = fs::read(&cache_file) {
+            return Ok(content);
+        }
+
+        let response = ureq::get(&url)
+            .timeout(FETCH_TIMEOUT)
+            .call()
+            .map_err(|e| IonSchemaError::IoError {
+                source: std::io::Error::new(std::io::ErrorKind::Other, e.to_string()),
+            })?;
+        let mut content = Vec::new();
+        response
+            .into_reader()
+            .read_to_end(&mut content)
+            .map_err(|source| IonSchemaError::IoError { source })?;
+
+        if fs::create_dir_all(&self.cache_dir).is_ok() {
+            let _ = fs::write(&cache_file, &content);
+        }
+
+        Ok(content)
+    }
+}
+
+impl fmt::Debug for HttpDocumentAuthority {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "HttpDocumentAuthority {{ base_url: {} }}", self.base_url)
+    }
+}
+
+impl DocumentAuthority for HttpDocumentAuthority {
+    fn elements(&self, id: &str, content: &mut Vec<u8>) -> IonSchemaResult<usize> {
+        let mut cache = self.cache.lock().expect("cache mutex was poisoned");
+        if let Some(cached) = cache.get(id) {
+            content.extend_from_slice(cached);
+            return Ok(cached.len());
+        }
+
+        let fetched = self.fetch(id)?;
+        content.extend_from_slice(&fetched);
+        let len = fetched.len();
+        cache.insert(id.to_string(), fetched);
+        Ok(len)
+    }
+}
@@ -1,8 +1,10 @@
+use crate::commands::schema::check_declared_isl_version;
 use crate::commands::IonCliCommand;
 use anyhow::Result;
 use clap::{Arg, ArgAction, ArgMatches, Command};
 use ion_schema::authority::{DocumentAuthority, FileSystemDocumentAuthority};
 use ion_schema::system::SchemaSystem;
+use std::fs;
 use std::path::Path;
 
 pub struct LoadCommand;
@@ -39,21 +41,35 @@ impl IonCliCommand for LoadCommand {
                     .required(true)
                     .help("One or more directories that will be searched for the requested schema"),
             )
+            .arg(
+                Arg::new("isl-version")
+                    .long("isl-version")
+                    .required(false)
+                    .action(ArgAction::Set)
+                    .value_name("version")
+                    .value_parser(["1.0", "2.0"])
+                    .help(
+                        "The Ion Schema version the loaded schema is expected to declare. If the \
+                         schema's declared version header doesn't match, this is an error.",
+                    ),
+            )
     }
 
     fn run(&self, _command_path: &mut Vec<String>, args: &ArgMatches) -> Result<()> {
         // Extract the user provided document authorities/ directories
-        let authorities: Vec<&String> = args.get_many("directories").unwrap().collect();
+        let directories: Vec<&String> = args.get_many("directories").unwrap().collect();
 
         // Extract schema file provided by user
         let schema_id = args.get_one::<String>("schema").unwrap();
 
+        let requested_isl_version = args.get_one::<String>("isl-version").map(String::as_str);
+
         // Set up document authorities vector
         let mut document_authorities: Vec<Box<dyn DocumentAuthority>> = vec![];
 
-        for authority in authorities {
+        for directory in &directories {
             document_authorities.push(Box::new(FileSystemDocumentAuthority::new(Path::new(
-                authority,
+                directory,
             ))))
         }
 
@@ -61,7 +77,22 @@ impl IonCliCommand for LoadCommand {
         let mut schema_system = SchemaSystem::new(document_authorities);
 
         // load given schema
-        println!("Schema: {:#?}", schema_system.load_schema(schema_id)?);
+        let schema = schema_system.load_schema(schema_id)?;
+
+        // `--isl-version`, if given, is checked against the schema's declared version header after
+        // a successful load, by re-reading the resolved document's raw bytes from whichever
+        // directory actually holds `schema_id` (mirroring `FileSystemDocumentAuthority`'s own
+        // resolution, which the schema system doesn't expose the result of).
+        if let Some(requested_isl_version) = requested_isl_version {
+            for directory in &directories {
+                if let Ok(content) = fs::read(Path::new(directory).join(schema_id)) {
+                    check_declared_isl_version(&content, Some(requested_isl_version))?;
+                    break;
+                }
+            }
+        }
+
+        println!("Schema: {:#?}", schema);
 
         Ok(())
     }
@@ -1,11 +1,15 @@
 pub mod check;
+pub mod convert;
+mod http_authority;
 pub mod validate;
 
 use crate::commands::command_namespace::IonCliNamespace;
 use crate::commands::schema::check::CheckCommand;
+use crate::commands::schema::convert::ConvertCommand;
+use crate::commands::schema::http_authority::HttpDocumentAuthority;
 use crate::commands::schema::validate::ValidateCommand;
 use crate::commands::IonCliCommand;
-use anyhow::Context;
+use anyhow::{bail, Context};
 use clap::{Arg, ArgAction, ArgMatches, ValueHint};
 use ion_rs::Element;
 use ion_schema::authority::{DocumentAuthority, FileSystemDocumentAuthority};
@@ -31,6 +35,7 @@ impl IonCliNamespace for SchemaNamespace {
         vec![
             Box::new(CheckCommand),
             Box::new(ValidateCommand),
+            Box::new(ConvertCommand),
             // TODO: Filter values command?
             // TODO: Compare types command?
             // TODO: Canonical representation of types command?
@@ -58,10 +63,20 @@ impl IonSchemaCommandInput {
             .map(Path::new)
             .map(FileSystemDocumentAuthority::new)
             .for_each(|a| authorities.push(Box::new(a)));
+        if !args.get_flag("offline") {
+            args.get_many::<String>("authority-url")
+                .unwrap_or_default()
+                .map(|url| HttpDocumentAuthority::new(url))
+                .for_each(|a| authorities.push(Box::new(a)));
+        }
 
         // Create a new schema system from given document authorities
         let mut schema_system = SchemaSystem::new(authorities);
 
+        // The user's explicit `--isl-version`, if any. A loaded document's own declared version
+        // must match it (a mismatch is an error); it takes precedence over the `--empty` default.
+        let requested_isl_version = args.get_one::<String>("isl-version").map(String::as_str);
+
         // Load the appropriate schema
         let mut empty_schema_version = None;
         let mut schema = if args.contains_id("schema-id") {
@@ -70,13 +85,20 @@ impl IonSchemaCommandInput {
         } else if args.contains_id("schema-file") {
             let file_name = args.get_one::<String>("schema-file").unwrap();
             let content = fs::read(file_name)?;
+            check_declared_isl_version(&content, requested_isl_version)?;
             schema_system.new_schema(&content, "user-provided-schema")?
         } else if args.contains_id("schema-text") {
             let content = args.get_one::<&str>("schema-text").unwrap();
+            check_declared_isl_version(content.as_bytes(), requested_isl_version)?;
             schema_system.new_schema(content.as_bytes(), "user-provided-schema")?
         } else {
-            let version = match args.get_one::<String>("empty-schema") {
-                Some(version) if version == "1.0" => "$ion_schema_1_0",
+            // Precedence for the version used to synthesize an empty/inline-type schema:
+            // explicit `--isl-version` > explicit `--empty <version>` > the "2.0" default that
+            // `--empty` itself already carries.
+            let version = match requested_isl_version
+                .or(args.get_one::<String>("empty-schema").map(String::as_str))
+            {
+                Some("1.0") => "$ion_schema_1_0",
                 _ => "$ion_schema_2_0",
             };
             empty_schema_version = Some(version);
@@ -202,6 +224,72 @@ impl IonSchemaCommandInput {
                     schema needs to import a type from another schema or if you are loading a schema using \
                     the --id option.",
                 ),
+            Arg::new("authority-url")
+                .help_heading(schema_options_header)
+                .long("authority-url")
+                .required(false)
+                .action(ArgAction::Append)
+                .value_name("url")
+                .value_hint(ValueHint::Url)
+                .help(
+                    "The base URL(s) of HTTP(S) authorities. Schema ids are resolved by fetching \
+                    <url>/<id>. Like --authority, these are only required if your schema needs to \
+                    import a type from another schema or if you are loading a schema using the --id \
+                    option.",
+                ),
+            Arg::new("offline")
+                .help_heading(schema_options_header)
+                .long("offline")
+                .action(ArgAction::SetTrue)
+                .help(
+                    "Disable network authorities entirely: --authority-url is ignored (even if \
+                    given, including its on-disk cache), and only --authority is used to resolve \
+                    imports.",
+                ),
+            Arg::new("isl-version")
+                .help_heading(schema_options_header)
+                .long("isl-version")
+                .required(false)
+                .action(ArgAction::Set)
+                .value_name("version")
+                .value_parser(["1.0", "2.0"])
+                .help(
+                    "The Ion Schema version to expect. If a loaded --schema-file/--schema-text \
+                    document declares a different version, this is an error. Also selects the \
+                    version header used when synthesizing an inline type's wrapper schema, taking \
+                    precedence over --empty's version. Defaults to the document's declared version, \
+                    or --empty's version when there is no document.",
+                ),
         ]
     }
 }
+
+/// If `requested_version` is set, checks it against the Ion Schema version `content` itself
+/// declares (the leading `$ion_schema_1_0`/`$ion_schema_2_0` symbol), erroring on a mismatch.
+/// A document with no declared version, or no `requested_version` to check against, is left for
+/// the schema system's own parsing to accept or reject.
+fn check_declared_isl_version(
+    content: &[u8],
+    requested_version: Option<&str>,
+) -> anyhow::Result<()> {
+    let Some(requested_version) = requested_version else {
+        return Ok(());
+    };
+    let text = std::string::String::from_utf8_lossy(content);
+    let declared_version = if text.contains("$ion_schema_1_0") {
+        Some("1.0")
+    } else if text.contains("$ion_schema_2_0") {
+        Some("2.0")
+    } else {
+        None
+    };
+    if let Some(declared_version) = declared_version {
+        if declared_version != requested_version {
+            bail!(
+                "Schema declares Ion Schema version {declared_version}, but --isl-version \
+                {requested_version} was requested."
+            );
+        }
+    }
+    Ok(())
+}
@@ -1,10 +1,13 @@
+use super::structural_recursion::{visit_structure, ValueVisitor};
 use crate::commands::{CommandIo, IonCliCommand, WithIonCliArgument};
 use anyhow::Result;
-use clap::{Arg, ArgMatches, Command};
+use clap::{value_parser, Arg, ArgMatches, Command};
 use ion_rs::*;
 use ion_rs::{AnyEncoding, IonInput, SystemReader, SystemStreamItem};
 use lowcharts::plot;
+use serde::Serialize;
 use std::cmp::max;
+use std::collections::HashMap;
 
 pub struct StatsCommand;
 
@@ -17,8 +20,8 @@ impl IonCliCommand for StatsCommand {
         "stats"
     }
 
-    fn is_stable(&self) -> bool {
-        false
+    fn unstable_features(&self) -> &'static [&'static str] {
+        &["stats"]
     }
 
     fn about(&self) -> &'static str {
@@ -31,8 +34,11 @@ impl IonCliCommand for StatsCommand {
         top-level values, their minimum, maximum, and mean sizes, and plot the size distribution of\n\
         the input stream. The report should also include the number of symbol tables in the input\n\
         stream, the total number of different symbols that occurred in the input stream, and the\n\
-        maximum depth of the input data stream. Currently, this subcommand only supports data\n\
-        analysis on binary Ion data.")
+        maximum depth of the input data stream. This works on text or binary Ion, from a file or\n\
+        from STDIN; values whose byte size can't be measured (e.g. a 1.1 value with no physical\n\
+        representation) are excluded from the size distribution rather than counted as zero-sized.\n\
+        Input compressed with gzip, zstd, xz, bzip2, or lz4 is decompressed automatically; pass\n\
+        --no-auto-decompress to disable this and read the raw bytes as-is.")
             .with_input()
             .with_output()
             .arg(
@@ -42,6 +48,29 @@ impl IonCliCommand for StatsCommand {
                     .num_args(0)
                     .help("Emit only the count of items for each supplied stream"),
             )
+            .arg(
+                Arg::new("format")
+                    .long("format")
+                    .short('f')
+                    .default_value("text")
+                    .value_parser(["text", "ion", "json"])
+                    .help(
+                        "Report format: 'text' for the human-oriented histogram, 'ion' or 'json' \
+                         for a single machine-readable document that can be piped into other \
+                         tooling (`ion`'s own subcommands, dashboards, etc.)",
+                    ),
+            )
+            .arg(
+                Arg::new("top")
+                    .long("top")
+                    .value_parser(value_parser!(usize))
+                    .allow_negative_numbers(false)
+                    .default_value("10")
+                    .help(
+                        "The number of most frequent symbol tokens (field names, annotations, \
+                         and symbol values) to report.",
+                    ),
+            )
     }
 
     fn run(&self, _command_path: &mut Vec<String>, args: &ArgMatches) -> Result<()> {
@@ -59,6 +88,274 @@ struct StreamStats {
     symbols_count: usize,
     max_depth: usize,
     unparseable_count: usize,
+    p50_size: f64,
+    p90_size: f64,
+    p99_size: f64,
+    /// Count and size distribution, keyed by the Ion type of every value encountered (top-level
+    /// or nested). Only top-level values carry a measured byte size (see [`TypeStats::record`]).
+    per_type: HashMap<IonType, TypeStats>,
+    /// Count and depth range for every struct field name encountered, wherever it occurs.
+    field_tally: HashMap<String, FieldTally>,
+    /// How often each symbol token's text occurred, as a field name, an annotation, or a symbol
+    /// value.
+    symbol_tally: HashMap<String, u64>,
+}
+
+/// The count and, where measurable, size distribution of every value of a single [`IonType`]
+/// encountered during a stats traversal.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+struct TypeStats {
+    count: usize,
+    /// How many of `count`'s occurrences had a measurable size; only top-level values do, since
+    /// nested values aren't backed by their own span.
+    sized_count: usize,
+    min: f64,
+    max: f64,
+    sum: f64,
+}
+
+impl TypeStats {
+    fn record(&mut self, size: Option<f64>) {
+        self.count += 1;
+        if let Some(size) = size {
+            if self.sized_count == 0 {
+                self.min = size;
+                self.max = size;
+            } else {
+                self.min = self.min.min(size);
+                self.max = self.max.max(size);
+            }
+            self.sum += size;
+            self.sized_count += 1;
+        }
+    }
+
+    fn mean(&self) -> f64 {
+        if self.sized_count == 0 {
+            0.0
+        } else {
+            self.sum / self.sized_count as f64
+        }
+    }
+}
+
+/// How often a struct field name occurred, and the range of nesting depths it occurred at.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+struct FieldTally {
+    count: usize,
+    min_depth: usize,
+    max_depth: usize,
+}
+
+impl FieldTally {
+    fn record(&mut self, depth: usize) {
+        if self.count == 0 {
+            self.min_depth = depth;
+            self.max_depth = depth;
+        } else {
+            self.min_depth = self.min_depth.min(depth);
+            self.max_depth = self.max_depth.max(depth);
+        }
+        self.count += 1;
+    }
+}
+
+/// Folds depth tracking, per-type counts/sizes, and struct field-name tallying into the single
+/// streaming traversal [`visit_structure`] already performs for a top-level value, rather than
+/// running a second pass over the same tree.
+struct StructureVisitor<'a> {
+    max_depth: &'a mut usize,
+    per_type: &'a mut HashMap<IonType, TypeStats>,
+    field_tally: &'a mut HashMap<String, FieldTally>,
+    /// How often each symbol token's text occurred, whether as a field name, an annotation, or a
+    /// symbol value.
+    symbol_tally: &'a mut HashMap<String, u64>,
+    /// The measured byte size of the top-level value this traversal started from, if any.
+    top_level_size: Option<f64>,
+}
+
+impl<'a> StructureVisitor<'a> {
+    fn tally_symbol(&mut self, text: &str) {
+        *self.symbol_tally.entry(text.to_string()).or_insert(0) += 1;
+    }
+}
+
+impl ValueVisitor<()> for StructureVisitor<'_> {
+    fn visit(
+        &mut self,
+        value: ValueRef<AnyEncoding>,
+        depth: usize,
+        field_name: Option<&str>,
+        annotations: &[String],
+    ) -> Result<()> {
+        *self.max_depth = max(*self.max_depth, depth);
+
+        let size = if depth == 0 {
+            self.top_level_size
+        } else {
+            None
+        };
+        self.per_type
+            .entry(value_ref_ion_type(&value))
+            .or_default()
+            .record(size);
+
+        if let Some(name) = field_name {
+            self.field_tally
+                .entry(name.to_string())
+                .or_default()
+                .record(depth);
+            self.tally_symbol(name);
+        }
+
+        for annotation in annotations {
+            self.tally_symbol(annotation);
+        }
+
+        if let ValueRef::Symbol(symbol) = value {
+            if let Some(text) = symbol.text() {
+                self.tally_symbol(text);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn result(self) {}
+}
+
+/// Returns `value_ref`'s [`IonType`]; `ValueRef` doesn't expose this directly, so every variant
+/// is matched out by hand.
+fn value_ref_ion_type(value_ref: &ValueRef<AnyEncoding>) -> IonType {
+    use ValueRef::*;
+    match value_ref {
+        Null(ion_type) => *ion_type,
+        Bool(_) => IonType::Bool,
+        Int(_) => IonType::Int,
+        Float(_) => IonType::Float,
+        Decimal(_) => IonType::Decimal,
+        Timestamp(_) => IonType::Timestamp,
+        Symbol(_) => IonType::Symbol,
+        String(_) => IonType::String,
+        Blob(_) => IonType::Blob,
+        Clob(_) => IonType::Clob,
+        SExp(_) => IonType::SExp,
+        List(_) => IonType::List,
+        Struct(_) => IonType::Struct,
+    }
+}
+
+/// A constant-memory estimator for a single quantile `p`, updated one observation at a time via
+/// the P² (Jain-Chlamtac) algorithm (https://www.cs.wustl.edu/~jain/papers/psqr.htm). Used so
+/// `stats` can report size percentiles over streams too large to hold in memory all at once,
+/// without the two-pass sort a plain percentile calculation would need.
+#[derive(Debug, Clone, PartialEq)]
+struct P2Quantile {
+    p: f64,
+    /// Marker heights: the current estimate of the value at each of the 5 markers.
+    q: [f64; 5],
+    /// Marker positions: the integer rank of each marker among observations seen so far.
+    n: [i64; 5],
+    /// Desired (fractional) marker positions, adjusted by `dn` after every observation.
+    np: [f64; 5],
+    /// Desired position increments, derived once from `p`.
+    dn: [f64; 5],
+    /// Buffers the first 5 observations until there are enough to seed the markers.
+    startup: Vec<f64>,
+}
+
+impl P2Quantile {
+    fn new(p: f64) -> Self {
+        P2Quantile {
+            p,
+            q: [0.0; 5],
+            n: [0; 5],
+            np: [0.0; 5],
+            dn: [0.0, p / 2.0, p, (1.0 + p) / 2.0, 1.0],
+            startup: Vec::with_capacity(5),
+        }
+    }
+
+    fn observe(&mut self, x: f64) {
+        if self.startup.len() < 5 {
+            self.startup.push(x);
+            if self.startup.len() == 5 {
+                self.startup.sort_by(|a, b| a.partial_cmp(b).unwrap());
+                self.q.copy_from_slice(&self.startup);
+                self.n = [1, 2, 3, 4, 5];
+                self.np = [
+                    1.0,
+                    1.0 + 2.0 * self.p,
+                    1.0 + 4.0 * self.p,
+                    3.0 + 2.0 * self.p,
+                    5.0,
+                ];
+            }
+            return;
+        }
+
+        let k = if x < self.q[0] {
+            self.q[0] = x;
+            0
+        } else if x >= self.q[4] {
+            self.q[4] = x;
+            3
+        } else {
+            (0..4)
+                .find(|&i| self.q[i] <= x && x < self.q[i + 1])
+                .expect("x is between q[0] and q[4], so some cell must contain it")
+        };
+
+        for n in self.n.iter_mut().skip(k + 1) {
+            *n += 1;
+        }
+        for i in 0..5 {
+            self.np[i] += self.dn[i];
+        }
+
+        for i in 1..4 {
+            let d = self.np[i] - self.n[i] as f64;
+            if (d >= 1.0 && self.n[i + 1] - self.n[i] > 1)
+                || (d <= -1.0 && self.n[i - 1] - self.n[i] < -1)
+            {
+                let d_sign: i64 = if d >= 0.0 { 1 } else { -1 };
+                let parabolic = self.parabolic(i, d_sign as f64);
+                self.q[i] = if self.q[i - 1] < parabolic && parabolic < self.q[i + 1] {
+                    parabolic
+                } else {
+                    self.linear(i, d_sign)
+                };
+                self.n[i] += d_sign;
+            }
+        }
+    }
+
+    /// The parabolic (quadratic) prediction for marker `i`'s new height, per the P² formula.
+    fn parabolic(&self, i: usize, d: f64) -> f64 {
+        let (n_im1, n_i, n_ip1) = (self.n[i - 1] as f64, self.n[i] as f64, self.n[i + 1] as f64);
+        let (q_im1, q_i, q_ip1) = (self.q[i - 1], self.q[i], self.q[i + 1]);
+        q_i + (d / (n_ip1 - n_im1))
+            * ((n_i - n_im1 + d) * (q_ip1 - q_i) / (n_ip1 - n_i)
+                + (n_ip1 - n_i - d) * (q_i - q_im1) / (n_i - n_im1))
+    }
+
+    /// Falls back to linear interpolation toward the neighboring marker when the parabolic
+    /// prediction would overshoot it.
+    fn linear(&self, i: usize, d_sign: i64) -> f64 {
+        let j = (i as i64 + d_sign) as usize;
+        self.q[i] + d_sign as f64 * (self.q[j] - self.q[i]) / (self.n[j] - self.n[i]) as f64
+    }
+
+    /// The current estimate of this estimator's quantile, or `0.0` if fewer than 5 observations
+    /// have been seen yet (not enough to seed the markers), matching [`size_summary`]'s
+    /// zeros-when-empty convention.
+    fn value(&self) -> f64 {
+        if self.startup.len() < 5 {
+            0.0
+        } else {
+            self.q[2]
+        }
+    }
 }
 
 fn analyze<Input: IonInput>(
@@ -67,7 +364,147 @@ fn analyze<Input: IonInput>(
     args: &ArgMatches,
 ) -> Result<()> {
     let stats = analyze_data_stream(reader)?;
-    // Plot a histogram of the above vector, with 4 buckets and a precision
+
+    if args.get_flag("count") {
+        writeln!(writer, "{}", stats.size_vec.len())?;
+        return Ok(());
+    }
+
+    let top = *args.get_one::<usize>("top").unwrap();
+
+    match args.get_one::<String>("format").map(String::as_str) {
+        Some("ion") => write_ion_report(&stats, top, writer),
+        Some("json") => write_json_report(&stats, top, writer),
+        _ => write_text_report(&stats, top, writer),
+    }
+}
+
+/// The fields common to every machine-readable stats report, independent of whether it's
+/// serialized as Ion or JSON.
+#[derive(Debug, Serialize)]
+struct StatsReport {
+    samples: usize,
+    min: f64,
+    max: f64,
+    mean: f64,
+    p50: f64,
+    p90: f64,
+    p99: f64,
+    symbols: usize,
+    local_symbol_tables: i32,
+    max_depth: usize,
+    unparseable: usize,
+    size_buckets: Vec<i64>,
+    /// Count and size distribution by Ion type, keyed by type name (e.g. `"struct"`, `"int"`).
+    by_type: HashMap<String, TypeReport>,
+    /// Count and depth range by struct field name, wherever it occurs in the stream.
+    by_field: HashMap<String, FieldTally>,
+    /// The `top` most frequent symbol tokens (by text), across field names, annotations, and
+    /// symbol values, ranked most-frequent first.
+    top_symbols: Vec<SymbolFrequency>,
+}
+
+/// A single symbol token's entry in [`StatsReport::top_symbols`].
+#[derive(Debug, Serialize)]
+struct SymbolFrequency {
+    text: String,
+    count: u64,
+}
+
+/// A single [`IonType`]'s entry in [`StatsReport::by_type`].
+#[derive(Debug, Serialize)]
+struct TypeReport {
+    count: usize,
+    min: f64,
+    max: f64,
+    mean: f64,
+}
+
+impl StatsReport {
+    fn from_stats(stats: &StreamStats, top: usize) -> Self {
+        let (min, max, mean) = size_summary(&stats.size_vec);
+        let mut top_symbols: Vec<_> = stats
+            .symbol_tally
+            .iter()
+            .map(|(text, &count)| SymbolFrequency {
+                text: text.clone(),
+                count,
+            })
+            .collect();
+        top_symbols.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.text.cmp(&b.text)));
+        top_symbols.truncate(top);
+        StatsReport {
+            samples: stats.size_vec.len(),
+            min,
+            max,
+            mean,
+            p50: stats.p50_size,
+            p90: stats.p90_size,
+            p99: stats.p99_size,
+            symbols: stats.symbols_count,
+            local_symbol_tables: stats.symtab_count,
+            max_depth: stats.max_depth,
+            unparseable: stats.unparseable_count,
+            size_buckets: size_buckets(&stats.size_vec, 4),
+            by_type: stats
+                .per_type
+                .iter()
+                .map(|(ion_type, type_stats)| {
+                    (
+                        ion_type_name(*ion_type).to_string(),
+                        TypeReport {
+                            count: type_stats.count,
+                            min: type_stats.min,
+                            max: type_stats.max,
+                            mean: type_stats.mean(),
+                        },
+                    )
+                })
+                .collect(),
+            by_field: stats.field_tally.clone(),
+            top_symbols,
+        }
+    }
+}
+
+/// Returns a short, lowercase name for `ion_type`, for use as a [`StatsReport::by_type`] key.
+fn ion_type_name(ion_type: IonType) -> &'static str {
+    use IonType::*;
+    match ion_type {
+        Null => "null",
+        Bool => "bool",
+        Int => "int",
+        Float => "float",
+        Decimal => "decimal",
+        Timestamp => "timestamp",
+        Symbol => "symbol",
+        String => "string",
+        Blob => "blob",
+        Clob => "clob",
+        SExp => "sexp",
+        List => "list",
+        Struct => "struct",
+    }
+}
+
+/// Serializes `stats` as a single `{...}` JSON document with the same fields as
+/// [`write_ion_report`], so the report can be piped into dashboards or other JSON-speaking tools.
+fn write_json_report(
+    stats: &StreamStats,
+    top: usize,
+    mut writer: impl std::io::Write,
+) -> Result<()> {
+    let report = StatsReport::from_stats(stats, top);
+    writeln!(writer, "{}", serde_json::to_string_pretty(&report)?)?;
+    Ok(())
+}
+
+fn write_text_report(
+    stats: &StreamStats,
+    top: usize,
+    mut writer: impl std::io::Write,
+) -> Result<()> {
+    // Plot a histogram of the size vector, with 4 buckets and a precision
     // chosen by library. The number of buckets could be changed as needed.
     let options = plot::HistogramOptions {
         intervals: 4,
@@ -75,27 +512,199 @@ fn analyze<Input: IonInput>(
     };
     let histogram = plot::Histogram::new(&stats.size_vec, options);
 
-    if args.get_flag("count") {
-        writeln!(writer, "{}", stats.size_vec.len())?;
-        return Ok(());
-    } else {
+    writeln!(
+        writer,
+        "'samples' is the number of top-level values for the input stream."
+    )?;
+    writeln!(writer, "The unit of min, max, and avg size is bytes.")?;
+    writeln!(writer, "{}", histogram)?;
+    writeln!(
+        writer,
+        "Size percentiles: p50={}, p90={}, p99={}",
+        stats.p50_size, stats.p90_size, stats.p99_size
+    )?;
+    writeln!(writer, "Symbols: {} ", stats.symbols_count)?;
+    writeln!(writer, "Local symbol tables: {} ", stats.symtab_count)?;
+    writeln!(writer, "Maximum container depth: {}", stats.max_depth)?;
+    if stats.unparseable_count > 0 {
+        writeln!(writer, "Unparseable values: {}", stats.unparseable_count)?;
+    }
+
+    writeln!(writer, "By type:")?;
+    let mut types: Vec<_> = stats.per_type.iter().collect();
+    types.sort_by_key(|(ion_type, _)| ion_type_name(**ion_type));
+    for (ion_type, type_stats) in types {
         writeln!(
             writer,
-            "'samples' is the number of top-level values for the input stream."
+            "  {}: count={}, min={}, max={}, mean={}",
+            ion_type_name(*ion_type),
+            type_stats.count,
+            type_stats.min,
+            type_stats.max,
+            type_stats.mean()
         )?;
-        writeln!(writer, "The unit of min, max, and avg size is bytes.")?;
-        writeln!(writer, "{}", histogram)?;
-        writeln!(writer, "Symbols: {} ", stats.symbols_count)?;
-        writeln!(writer, "Local symbol tables: {} ", stats.symtab_count)?;
-        writeln!(writer, "Maximum container depth: {}", stats.max_depth)?;
-        if stats.unparseable_count > 0 {
-            writeln!(writer, "Unparseable values: {}", stats.unparseable_count)?;
+    }
+
+    if !stats.field_tally.is_empty() {
+        writeln!(writer, "By field name:")?;
+        let mut fields: Vec<_> = stats.field_tally.iter().collect();
+        fields.sort_by(|(_, a), (_, b)| b.count.cmp(&a.count));
+        for (name, tally) in fields {
+            writeln!(
+                writer,
+                "  {}: count={}, depth={}..={}",
+                name, tally.count, tally.min_depth, tally.max_depth
+            )?;
+        }
+    }
+
+    if !stats.symbol_tally.is_empty() {
+        let mut symbols: Vec<_> = stats.symbol_tally.iter().collect();
+        symbols.sort_by(|(a_text, a_count), (b_text, b_count)| {
+            b_count.cmp(a_count).then_with(|| a_text.cmp(b_text))
+        });
+        symbols.truncate(top);
+        let max_count = symbols.iter().map(|(_, &count)| count).max().unwrap_or(1);
+
+        writeln!(writer, "Top {} symbol tokens:", symbols.len())?;
+        for (text, &count) in symbols {
+            // Scale each bar to at most 40 characters wide, relative to the most frequent token.
+            let bar_len = (40 * count / max_count.max(1)).max(1);
+            writeln!(
+                writer,
+                "  {:>20} {:<6} {}",
+                text,
+                count,
+                "#".repeat(bar_len as usize)
+            )?;
         }
     }
 
     Ok(())
 }
 
+/// Serializes `stats` as a single `stats::{...}` struct so the report composes with the rest of
+/// the pipeline, e.g. `ion stats -f ion data.ion | ion jq '.max_depth'`.
+fn write_ion_report(
+    stats: &StreamStats,
+    top: usize,
+    mut writer: impl std::io::Write,
+) -> Result<()> {
+    let data = StatsReport::from_stats(stats, top);
+
+    let report = Struct::builder()
+        .with_field("samples", Element::from(data.samples as i64))
+        .with_field("min", Element::from(data.min))
+        .with_field("max", Element::from(data.max))
+        .with_field("mean", Element::from(data.mean))
+        .with_field("p50", Element::from(data.p50))
+        .with_field("p90", Element::from(data.p90))
+        .with_field("p99", Element::from(data.p99))
+        .with_field("symbols", Element::from(data.symbols as i64))
+        .with_field(
+            "local_symbol_tables",
+            Element::from(data.local_symbol_tables),
+        )
+        .with_field("max_depth", Element::from(data.max_depth as i64))
+        .with_field("unparseable", Element::from(data.unparseable as i64))
+        .with_field(
+            "size_buckets",
+            Element::from(List::from_iter(
+                data.size_buckets.into_iter().map(Element::from),
+            )),
+        )
+        .with_field(
+            "by_type",
+            Element::from(
+                Struct::builder()
+                    .with_fields(data.by_type.into_iter().map(|(name, type_report)| {
+                        (
+                            name,
+                            Element::from(
+                                Struct::builder()
+                                    .with_field("count", Element::from(type_report.count as i64))
+                                    .with_field("min", Element::from(type_report.min))
+                                    .with_field("max", Element::from(type_report.max))
+                                    .with_field("mean", Element::from(type_report.mean))
+                                    .build(),
+                            ),
+                        )
+                    }))
+                    .build(),
+            ),
+        )
+        .with_field(
+            "by_field",
+            Element::from(
+                Struct::builder()
+                    .with_fields(data.by_field.into_iter().map(|(name, tally)| {
+                        (
+                            name,
+                            Element::from(
+                                Struct::builder()
+                                    .with_field("count", Element::from(tally.count as i64))
+                                    .with_field("min_depth", Element::from(tally.min_depth as i64))
+                                    .with_field("max_depth", Element::from(tally.max_depth as i64))
+                                    .build(),
+                            ),
+                        )
+                    }))
+                    .build(),
+            ),
+        )
+        .with_field(
+            "top_symbols",
+            Element::from(List::from_iter(data.top_symbols.into_iter().map(
+                |symbol_frequency| {
+                    Element::from(
+                        Struct::builder()
+                            .with_field("text", Element::from(symbol_frequency.text))
+                            .with_field("count", Element::from(symbol_frequency.count as i64))
+                            .build(),
+                    )
+                },
+            ))),
+        )
+        .build();
+    let report = Element::from(report).with_annotations(["stats"]);
+
+    let mut ion_writer = Writer::new(v1_0::Text, &mut writer)?;
+    ion_writer.write_element(&report)?;
+    ion_writer.flush()?;
+    Ok(())
+}
+
+/// Returns `(min, max, mean)` for `sizes`, or all zeros if the stream had no top-level values.
+fn size_summary(sizes: &[f64]) -> (f64, f64, f64) {
+    if sizes.is_empty() {
+        return (0.0, 0.0, 0.0);
+    }
+    let min = sizes.iter().cloned().fold(f64::INFINITY, f64::min);
+    let max = sizes.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+    let mean = sizes.iter().sum::<f64>() / sizes.len() as f64;
+    (min, max, mean)
+}
+
+/// Bins `sizes` into `intervals` equal-width buckets spanning `[min, max]`, returning the count of
+/// values that landed in each bucket.
+fn size_buckets(sizes: &[f64], intervals: usize) -> Vec<i64> {
+    if sizes.is_empty() {
+        return vec![0; intervals];
+    }
+    let (min, max, _) = size_summary(sizes);
+    let width = (max - min) / intervals as f64;
+    let mut buckets = vec![0i64; intervals];
+    for &size in sizes {
+        let bucket = if width <= 0.0 {
+            0
+        } else {
+            (((size - min) / width) as usize).min(intervals - 1)
+        };
+        buckets[bucket] += 1;
+    }
+    buckets
+}
+
 fn analyze_data_stream<Input: IonInput>(
     reader: &mut SystemReader<AnyEncoding, Input>,
 ) -> Result<StreamStats> {
@@ -103,6 +712,12 @@ fn analyze_data_stream<Input: IonInput>(
     let mut symtab_count = 0;
     let mut max_depth = 0;
     let mut unparseable_count = 0;
+    let mut p50 = P2Quantile::new(0.5);
+    let mut p90 = P2Quantile::new(0.9);
+    let mut p99 = P2Quantile::new(0.99);
+    let mut per_type: HashMap<IonType, TypeStats> = HashMap::new();
+    let mut field_tally: HashMap<String, FieldTally> = HashMap::new();
+    let mut symbol_tally: HashMap<String, u64> = HashMap::new();
 
     loop {
         let system_result = reader.next_item();
@@ -121,13 +736,27 @@ fn analyze_data_stream<Input: IonInput>(
                 VersionMarker(_) | EncodingDirective(_) => continue,
                 SymbolTable(_) => symtab_count += 1,
                 system_value @ Value(raw_value) => {
-                    let size = system_value
-                        .raw_stream_item()
-                        .map(|v| v.span().bytes().len())
-                        .unwrap_or(0); // 1.1 values may not have any physical representation
-                    size_vec.push(size as f64);
-                    let current_depth = top_level_max_depth(raw_value)?;
-                    max_depth = max(max_depth, current_depth);
+                    // 1.1 values may not have any physical representation (e.g. a macro
+                    // expansion); skip the size sample entirely rather than recording a
+                    // fabricated zero that would drag down min/mean.
+                    let top_level_size = system_value.raw_stream_item().map(|raw_item| {
+                        let size = raw_item.span().bytes().len() as f64;
+                        size_vec.push(size);
+                        p50.observe(size);
+                        p90.observe(size);
+                        p99.observe(size);
+                        size
+                    });
+                    visit_structure(
+                        raw_value,
+                        StructureVisitor {
+                            max_depth: &mut max_depth,
+                            per_type: &mut per_type,
+                            field_tally: &mut field_tally,
+                            symbol_tally: &mut symbol_tally,
+                            top_level_size,
+                        },
+                    )?;
                 }
                 // SystemStreamItem is non_exhaustive
                 unsupported => panic!("Unsupported system stream item: {unsupported:?}"),
@@ -151,37 +780,15 @@ fn analyze_data_stream<Input: IonInput>(
         symbols_count,
         max_depth,
         unparseable_count,
+        p50_size: p50.value(),
+        p90_size: p90.value(),
+        p99_size: p99.value(),
+        per_type,
+        field_tally,
+        symbol_tally,
     })
 }
 
-fn top_level_max_depth(value: LazyValue<AnyEncoding>) -> Result<usize> {
-    let mut max_depth = 0;
-    let mut stack = vec![(value, 0)];
-    while let Some((current_value, depth)) = stack.pop() {
-        max_depth = max(max_depth, depth);
-        use ValueRef::*;
-        match current_value.read()? {
-            Struct(s) => {
-                for field in s {
-                    stack.push((field?.value(), depth + 1));
-                }
-            }
-            List(s) => {
-                for element in s {
-                    stack.push((element?, depth + 1));
-                }
-            }
-            SExp(s) => {
-                for element in s {
-                    stack.push((element?, depth + 1));
-                }
-            }
-            _ => continue,
-        }
-    }
-    Ok(max_depth)
-}
-
 #[test]
 fn test_analyze() -> Result<()> {
     let expect_out = StreamStats {
@@ -191,6 +798,118 @@ fn test_analyze() -> Result<()> {
         symbols_count: 8,
         max_depth: 2,
         unparseable_count: 0,
+        // Fewer than 5 top-level values were seen, so the P² markers never finished seeding.
+        p50_size: 0.0,
+        p90_size: 0.0,
+        p99_size: 0.0,
+        // Only the 4 top-level structs carry a measured size; everything nested is sizeless.
+        per_type: HashMap::from([
+            (
+                IonType::Struct,
+                TypeStats {
+                    count: 4,
+                    sized_count: 4,
+                    min: 7.0,
+                    max: 16.0,
+                    sum: 41.0,
+                },
+            ),
+            (
+                IonType::Symbol,
+                TypeStats {
+                    count: 6, // bar, baz, bar, data, baz, struct
+                    sized_count: 0,
+                    min: 0.0,
+                    max: 0.0,
+                    sum: 0.0,
+                },
+            ),
+            (
+                IonType::List,
+                TypeStats {
+                    count: 2, // the two `abc` lists
+                    sized_count: 0,
+                    min: 0.0,
+                    max: 0.0,
+                    sum: 0.0,
+                },
+            ),
+            (
+                IonType::Int,
+                TypeStats {
+                    count: 2, // 123, 456
+                    sized_count: 0,
+                    min: 0.0,
+                    max: 0.0,
+                    sum: 0.0,
+                },
+            ),
+            (
+                IonType::Decimal,
+                TypeStats {
+                    count: 1, // 42.0
+                    sized_count: 0,
+                    min: 0.0,
+                    max: 0.0,
+                    sum: 0.0,
+                },
+            ),
+            (
+                IonType::Float,
+                TypeStats {
+                    count: 1, // 43e0
+                    sized_count: 0,
+                    min: 0.0,
+                    max: 0.0,
+                    sum: 0.0,
+                },
+            ),
+        ]),
+        field_tally: HashMap::from([
+            (
+                "foo".to_string(),
+                FieldTally {
+                    count: 4,
+                    min_depth: 1,
+                    max_depth: 1,
+                },
+            ),
+            (
+                "abc".to_string(),
+                FieldTally {
+                    count: 2,
+                    min_depth: 1,
+                    max_depth: 1,
+                },
+            ),
+            (
+                "test".to_string(),
+                FieldTally {
+                    count: 1,
+                    min_depth: 1,
+                    max_depth: 1,
+                },
+            ),
+            (
+                "type".to_string(),
+                FieldTally {
+                    count: 1,
+                    min_depth: 1,
+                    max_depth: 1,
+                },
+            ),
+        ]),
+        // Field names (foo, abc, test, type) plus symbol values (bar, baz, data, struct).
+        symbol_tally: HashMap::from([
+            ("foo".to_string(), 4),
+            ("abc".to_string(), 2),
+            ("test".to_string(), 1),
+            ("type".to_string(), 1),
+            ("bar".to_string(), 2),
+            ("baz".to_string(), 2),
+            ("data".to_string(), 1),
+            ("struct".to_string(), 1),
+        ]),
     };
     let test_data: &str = r#"
     {
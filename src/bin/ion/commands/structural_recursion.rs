@@ -1,16 +1,40 @@
-use anyhow::Result;
-use ion_rs::{AnyEncoding, Element, IonType, LazyValue, ValueRef};
+use anyhow::{bail, Result};
+use ion_rs::{AnyEncoding, Element, IonData, IonType, LazyValue, ValueRef};
+
+/// What [`map_structure`] should do with the result of one [`ElementMapper::map`] call.
+pub enum MapAction {
+    /// Use this element in place of the one passed in, and keep descending into its children
+    /// (if it's still a container) as usual.
+    Replace(Element),
+    /// Use this element in place of the one passed in, but don't descend into its children even
+    /// if it's a container -- whatever shape `element` has is taken as final.
+    ReplaceAndSkipChildren(Element),
+    /// Stop the traversal immediately. Containers already partway through being rebuilt are
+    /// finished using whatever children were mapped before the stop, with `null` filling in for
+    /// the element that returned `Stop` and for children the traversal never reached, so
+    /// `map_structure` still returns a well-formed tree.
+    Stop,
+}
 
 /// Trait for operations that transform Ion elements,
 /// used for transformations like timestamp conversions
 pub trait ElementMapper {
-    fn map(&self, element: Element) -> Result<Element>;
+    fn map(&self, element: Element) -> Result<MapAction>;
 }
 
 /// Trait for operations that analyze Ion values without transformation,
 /// used for analysis like depth calculation that only need to examine values
 pub trait ValueVisitor<T> {
-    fn visit(&mut self, value: ValueRef<AnyEncoding>, depth: usize) -> Result<()>;
+    /// `field_name` is the struct field this value was reached under, or `None` if it's a
+    /// sequence element or the traversal root. `annotations` is the text of every annotation on
+    /// this value that resolved to text (unresolved annotations are silently skipped).
+    fn visit(
+        &mut self,
+        value: ValueRef<AnyEncoding>,
+        depth: usize,
+        field_name: Option<&str>,
+        annotations: &[String],
+    ) -> Result<()>;
     fn result(self) -> T;
 }
 
@@ -30,9 +54,8 @@ pub fn map_structure<M: ElementMapper>(root: Element, mapper: &M) -> Result<Elem
 
     while let Some(item) = stack.pop() {
         match item {
-            WorkItem::Process(element) => {
-                let mapped = mapper.map(element)?; // Applying mapper first
-                match mapped.ion_type() {
+            WorkItem::Process(element) => match mapper.map(element)? {
+                MapAction::Replace(mapped) => match mapped.ion_type() {
                     IonType::List => {
                         let list = mapped.as_sequence().unwrap(); // Mapper returned a list, now processing its children
                         let children: Vec<_> = list.elements().cloned().collect();
@@ -57,8 +80,40 @@ pub fn map_structure<M: ElementMapper>(root: Element, mapper: &M) -> Result<Elem
                     _ => {
                         results.push(mapped);
                     }
+                },
+                MapAction::ReplaceAndSkipChildren(mapped) => {
+                    // No BuildList/BuildStruct frame pushed: this container's children are never
+                    // visited, so there's nothing for such a frame to reconstruct later.
+                    results.push(mapped);
                 }
-            }
+                MapAction::Stop => {
+                    // Unwind the stack, finishing any containers already in progress using the
+                    // children mapped so far. Unprocessed `Process` frames are replaced with
+                    // `null` placeholders so each `BuildList`/`BuildStruct` frame still finds the
+                    // number of children it expects. The element that asked to stop was consumed
+                    // by `map`, so it gets a `null` placeholder as well.
+                    results.push(Element::null(IonType::Null));
+                    while let Some(item) = stack.pop() {
+                        match item {
+                            WorkItem::Process(_) => {
+                                results.push(Element::null(IonType::Null));
+                            }
+                            WorkItem::BuildList(size) => {
+                                let elements = results.split_off(results.len() - size);
+                                results.push(Element::from(ion_rs::List::from(elements)));
+                            }
+                            WorkItem::BuildStruct(field_names) => {
+                                let values = results.split_off(results.len() - field_names.len());
+                                let mut struct_builder = ion_rs::Struct::builder();
+                                for (name, value) in field_names.into_iter().zip(values) {
+                                    struct_builder = struct_builder.with_field(name, value);
+                                }
+                                results.push(Element::from(struct_builder.build()));
+                            }
+                        }
+                    }
+                }
+            },
             WorkItem::BuildList(size) => {
                 // Reconstructing the list from the last size processed results
                 let elements = results.split_off(results.len() - size);
@@ -88,29 +143,36 @@ pub fn visit_structure<V: ValueVisitor<T>, T>(
     root: LazyValue<AnyEncoding>,
     mut visitor: V,
 ) -> Result<T> {
-    let mut stack = vec![(root, 0)];
+    let mut stack = vec![(root, 0, None)];
 
-    while let Some((current_value, depth)) = stack.pop() {
+    while let Some((current_value, depth, field_name)) = stack.pop() {
         let value_ref = current_value.read()?;
-        visitor.visit(value_ref, depth)?;
+        let annotations: Vec<String> = current_value
+            .annotations()
+            .filter_map(|annotation| annotation.ok())
+            .filter_map(|annotation| annotation.text().map(|text| text.to_string()))
+            .collect();
+        visitor.visit(value_ref, depth, field_name.as_deref(), &annotations)?;
 
         // For container types, add children to the stack with incremented depth
         match value_ref {
             ValueRef::Struct(s) => {
                 for field in s {
-                    stack.push((field?.value(), depth + 1));
+                    let field = field?;
+                    let name = field.name()?.text().map(|text| text.to_string());
+                    stack.push((field.value(), depth + 1, name));
                 }
             }
             ValueRef::List(s) => {
                 // Add all list elements to stack
                 for element in s {
-                    stack.push((element?, depth + 1));
+                    stack.push((element?, depth + 1, None));
                 }
             }
             ValueRef::SExp(s) => {
                 // Add all s-expression elements to stack
                 for element in s {
-                    stack.push((element?, depth + 1));
+                    stack.push((element?, depth + 1, None));
                 }
             }
             _ => continue,
@@ -119,3 +181,603 @@ pub fn visit_structure<V: ValueVisitor<T>, T>(
 
     Ok(visitor.result())
 }
+
+/// A step in a [`Selector`] path, applied to each "focus" value produced by the previous step
+/// (the traversal root, for the first step).
+#[derive(Debug, Clone, PartialEq)]
+pub enum Step {
+    /// `.name` -- descends into the named field of a struct. If the focus isn't a struct, or has
+    /// no field with this name, this step silently contributes no matches. A struct may repeat a
+    /// field name; every occurrence is matched.
+    Field(String),
+    /// `[n]` -- selects the nth (0-based) element of a list or sexp. An out-of-range index, or a
+    /// focus that isn't a sequence, silently contributes no matches.
+    Index(usize),
+    /// `*` -- yields every immediate child of a struct, list, or sexp. A scalar focus silently
+    /// contributes no matches.
+    Wildcard,
+    /// `**` -- recursive descent: matches the focus itself and every value reachable from it at
+    /// any depth, trying the remainder of the selector at each one.
+    RecursiveDescent,
+    /// `[? <predicate>]` -- keeps the focus only if `predicate` holds for it.
+    Filter(Predicate),
+}
+
+/// A comparison operator usable inside a [`Predicate::Compare`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CompareOp {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+/// A boolean test evaluated against a focus value inside a `[? ...]` filter [`Step`].
+///
+/// Each atomic variant names a dotted field path, resolved relative to the focus (an empty path
+/// means the focus itself), descending through struct fields only. If the path doesn't resolve --
+/// a missing field, or a non-struct value partway through -- the atom is simply `false`, the same
+/// "wrong shape means no match" policy [`select`] uses for its own steps.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Predicate {
+    /// `<path> exists` -- true if `path` resolves to a value under the focus.
+    Exists(Vec<String>),
+    /// `<path> is <type>` -- true if `path` resolves to a value of the named Ion type.
+    IsType(Vec<String>, IonType),
+    /// `<path> <op> <scalar>` -- true if `path` resolves to a scalar that compares as `op`
+    /// against `scalar`.
+    Compare(Vec<String>, CompareOp, Element),
+    And(Box<Predicate>, Box<Predicate>),
+    Or(Box<Predicate>, Box<Predicate>),
+    Not(Box<Predicate>),
+}
+
+impl Predicate {
+    /// Evaluates this predicate against `focus`. Fails only if reading the data fails: a path that
+    /// doesn't resolve (wrong container type, missing field) simply makes the predicate `false`, the same policy
+    /// [`select`] uses when a step meets the wrong shape of value.
+    fn matches(&self, focus: ValueRef<AnyEncoding>) -> Result<bool> {
+        use Predicate::*;
+        Ok(match self {
+            Exists(path) => resolve_path(focus, path)?.is_some(),
+            IsType(path, expected) => {
+                resolve_path(focus, path)?.map(|value| value.ion_type()) == Some(*expected)
+            }
+            Compare(path, op, literal) => match resolve_path(focus, path)? {
+                Some(value_ref) => match Element::try_from(value_ref) {
+                    Ok(value) => compare(&value, *op, literal),
+                    Err(_) => false,
+                },
+                None => false,
+            },
+            And(lhs, rhs) => lhs.matches(focus)? && rhs.matches(focus)?,
+            Or(lhs, rhs) => lhs.matches(focus)? || rhs.matches(focus)?,
+            Not(inner) => !inner.matches(focus)?,
+        })
+    }
+}
+
+/// Resolves `path` (a sequence of field names, descending one struct level per name) against
+/// `focus`. An empty path resolves to `focus` itself. Returns `None` -- rather than erroring --
+/// the moment a name is missing or the current value isn't a struct.
+fn resolve_path(
+    focus: ValueRef<AnyEncoding>,
+    path: &[String],
+) -> Result<Option<ValueRef<AnyEncoding>>> {
+    let mut current = focus;
+    for name in path {
+        let ValueRef::Struct(fields) = current else {
+            return Ok(None);
+        };
+        let mut found = None;
+        for field in fields {
+            let field = field?;
+            if field.name()?.text() == Some(name.as_str()) {
+                found = Some(field.value().read()?);
+                break;
+            }
+        }
+        match found {
+            Some(value_ref) => current = value_ref,
+            None => return Ok(None),
+        }
+    }
+    Ok(Some(current))
+}
+
+/// Compares `value` against `literal` using Ion's data-model equivalence for `=`/`!=`, and the
+/// total order [`IonData`] defines (used elsewhere in this crate, e.g. `commands::jq`, to order
+/// Ion values) for the relational operators.
+fn compare(value: &Element, op: CompareOp, literal: &Element) -> bool {
+    use CompareOp::*;
+    match op {
+        Eq => value == literal,
+        Ne => value != literal,
+        Lt | Le | Gt | Ge => {
+            let ordering = IonData::from(value).cmp(&IonData::from(literal));
+            match op {
+                Lt => ordering.is_lt(),
+                Le => ordering.is_le(),
+                Gt => ordering.is_gt(),
+                Ge => ordering.is_ge(),
+                Eq | Ne => unreachable!(),
+            }
+        }
+    }
+}
+
+/// A sequence of [`Step`]s applied left-to-right to navigate Ion values -- a minimal,
+/// JSONPath-inspired selector/predicate query language. Parse one from text with
+/// [`Selector::parse`], then evaluate it against a value with [`select`].
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Selector {
+    steps: Vec<Step>,
+}
+
+impl Selector {
+    /// Parses a selector from its textual form, e.g. `.items[?(.price > 10)].name` or
+    /// `**.id`. See the grammar on `SelectorParser` for the full syntax.
+    pub fn parse(input: &str) -> Result<Selector> {
+        let mut parser = SelectorParser::new(input);
+        let steps = parser.parse_steps()?;
+        parser.expect_end()?;
+        Ok(Selector { steps })
+    }
+}
+
+/// Evaluates `selector` against `root`, returning every matching value as an [`Element`].
+///
+/// Like [`visit_structure`], this walks the tree iteratively with an explicit stack instead of
+/// recursing, so evaluation is streaming: a `**` step only reads as much of the tree as it needs
+/// in order to find matches, rather than materializing the whole subtree up front. A step applied
+/// to the wrong shape of value (an index against a struct, a field against a scalar, and so on)
+/// silently contributes no matches instead of failing the whole query, and a `.name` step against
+/// a struct with a repeated field name matches every occurrence.
+pub fn select(root: LazyValue<AnyEncoding>, selector: &Selector) -> Result<Vec<Element>> {
+    let mut stack = vec![(root, selector.steps.as_slice())];
+    let mut matches = Vec::new();
+
+    while let Some((value, steps)) = stack.pop() {
+        let value_ref = value.read()?;
+        match steps.split_first() {
+            None => matches.push(Element::try_from(value_ref)?),
+            Some((Step::Field(name), rest)) => {
+                if let ValueRef::Struct(fields) = value_ref {
+                    for field in fields {
+                        let field = field?;
+                        if field.name()?.text() == Some(name.as_str()) {
+                            stack.push((field.value(), rest));
+                        }
+                    }
+                }
+            }
+            Some((Step::Index(index), rest)) => match value_ref {
+                ValueRef::List(elements) => {
+                    for (i, element) in elements.into_iter().enumerate() {
+                        if i == *index {
+                            stack.push((element?, rest));
+                            break;
+                        }
+                    }
+                }
+                ValueRef::SExp(elements) => {
+                    for (i, element) in elements.into_iter().enumerate() {
+                        if i == *index {
+                            stack.push((element?, rest));
+                            break;
+                        }
+                    }
+                }
+                _ => {}
+            },
+            Some((Step::Wildcard, rest)) => match value_ref {
+                ValueRef::Struct(fields) => {
+                    for field in fields {
+                        stack.push((field?.value(), rest));
+                    }
+                }
+                ValueRef::List(elements) => {
+                    for element in elements {
+                        stack.push((element?, rest));
+                    }
+                }
+                ValueRef::SExp(elements) => {
+                    for element in elements {
+                        stack.push((element?, rest));
+                    }
+                }
+                _ => {}
+            },
+            Some((Step::RecursiveDescent, rest)) => {
+                // Keep descending, re-trying the whole `**` step (including this node's siblings
+                // at every deeper level) against each child...
+                match value_ref {
+                    ValueRef::Struct(fields) => {
+                        for field in fields {
+                            stack.push((field?.value(), steps));
+                        }
+                    }
+                    ValueRef::List(elements) => {
+                        for element in elements {
+                            stack.push((element?, steps));
+                        }
+                    }
+                    ValueRef::SExp(elements) => {
+                        for element in elements {
+                            stack.push((element?, steps));
+                        }
+                    }
+                    _ => {}
+                }
+                // ...and also try the remainder of the selector right here, since recursive
+                // descent matches at any depth, including zero.
+                stack.push((value, rest));
+            }
+            Some((Step::Filter(predicate), rest)) => {
+                if predicate.matches(value_ref)? {
+                    stack.push((value, rest));
+                }
+            }
+        }
+    }
+
+    Ok(matches)
+}
+
+/// A small hand-rolled recursive-descent parser for [`Selector`]'s textual grammar:
+///
+/// ```text
+/// selector   := step*
+/// step       := '.' ('*' | identifier) | '**' | '[' (index | '?' predicate) ']'
+/// predicate  := and_expr ('|' and_expr)*
+/// and_expr   := unary ('&' unary)*
+/// unary      := '!' unary | '(' predicate ')' | atom
+/// atom       := path ('exists' | 'is' identifier | cmp_op scalar)
+/// path       := ('.' identifier)*
+/// cmp_op     := '=' | '!=' | '<' | '<=' | '>' | '>='
+/// scalar     := integer | float | '"' ... '"' | 'true' | 'false' | 'null'
+/// ```
+///
+/// There's no support for quoting a field name that isn't a valid identifier; this is a
+/// deliberate first-cut scope limit, not an oversight.
+struct SelectorParser<'a> {
+    input: &'a str,
+    chars: Vec<char>,
+    pos: usize,
+}
+
+impl<'a> SelectorParser<'a> {
+    fn new(input: &'a str) -> Self {
+        SelectorParser {
+            input,
+            chars: input.chars().collect(),
+            pos: 0,
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn peek_at(&self, offset: usize) -> Option<char> {
+        self.chars.get(self.pos + offset).copied()
+    }
+
+    fn skip_ws(&mut self) {
+        while matches!(self.peek(), Some(c) if c.is_whitespace()) {
+            self.pos += 1;
+        }
+    }
+
+    fn expect_char(&mut self, expected: char) -> Result<()> {
+        self.skip_ws();
+        match self.peek() {
+            Some(c) if c == expected => {
+                self.pos += 1;
+                Ok(())
+            }
+            other => bail!(
+                "expected '{expected}' at position {} in selector {:?}, found {:?}",
+                self.pos,
+                self.input,
+                other
+            ),
+        }
+    }
+
+    fn expect_end(&mut self) -> Result<()> {
+        self.skip_ws();
+        if self.pos < self.chars.len() {
+            bail!(
+                "unexpected trailing text at position {} in selector {:?}",
+                self.pos,
+                self.input
+            );
+        }
+        Ok(())
+    }
+
+    /// Consumes `keyword` if it appears next, respecting word boundaries so e.g. `issue` doesn't
+    /// match the `is` keyword. Returns whether it matched.
+    fn consume_keyword(&mut self, keyword: &str) -> bool {
+        self.skip_ws();
+        let keyword_len = keyword.chars().count();
+        if self.chars[self.pos..].starts_with(&keyword.chars().collect::<Vec<_>>()[..]) {
+            let boundary_ok = match self.peek_at(keyword_len) {
+                Some(c) => !c.is_ascii_alphanumeric() && c != '_',
+                None => true,
+            };
+            if boundary_ok {
+                self.pos += keyword_len;
+                return true;
+            }
+        }
+        false
+    }
+
+    fn parse_identifier(&mut self) -> Result<String> {
+        self.skip_ws();
+        let start = self.pos;
+        match self.peek() {
+            Some(c) if c.is_ascii_alphabetic() || c == '_' => self.pos += 1,
+            other => bail!(
+                "expected a field name at position {start} in selector {:?}, found {:?}",
+                self.input,
+                other
+            ),
+        }
+        while matches!(self.peek(), Some(c) if c.is_ascii_alphanumeric() || c == '_') {
+            self.pos += 1;
+        }
+        Ok(self.chars[start..self.pos].iter().collect())
+    }
+
+    fn parse_usize(&mut self) -> Result<usize> {
+        self.skip_ws();
+        let start = self.pos;
+        while matches!(self.peek(), Some(c) if c.is_ascii_digit()) {
+            self.pos += 1;
+        }
+        if self.pos == start {
+            bail!(
+                "expected a non-negative integer index at position {start} in selector {:?}",
+                self.input
+            );
+        }
+        let text: String = self.chars[start..self.pos].iter().collect();
+        Ok(text.parse()?)
+    }
+
+    fn parse_steps(&mut self) -> Result<Vec<Step>> {
+        let mut steps = Vec::new();
+        loop {
+            self.skip_ws();
+            match self.peek() {
+                None => break,
+                Some('.') => {
+                    self.pos += 1;
+                    if self.peek() == Some('*') {
+                        self.pos += 1;
+                        steps.push(Step::Wildcard);
+                    } else {
+                        steps.push(Step::Field(self.parse_identifier()?));
+                    }
+                }
+                Some('*') if self.peek_at(1) == Some('*') => {
+                    self.pos += 2;
+                    steps.push(Step::RecursiveDescent);
+                }
+                Some('[') => {
+                    self.pos += 1;
+                    self.skip_ws();
+                    if self.peek() == Some('?') {
+                        self.pos += 1;
+                        let predicate = self.parse_or()?;
+                        self.expect_char(']')?;
+                        steps.push(Step::Filter(predicate));
+                    } else {
+                        let index = self.parse_usize()?;
+                        self.expect_char(']')?;
+                        steps.push(Step::Index(index));
+                    }
+                }
+                Some(other) => bail!(
+                    "unexpected character '{other}' at position {} in selector {:?}",
+                    self.pos,
+                    self.input
+                ),
+            }
+        }
+        Ok(steps)
+    }
+
+    fn parse_or(&mut self) -> Result<Predicate> {
+        let mut lhs = self.parse_and()?;
+        loop {
+            self.skip_ws();
+            if self.peek() == Some('|') {
+                self.pos += 1;
+                let rhs = self.parse_and()?;
+                lhs = Predicate::Or(Box::new(lhs), Box::new(rhs));
+            } else {
+                break;
+            }
+        }
+        Ok(lhs)
+    }
+
+    fn parse_and(&mut self) -> Result<Predicate> {
+        let mut lhs = self.parse_unary()?;
+        loop {
+            self.skip_ws();
+            if self.peek() == Some('&') {
+                self.pos += 1;
+                let rhs = self.parse_unary()?;
+                lhs = Predicate::And(Box::new(lhs), Box::new(rhs));
+            } else {
+                break;
+            }
+        }
+        Ok(lhs)
+    }
+
+    fn parse_unary(&mut self) -> Result<Predicate> {
+        self.skip_ws();
+        match self.peek() {
+            Some('!') => {
+                self.pos += 1;
+                Ok(Predicate::Not(Box::new(self.parse_unary()?)))
+            }
+            Some('(') => {
+                self.pos += 1;
+                let inner = self.parse_or()?;
+                self.expect_char(')')?;
+                Ok(inner)
+            }
+            _ => self.parse_atom(),
+        }
+    }
+
+    fn parse_path(&mut self) -> Result<Vec<String>> {
+        let mut path = Vec::new();
+        while self.peek() == Some('.') {
+            self.pos += 1;
+            path.push(self.parse_identifier()?);
+        }
+        Ok(path)
+    }
+
+    fn parse_atom(&mut self) -> Result<Predicate> {
+        let path = self.parse_path()?;
+        if self.consume_keyword("exists") {
+            return Ok(Predicate::Exists(path));
+        }
+        if self.consume_keyword("is") {
+            let type_name = self.parse_identifier()?;
+            return Ok(Predicate::IsType(path, parse_ion_type_name(&type_name)?));
+        }
+        let op = self.parse_compare_op()?;
+        let scalar = self.parse_scalar()?;
+        Ok(Predicate::Compare(path, op, scalar))
+    }
+
+    fn parse_compare_op(&mut self) -> Result<CompareOp> {
+        self.skip_ws();
+        let op = match (self.peek(), self.peek_at(1)) {
+            (Some('!'), Some('=')) => {
+                self.pos += 2;
+                CompareOp::Ne
+            }
+            (Some('<'), Some('=')) => {
+                self.pos += 2;
+                CompareOp::Le
+            }
+            (Some('>'), Some('=')) => {
+                self.pos += 2;
+                CompareOp::Ge
+            }
+            (Some('='), _) => {
+                self.pos += 1;
+                CompareOp::Eq
+            }
+            (Some('<'), _) => {
+                self.pos += 1;
+                CompareOp::Lt
+            }
+            (Some('>'), _) => {
+                self.pos += 1;
+                CompareOp::Gt
+            }
+            other => bail!(
+                "expected a comparison operator (=, !=, <, <=, >, >=) at position {} in selector \
+                 {:?}, found {:?}",
+                self.pos,
+                self.input,
+                other
+            ),
+        };
+        Ok(op)
+    }
+
+    fn parse_scalar(&mut self) -> Result<Element> {
+        self.skip_ws();
+        if self.consume_keyword("true") {
+            return Ok(Element::from(true));
+        }
+        if self.consume_keyword("false") {
+            return Ok(Element::from(false));
+        }
+        if self.consume_keyword("null") {
+            return Ok(Element::null(IonType::Null));
+        }
+        match self.peek() {
+            Some('"') => {
+                self.pos += 1;
+                let start = self.pos;
+                while matches!(self.peek(), Some(c) if c != '"') {
+                    self.pos += 1;
+                }
+                if self.peek() != Some('"') {
+                    bail!("unterminated string literal in selector {:?}", self.input);
+                }
+                let text: String = self.chars[start..self.pos].iter().collect();
+                self.pos += 1;
+                Ok(Element::from(text))
+            }
+            Some(c) if c.is_ascii_digit() || c == '-' => {
+                let start = self.pos;
+                self.pos += 1;
+                while matches!(self.peek(), Some(c) if c.is_ascii_digit()) {
+                    self.pos += 1;
+                }
+                let mut is_float = false;
+                if self.peek() == Some('.')
+                    && matches!(self.peek_at(1), Some(c) if c.is_ascii_digit())
+                {
+                    is_float = true;
+                    self.pos += 1;
+                    while matches!(self.peek(), Some(c) if c.is_ascii_digit()) {
+                        self.pos += 1;
+                    }
+                }
+                let text: String = self.chars[start..self.pos].iter().collect();
+                if is_float {
+                    Ok(Element::from(text.parse::<f64>()?))
+                } else {
+                    Ok(Element::from(text.parse::<i64>()?))
+                }
+            }
+            other => bail!(
+                "expected a scalar literal (string, number, true, false, or null) at position {} \
+                 in selector {:?}, found {:?}",
+                self.pos,
+                self.input,
+                other
+            ),
+        }
+    }
+}
+
+fn parse_ion_type_name(name: &str) -> Result<IonType> {
+    Ok(match name {
+        "null" => IonType::Null,
+        "bool" => IonType::Bool,
+        "int" => IonType::Int,
+        "float" => IonType::Float,
+        "decimal" => IonType::Decimal,
+        "timestamp" => IonType::Timestamp,
+        "string" => IonType::String,
+        "symbol" => IonType::Symbol,
+        "blob" => IonType::Blob,
+        "clob" => IonType::Clob,
+        "list" => IonType::List,
+        "sexp" => IonType::SExp,
+        "struct" => IonType::Struct,
+        other => bail!(
+            "unrecognized type name '{other}'; expected one of null, bool, int, float, decimal, \
+             timestamp, string, symbol, blob, clob, list, sexp, struct"
+        ),
+    })
+}
@@ -1,15 +1,14 @@
 use anyhow::Result;
 use ion_rs::{Element, IonType};
 
-use super::structural_recursion::{map_structure, ElementMapper};
+use super::structural_recursion::{map_structure, ElementMapper, MapAction};
 
 struct TimestampConverter;
 
 impl ElementMapper for TimestampConverter {
-    fn map(&self, element: Element) -> Result<Element> {
-        Ok(element.as_text()
-            .and_then(as_timestamp)
-            .unwrap_or(element))
+    fn map(&self, element: Element) -> Result<MapAction> {
+        let mapped = element.as_text().and_then(as_timestamp).unwrap_or(element);
+        Ok(MapAction::Replace(mapped))
     }
 }
 
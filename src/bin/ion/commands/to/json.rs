@@ -0,0 +1,508 @@
+use anyhow::{bail, Context, Result};
+use clap::builder::PossibleValue;
+use clap::{value_parser, Arg, ArgMatches, Command, ValueEnum};
+use data_encoding::{BASE64, BASE64URL, HEXLOWER};
+use ion_rs::{AnyEncoding, IonResult, LazyValue, Reader, ValueRef};
+use serde_json::{Map, Number, Value as JsonValue};
+use std::io::Write;
+use std::str::FromStr;
+
+use crate::commands::typed_json::{escape_field_name, ANNOTATIONS_KEY, ENCODING_KEY, ION_TYPE_KEY, VALUE_KEY};
+use crate::commands::{CommandIo, IonCliCommand, WithIonCliArgument};
+
+/// How `ToJsonCommand` lays out the JSON values it writes.
+#[derive(Copy, Clone, PartialEq, Eq, Default, Debug)]
+enum FormatStyle {
+    /// One compact value per line -- `to json`'s original behavior.
+    #[default]
+    Lines,
+    /// Each value pretty-printed on its own, via `serde_json::to_string_pretty`.
+    Pretty,
+    /// Every top-level value wrapped in a single JSON array, making the output one valid JSON
+    /// document instead of a line-delimited stream.
+    Array,
+}
+
+impl ValueEnum for FormatStyle {
+    fn value_variants<'a>() -> &'a [Self] {
+        &[FormatStyle::Lines, FormatStyle::Pretty, FormatStyle::Array]
+    }
+
+    fn to_possible_value(&self) -> Option<PossibleValue> {
+        match self {
+            FormatStyle::Lines => Some("lines".into()),
+            FormatStyle::Pretty => Some("pretty".into()),
+            FormatStyle::Array => Some("array".into()),
+        }
+    }
+}
+
+/// How to handle Ion values that have no lossless JSON equivalent (non-finite floats, decimals,
+/// timestamps, blobs/clobs, and symbols with unknown text).
+#[derive(Copy, Clone, PartialEq, Eq, Default, Debug)]
+pub(crate) enum LossyConversionPolicy {
+    /// Down-convert using the same behavior `to json` has always had: coerce to the closest
+    /// native JSON type, discarding whatever information that type can't hold.
+    #[default]
+    Default,
+    /// Refuse to emit a value that would lose information instead of silently down-converting it.
+    Strict,
+    /// Wrap a lossy value in `{"$ionType": "<type>", "value": <down-converted value>}` so the
+    /// original Ion type is still recoverable from the JSON output.
+    Annotate,
+}
+
+impl ValueEnum for LossyConversionPolicy {
+    fn value_variants<'a>() -> &'a [Self] {
+        &[
+            LossyConversionPolicy::Default,
+            LossyConversionPolicy::Strict,
+            LossyConversionPolicy::Annotate,
+        ]
+    }
+
+    fn to_possible_value(&self) -> Option<PossibleValue> {
+        match self {
+            LossyConversionPolicy::Default => Some("default".into()),
+            LossyConversionPolicy::Strict => Some("strict".into()),
+            LossyConversionPolicy::Annotate => Some("annotate".into()),
+        }
+    }
+}
+
+impl LossyConversionPolicy {
+    /// Applies this policy to a value of Ion type `ion_type` that was down-converted to `value`
+    /// because JSON has no lossless equivalent for it.
+    fn apply(&self, ion_type: &'static str, value: JsonValue) -> Result<JsonValue> {
+        match self {
+            LossyConversionPolicy::Default => Ok(value),
+            LossyConversionPolicy::Strict => bail!(
+                "refusing to down-convert a {ion_type} value to JSON; pass --lossy-policy=default \
+                or --lossy-policy=annotate to allow this"
+            ),
+            LossyConversionPolicy::Annotate => {
+                let mut map = Map::new();
+                map.insert(
+                    "$ionType".to_string(),
+                    JsonValue::String(ion_type.to_string()),
+                );
+                map.insert("value".to_string(), value);
+                Ok(JsonValue::Object(map))
+            }
+        }
+    }
+}
+
+/// Whether `ToJsonCommand` down-converts values that have no lossless JSON equivalent (per
+/// `LossyConversionPolicy`) or wraps them so the original Ion is recoverable.
+#[derive(Copy, Clone, PartialEq, Eq, Default, Debug)]
+enum ConversionMode {
+    /// The classic `to json` behavior: down-convert using `--lossy-policy`.
+    #[default]
+    Lossy,
+    /// Wrap every value Ion can express but JSON can't in a `{"$ionType": ..., "$value": ...}`
+    /// object so that `ion from json` can reconstruct it losslessly. See [`to_json_value_typed`].
+    Typed,
+}
+
+impl ValueEnum for ConversionMode {
+    fn value_variants<'a>() -> &'a [Self] {
+        &[ConversionMode::Lossy, ConversionMode::Typed]
+    }
+
+    fn to_possible_value(&self) -> Option<PossibleValue> {
+        match self {
+            ConversionMode::Lossy => Some("lossy".into()),
+            ConversionMode::Typed => Some("typed".into()),
+        }
+    }
+}
+
+/// How `to_json_value`/`to_json_value_typed` render the raw bytes of a `Blob` (and of a `Clob`,
+/// when `--clob-as-text` doesn't apply or the clob isn't valid UTF-8).
+#[derive(Copy, Clone, PartialEq, Eq, Default, Debug)]
+pub(crate) enum BinaryEncoding {
+    /// Standard base64, as `to json` has always emitted.
+    #[default]
+    Base64,
+    /// URL- and filename-safe base64 (`-`/`_` instead of `+`/`/`), per RFC 4648 ยง5.
+    Base64Url,
+    /// Lowercase hexadecimal.
+    Hex,
+}
+
+impl ValueEnum for BinaryEncoding {
+    fn value_variants<'a>() -> &'a [Self] {
+        &[
+            BinaryEncoding::Base64,
+            BinaryEncoding::Base64Url,
+            BinaryEncoding::Hex,
+        ]
+    }
+
+    fn to_possible_value(&self) -> Option<PossibleValue> {
+        match self {
+            BinaryEncoding::Base64 => Some("base64".into()),
+            BinaryEncoding::Base64Url => Some("base64url".into()),
+            BinaryEncoding::Hex => Some("hex".into()),
+        }
+    }
+}
+
+impl BinaryEncoding {
+    fn encode(&self, bytes: &[u8]) -> String {
+        match self {
+            BinaryEncoding::Base64 => BASE64.encode(bytes),
+            BinaryEncoding::Base64Url => BASE64URL.encode(bytes),
+            BinaryEncoding::Hex => HEXLOWER.encode(bytes),
+        }
+    }
+
+    /// The `$encoding` tag `to_json_value_typed` records for a wrapped blob/clob, so `ion from
+    /// json` knows which decoder to reverse the encoding with.
+    fn tag(&self) -> &'static str {
+        match self {
+            BinaryEncoding::Base64 => "base64",
+            BinaryEncoding::Base64Url => "base64url",
+            BinaryEncoding::Hex => "hex",
+        }
+    }
+}
+
+pub struct ToJsonCommand;
+
+impl IonCliCommand for ToJsonCommand {
+    fn name(&self) -> &'static str {
+        "json"
+    }
+
+    fn about(&self) -> &'static str {
+        "Converts Ion data to JSON."
+    }
+
+    fn unstable_features(&self) -> &'static [&'static str] {
+        &["to-json"] // TODO: Should this be stable?
+    }
+
+    fn is_porcelain(&self) -> bool {
+        false
+    }
+
+    fn configure_args(&self, command: Command) -> Command {
+        command
+            .with_input()
+            .with_output()
+            .arg(
+                Arg::new("mode")
+                    .long("mode")
+                    .default_value("lossy")
+                    .value_parser(value_parser!(ConversionMode))
+                    .help("Whether to down-convert or losslessly wrap values JSON can't natively represent.")
+                    .long_help(
+                        "`lossy` (the default) down-converts values that have no native JSON \
+                         equivalent per `--lossy-policy`. `typed` instead wraps each such value \
+                         (and any value carrying annotations) in a small object tagged with its \
+                         Ion type, so that `ion from json` can reconstruct the original losslessly; \
+                         `--lossy-policy` is ignored in this mode.",
+                    ),
+            )
+            .arg(
+                Arg::new("lossy-policy")
+                    .long("lossy-policy")
+                    .default_value("default")
+                    .value_parser(value_parser!(LossyConversionPolicy))
+                    .help(
+                        "How to handle Ion values with no lossless JSON equivalent (non-finite floats, \
+                        decimals, timestamps, blobs/clobs, and symbols with unknown text).",
+                    ),
+            )
+            .arg(
+                Arg::new("binary-encoding")
+                    .long("binary-encoding")
+                    .default_value("base64")
+                    .value_parser(value_parser!(BinaryEncoding))
+                    .help("How to render the raw bytes of a Blob (and of a Clob, unless --clob-as-text applies)."),
+            )
+            .arg(
+                Arg::new("clob-as-text")
+                    .long("clob-as-text")
+                    .num_args(0)
+                    .help(
+                        "Decode a Clob as a UTF-8 string instead of --binary-encoding, falling back \
+                         to --binary-encoding if the clob's bytes aren't valid UTF-8",
+                    ),
+            )
+            .arg(
+                Arg::new("format-style")
+                    .long("format-style")
+                    .default_value("lines")
+                    .value_parser(value_parser!(FormatStyle))
+                    .help("Layout of the JSON output.")
+                    .long_help(
+                        "`lines` (the default) writes one compact value per line. `pretty` \
+                         pretty-prints each value on its own. `array` wraps every top-level value \
+                         in a single JSON array, making the output one valid JSON document instead \
+                         of a line-delimited stream.",
+                    ),
+            )
+    }
+
+    fn run(&self, _command_path: &mut Vec<String>, args: &ArgMatches) -> Result<()> {
+        let mode = args
+            .get_one::<ConversionMode>("mode")
+            .copied()
+            .unwrap_or_default();
+        let lossy_policy = args
+            .get_one::<LossyConversionPolicy>("lossy-policy")
+            .copied()
+            .unwrap_or_default();
+        let binary_encoding = args
+            .get_one::<BinaryEncoding>("binary-encoding")
+            .copied()
+            .unwrap_or_default();
+        let clob_as_text = args.get_flag("clob-as-text");
+        let format_style = args
+            .get_one::<FormatStyle>("format-style")
+            .copied()
+            .unwrap_or_default();
+        CommandIo::new(args)?.for_each_input(|output, input| {
+            let input_name = input.name().to_owned();
+            let mut reader = Reader::new(AnyEncoding, input.into_source())
+                .with_context(|| format!("input file '{input_name}' was not valid Ion"))?;
+            const FLUSH_EVERY_N: usize = 100;
+            let mut value_count = 0usize;
+            let mut array_values = Vec::new();
+            while let Some(value) = reader.next()? {
+                let json = match mode {
+                    ConversionMode::Lossy => {
+                        to_json_value(value, lossy_policy, binary_encoding, clob_as_text)?
+                    }
+                    ConversionMode::Typed => {
+                        to_json_value_typed(value, binary_encoding, clob_as_text)?
+                    }
+                };
+                match format_style {
+                    FormatStyle::Lines => writeln!(output, "{json}")?,
+                    FormatStyle::Pretty => writeln!(output, "{}", serde_json::to_string_pretty(&json)?)?,
+                    FormatStyle::Array => array_values.push(json),
+                }
+                value_count += 1;
+                if value_count % FLUSH_EVERY_N == 0 {
+                    output.flush()?;
+                }
+            }
+            if format_style == FormatStyle::Array {
+                writeln!(
+                    output,
+                    "{}",
+                    serde_json::to_string_pretty(&JsonValue::Array(array_values))?
+                )?;
+            }
+            Ok(())
+        })
+    }
+}
+
+/// Renders a Clob's bytes as JSON per `--clob-as-text`/`--binary-encoding`: valid UTF-8 becomes a
+/// plain string when `clob_as_text` is set, otherwise (or on invalid UTF-8) falls back to
+/// `binary_encoding`.
+fn clob_value(bytes: &[u8], binary_encoding: BinaryEncoding, clob_as_text: bool) -> JsonValue {
+    if clob_as_text {
+        if let Ok(text) = std::str::from_utf8(bytes) {
+            return JsonValue::String(text.to_owned());
+        }
+    }
+    JsonValue::String(binary_encoding.encode(bytes))
+}
+
+/// Converts a single top-level Ion value into its JSON equivalent, down-converting the Ion-only
+/// types that have no direct JSON representation: timestamps and decimals become JSON numbers or
+/// strings as appropriate, symbols become strings (or `null` if they have no known text), blobs
+/// become strings encoded per `binary_encoding`, clobs likewise unless `clob_as_text` applies, and
+/// annotations are dropped since JSON has no equivalent concept. `lossy_policy` governs what
+/// happens at each of those down-conversions.
+pub(crate) fn to_json_value(
+    value: LazyValue<AnyEncoding>,
+    lossy_policy: LossyConversionPolicy,
+    binary_encoding: BinaryEncoding,
+    clob_as_text: bool,
+) -> Result<JsonValue> {
+    use ValueRef::*;
+    let value = match value.read()? {
+        Null(_) => JsonValue::Null,
+        Bool(b) => JsonValue::Bool(b),
+        Int(i) => JsonValue::Number(Number::from(i.expect_i128()?)),
+        Float(f) if f.is_finite() => JsonValue::Number(Number::from_f64(f).expect("f64 is finite")),
+        // Special floats like +inf, -inf, and NaN are written as `null` in
+        // accordance with Ion's JSON down-conversion guidelines; the other policies see the
+        // float's text so `annotate` keeps which special value it was.
+        Float(f) => match lossy_policy {
+            LossyConversionPolicy::Default => JsonValue::Null,
+            _ => lossy_policy.apply("float", JsonValue::String(f.to_string()))?,
+        },
+        Decimal(d) => {
+            let mut text = d.to_string().replace('d', "e");
+            if text.ends_with('.') {
+                // If there's a trailing "." with no digits of precision, discard it. JSON's
+                // `Number` type does not do anything with this information.
+                let _ = text.pop();
+            }
+            let number = Number::from_str(text.as_str())
+                .with_context(|| format!("{d} could not be turned into a Number"))?;
+            lossy_policy.apply("decimal", JsonValue::Number(number))?
+        }
+        Timestamp(t) => lossy_policy.apply("timestamp", JsonValue::String(t.to_string()))?,
+        Symbol(s) => match s.text() {
+            Some(text) => JsonValue::String(text.to_owned()),
+            None => lossy_policy.apply("symbol", JsonValue::Null)?,
+        },
+        String(s) => JsonValue::String(s.text().to_owned()),
+        Blob(b) => lossy_policy.apply("blob", JsonValue::String(binary_encoding.encode(b.as_slice())))?,
+        Clob(b) => lossy_policy.apply("clob", clob_value(b.as_slice(), binary_encoding, clob_as_text))?,
+        SExp(s) => to_json_array(s.iter(), lossy_policy, binary_encoding, clob_as_text)?,
+        List(l) => to_json_array(l.iter(), lossy_policy, binary_encoding, clob_as_text)?,
+        Struct(s) => {
+            let mut map = Map::new();
+            for field in s {
+                let field = field?;
+                let name = field.name()?.text().unwrap_or("$0").to_owned();
+                let value = to_json_value(field.value(), lossy_policy, binary_encoding, clob_as_text)?;
+                map.insert(name, value);
+            }
+            JsonValue::Object(map)
+        }
+    };
+    Ok(value)
+}
+
+fn to_json_array<'a>(
+    ion_values: impl IntoIterator<Item = IonResult<LazyValue<'a, AnyEncoding>>>,
+    lossy_policy: LossyConversionPolicy,
+    binary_encoding: BinaryEncoding,
+    clob_as_text: bool,
+) -> Result<JsonValue> {
+    let result: Result<Vec<JsonValue>> = ion_values
+        .into_iter()
+        .map(|v| to_json_value(v?, lossy_policy, binary_encoding, clob_as_text))
+        .collect();
+    Ok(JsonValue::Array(result?))
+}
+
+/// `--mode typed`'s converter: like [`to_json_value`], but instead of down-converting a value with
+/// no native JSON equivalent, wraps it as `{"$ionType": "<type>", "$value": <down-converted>}` (see
+/// [`crate::commands::typed_json`]) so `ion from json` can reverse the conversion. A value is also
+/// wrapped -- using its actual Ion type as `$ionType` -- if it carries annotations, since those
+/// would otherwise be silently dropped; an un-annotated, natively-representable value (null, bool,
+/// int, finite float, string, list, struct) is emitted bare, just as in lossy mode. `clob_as_text`
+/// clobs that decode as UTF-8 aren't marked lossy, since the original bytes round-trip exactly.
+fn to_json_value_typed(
+    value: LazyValue<AnyEncoding>,
+    binary_encoding: BinaryEncoding,
+    clob_as_text: bool,
+) -> Result<JsonValue> {
+    use ValueRef::*;
+    let annotations: Vec<String> = value
+        .annotations()
+        .map(|a| Ok(a?.text().unwrap_or("$0").to_owned()))
+        .collect::<Result<_>>()?;
+    let (ion_type, json, encoding, lossy) = match value.read()? {
+        Null(_) => ("null", JsonValue::Null, None, false),
+        Bool(b) => ("bool", JsonValue::Bool(b), None, false),
+        Int(i) => ("int", JsonValue::Number(Number::from(i.expect_i128()?)), None, false),
+        Float(f) if f.is_finite() => (
+            "float",
+            JsonValue::Number(Number::from_f64(f).expect("f64 is finite")),
+            None,
+            false,
+        ),
+        Float(f) => ("float", JsonValue::String(f.to_string()), None, true),
+        Decimal(d) => ("decimal", JsonValue::String(d.to_string()), None, true),
+        Timestamp(t) => ("timestamp", JsonValue::String(t.to_string()), None, true),
+        Symbol(s) => (
+            "symbol",
+            s.text()
+                .map(|text| JsonValue::String(text.to_owned()))
+                .unwrap_or(JsonValue::Null),
+            None,
+            true,
+        ),
+        String(s) => ("string", JsonValue::String(s.text().to_owned()), None, false),
+        Blob(b) => (
+            "blob",
+            JsonValue::String(binary_encoding.encode(b.as_slice())),
+            Some(binary_encoding.tag()),
+            true,
+        ),
+        Clob(b) => {
+            if clob_as_text {
+                if let Ok(text) = std::str::from_utf8(b.as_slice()) {
+                    ("clob", JsonValue::String(text.to_owned()), Some("utf8"), true)
+                } else {
+                    (
+                        "clob",
+                        JsonValue::String(binary_encoding.encode(b.as_slice())),
+                        Some(binary_encoding.tag()),
+                        true,
+                    )
+                }
+            } else {
+                (
+                    "clob",
+                    JsonValue::String(binary_encoding.encode(b.as_slice())),
+                    Some(binary_encoding.tag()),
+                    true,
+                )
+            }
+        }
+        SExp(s) => (
+            "sexp",
+            to_json_array_typed(s.iter(), binary_encoding, clob_as_text)?,
+            None,
+            true,
+        ),
+        List(l) => (
+            "list",
+            to_json_array_typed(l.iter(), binary_encoding, clob_as_text)?,
+            None,
+            false,
+        ),
+        Struct(s) => {
+            let mut map = Map::new();
+            for field in s {
+                let field = field?;
+                let name = field.name()?.text().unwrap_or("$0").to_owned();
+                let value = to_json_value_typed(field.value(), binary_encoding, clob_as_text)?;
+                map.insert(escape_field_name(&name), value);
+            }
+            ("struct", JsonValue::Object(map), None, false)
+        }
+    };
+    if !lossy && annotations.is_empty() {
+        return Ok(json);
+    }
+    let mut map = Map::new();
+    map.insert(ION_TYPE_KEY.to_owned(), JsonValue::String(ion_type.to_owned()));
+    map.insert(VALUE_KEY.to_owned(), json);
+    if let Some(encoding) = encoding {
+        map.insert(ENCODING_KEY.to_owned(), JsonValue::String(encoding.to_owned()));
+    }
+    if !annotations.is_empty() {
+        map.insert(
+            ANNOTATIONS_KEY.to_owned(),
+            JsonValue::Array(annotations.into_iter().map(JsonValue::String).collect()),
+        );
+    }
+    Ok(JsonValue::Object(map))
+}
+
+fn to_json_array_typed<'a>(
+    ion_values: impl IntoIterator<Item = IonResult<LazyValue<'a, AnyEncoding>>>,
+    binary_encoding: BinaryEncoding,
+    clob_as_text: bool,
+) -> Result<JsonValue> {
+    let result: Result<Vec<JsonValue>> = ion_values
+        .into_iter()
+        .map(|v| to_json_value_typed(v?, binary_encoding, clob_as_text))
+        .collect();
+    Ok(JsonValue::Array(result?))
+}
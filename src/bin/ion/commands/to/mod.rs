@@ -1,9 +1,15 @@
 use crate::commands::command_namespace::IonCliNamespace;
 use crate::commands::IonCliCommand;
 
+use crate::commands::to::cbor::ToCborCommand;
 use crate::commands::to::json::ToJsonCommand;
+use crate::commands::to::msgpack::ToMsgpackCommand;
+use crate::commands::to::yaml::ToYamlCommand;
 
+pub mod cbor;
 pub mod json;
+pub mod msgpack;
+pub mod yaml;
 
 pub struct ToNamespace;
 
@@ -17,6 +23,11 @@ impl IonCliNamespace for ToNamespace {
     }
 
     fn subcommands(&self) -> Vec<Box<dyn IonCliCommand>> {
-        vec![Box::new(ToJsonCommand)]
+        vec![
+            Box::new(ToJsonCommand),
+            Box::new(ToCborCommand),
+            Box::new(ToMsgpackCommand),
+            Box::new(ToYamlCommand),
+        ]
     }
 }
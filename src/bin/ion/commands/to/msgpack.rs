@@ -0,0 +1,84 @@
+use anyhow::{Context, Result};
+use clap::{value_parser, Arg, ArgMatches, Command};
+use ion_rs::{AnyEncoding, Reader};
+use std::io::Write;
+
+use crate::commands::to::json::{to_json_value, BinaryEncoding, LossyConversionPolicy};
+use crate::commands::{CommandIo, IonCliCommand, WithIonCliArgument};
+
+pub struct ToMsgpackCommand;
+
+impl IonCliCommand for ToMsgpackCommand {
+    fn name(&self) -> &'static str {
+        "msgpack"
+    }
+
+    fn about(&self) -> &'static str {
+        "Converts Ion data to MessagePack."
+    }
+
+    fn unstable_features(&self) -> &'static [&'static str] {
+        &["to-msgpack"]
+    }
+
+    fn is_porcelain(&self) -> bool {
+        false
+    }
+
+    fn configure_args(&self, command: Command) -> Command {
+        command
+            .with_input()
+            .with_output()
+            .arg(
+                Arg::new("lossy-policy")
+                    .long("lossy-policy")
+                    .default_value("default")
+                    .value_parser(value_parser!(LossyConversionPolicy))
+                    .help(
+                        "How to handle Ion values with no lossless MessagePack equivalent \
+                        (non-finite floats, decimals, timestamps, blobs/clobs, and symbols with \
+                        unknown text).",
+                    ),
+            )
+            .arg(
+                Arg::new("binary-encoding")
+                    .long("binary-encoding")
+                    .default_value("base64")
+                    .value_parser(value_parser!(BinaryEncoding))
+                    .help("How to render the raw bytes of a Blob (and of a Clob, unless --clob-as-text applies)."),
+            )
+            .arg(
+                Arg::new("clob-as-text")
+                    .long("clob-as-text")
+                    .num_args(0)
+                    .help(
+                        "Decode a Clob as a UTF-8 string instead of --binary-encoding, falling back \
+                         to --binary-encoding if the clob's bytes aren't valid UTF-8",
+                    ),
+            )
+    }
+
+    fn run(&self, _command_path: &mut Vec<String>, args: &ArgMatches) -> Result<()> {
+        let lossy_policy = args
+            .get_one::<LossyConversionPolicy>("lossy-policy")
+            .copied()
+            .unwrap_or_default();
+        let binary_encoding = args
+            .get_one::<BinaryEncoding>("binary-encoding")
+            .copied()
+            .unwrap_or_default();
+        let clob_as_text = args.get_flag("clob-as-text");
+        CommandIo::new(args)?.for_each_input(|output, input| {
+            let input_name = input.name().to_owned();
+            let mut reader = Reader::new(AnyEncoding, input.into_source())
+                .with_context(|| format!("input file '{input_name}' was not valid Ion"))?;
+            while let Some(value) = reader.next()? {
+                let json = to_json_value(value, lossy_policy, binary_encoding, clob_as_text)?;
+                let bytes = rmp_serde::to_vec(&json)
+                    .with_context(|| "could not serialize value as MessagePack")?;
+                output.write_all(&bytes)?;
+            }
+            Ok(())
+        })
+    }
+}
@@ -0,0 +1,178 @@
+//! Shared constants and struct-field escaping for `to json --mode typed` / `from json`'s lossless
+//! JSON encoding: every Ion value with no native JSON equivalent is wrapped as
+//! `{"$ionType": "<type>", "$value": <down-converted value>}` (plus an optional `"$encoding"` for
+//! blobs/clobs and `"$annotations"` for annotated values), and a struct field that would otherwise
+//! collide with one of those sentinel keys is escaped by doubling its leading `$` on the way out
+//! and unescaped on the way back.
+
+use anyhow::{bail, Context, Result};
+use data_encoding::{BASE64, BASE64URL, HEXLOWER_PERMISSIVE};
+use ion_rs::{Blob, Clob, Element, IonType, List, SExp, Struct, Symbol};
+
+/// The object key holding a wrapped value's Ion type name.
+pub const ION_TYPE_KEY: &str = "$ionType";
+/// The object key holding a wrapped value's down-converted JSON form.
+pub const VALUE_KEY: &str = "$value";
+/// The object key naming how a wrapped blob/clob's `$value` string is encoded.
+pub const ENCODING_KEY: &str = "$encoding";
+/// The object key holding a wrapped value's annotations, if it has any.
+pub const ANNOTATIONS_KEY: &str = "$annotations";
+
+/// Doubles a leading `$` on a struct field name that would otherwise be indistinguishable from
+/// one of `typed_json`'s sentinel keys once round-tripped through JSON.
+pub fn escape_field_name(name: &str) -> String {
+    if name.starts_with("$ion") {
+        format!("${name}")
+    } else {
+        name.to_owned()
+    }
+}
+
+/// Reverses [`escape_field_name`]: a field name doubled on the way out is restored to its
+/// original, single-`$` form.
+pub fn unescape_field_name(name: &str) -> String {
+    match name.strip_prefix('$') {
+        Some(rest) if rest.starts_with("$ion") => rest.to_owned(),
+        _ => name.to_owned(),
+    }
+}
+
+/// Reverses `to json --mode typed`'s wrapping, recursively rebuilding the original Ion value (and
+/// reapplying any `$annotations`) from JSON produced by that mode. A struct that isn't one of
+/// these wrappers has its field names unescaped (see [`unescape_field_name`]) and its fields
+/// detypified in turn; a sequence has its elements detypified; anything else is returned as-is.
+/// This is applied unconditionally by `from json` -- a JSON document that happens to have an
+/// unrelated `$ionType` field with a value that isn't one of the recognized type tags is left
+/// alone rather than rejected, so only output that `to json --mode typed` could plausibly have
+/// produced is reinterpreted.
+pub fn detypify(element: Element) -> Result<Element> {
+    if let Some(s) = element.as_struct() {
+        if let Some(rebuilt) = as_typed_wrapper(s)? {
+            return Ok(rebuilt);
+        }
+        let mut builder = Struct::builder();
+        for (name, value) in s.fields() {
+            let name = unescape_field_name(name.text().unwrap_or_default());
+            builder = builder.with_field(name, detypify(value.clone())?);
+        }
+        return Ok(Element::from(builder.build()));
+    }
+    if let Some(sequence) = element.as_sequence() {
+        let mapped: Result<Vec<Element>> = sequence.elements().map(|e| detypify(e.clone())).collect();
+        return Ok(if element.ion_type() == IonType::SExp {
+            Element::from(SExp::from_iter(mapped?))
+        } else {
+            Element::from(List::from_iter(mapped?))
+        });
+    }
+    Ok(element)
+}
+
+/// If `s` has the shape `to json --mode typed` gives a wrapped value (a recognized `$ionType` tag
+/// plus a `$value`), rebuilds and returns the original Ion element; otherwise returns `None` so
+/// the caller treats `s` as an ordinary struct.
+fn as_typed_wrapper(s: &Struct) -> Result<Option<Element>> {
+    let Some(ion_type) = s
+        .fields()
+        .find(|(name, _)| name.text() == Some(ION_TYPE_KEY))
+        .and_then(|(_, value)| value.as_string().map(str::to_owned))
+    else {
+        return Ok(None);
+    };
+    if !matches!(
+        ion_type.as_str(),
+        "null" | "bool" | "int" | "float" | "decimal" | "timestamp" | "symbol" | "string" | "blob"
+            | "clob" | "sexp" | "list" | "struct"
+    ) {
+        // Not one of our sentinel tags -- some other struct that happens to have an `$ionType`
+        // field. Leave it as an ordinary struct rather than erroring out.
+        return Ok(None);
+    }
+    let Some(value) = s
+        .fields()
+        .find(|(name, _)| name.text() == Some(VALUE_KEY))
+        .map(|(_, value)| value.clone())
+    else {
+        return Ok(None);
+    };
+    let encoding = s
+        .fields()
+        .find(|(name, _)| name.text() == Some(ENCODING_KEY))
+        .and_then(|(_, value)| value.as_string().map(str::to_owned));
+    let annotations: Vec<String> = s
+        .fields()
+        .find(|(name, _)| name.text() == Some(ANNOTATIONS_KEY))
+        .and_then(|(_, value)| value.as_sequence().map(|seq| seq.elements().collect::<Vec<_>>()))
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|e| e.as_string().map(str::to_owned))
+        .collect();
+
+    let rebuilt = match ion_type.as_str() {
+        "null" | "bool" | "int" | "string" => value,
+        "float" => match value.as_string() {
+            Some(text) => Element::read_one(text.as_bytes())
+                .ok()
+                .with_context(|| format!("invalid $ionType:float $value {text:?}"))?,
+            None => value,
+        },
+        "decimal" | "timestamp" => {
+            let text = value
+                .as_string()
+                .context("$ionType wrapper's $value must be a string")?;
+            Element::read_one(text.as_bytes())
+                .ok()
+                .with_context(|| format!("invalid $ionType:{ion_type} $value {text:?}"))?
+        }
+        "symbol" => match value.as_string() {
+            Some(text) => Element::from(Symbol::from(text.to_owned())),
+            // The original symbol's ID isn't recoverable once it's round-tripped through JSON as
+            // `null`; the best we can do is produce a symbol with empty (rather than unknown)
+            // text.
+            None => Element::from(Symbol::from(String::new())),
+        },
+        "blob" | "clob" => {
+            let text = value
+                .as_string()
+                .context("$ionType wrapper's $value must be a string")?;
+            let bytes = match encoding.as_deref() {
+                // `--clob-as-text` leaves the clob's text undecoded -- it already is the bytes.
+                Some("utf8") => text.as_bytes().to_vec(),
+                other => decode_binary(other.unwrap_or("base64"), text)?,
+            };
+            if ion_type == "blob" {
+                Element::from(Blob::from(bytes))
+            } else {
+                Element::from(Clob::from(bytes))
+            }
+        }
+        "sexp" => {
+            let seq = value
+                .as_sequence()
+                .context("$ionType:sexp wrapper's $value must be an array")?;
+            let mapped: Result<Vec<Element>> =
+                seq.elements().map(|e| detypify(e.clone())).collect();
+            Element::from(SExp::from_iter(mapped?))
+        }
+        "list" | "struct" => detypify(value)?,
+        other => bail!("unreachable: already matched $ionType {other:?}"),
+    };
+    Ok(Some(rebuilt.with_annotations(annotations)))
+}
+
+fn decode_binary(encoding: &str, text: &str) -> Result<Vec<u8>> {
+    match encoding {
+        "base64" => BASE64
+            .decode(text.as_bytes())
+            .context("invalid base64 in $ionType wrapper's $value"),
+        "base64url" => BASE64URL
+            .decode(text.as_bytes())
+            .context("invalid base64url in $ionType wrapper's $value"),
+        "hex" => HEXLOWER_PERMISSIVE
+            .decode(text.as_bytes())
+            .context("invalid hex in $ionType wrapper's $value"),
+        other => {
+            bail!("unsupported $encoding {other:?}; expected \"base64\", \"base64url\", or \"hex\"")
+        }
+    }
+}
@@ -0,0 +1,252 @@
+use crate::commands::IonCliCommand;
+use crate::input::CompressionDetected;
+use crate::output_compression::OutputCompression;
+use anyhow::Result;
+use clap::{crate_version, Arg, ArgAction, ArgMatches, Command};
+use ion_rs::{v1_0, Element, List, Struct, Writer};
+use serde::Serialize;
+
+/// The `ion-rs` version this build was compiled against. `ion-rs` doesn't expose its own version
+/// as a constant the way `crate_version!` reads this crate's own `Cargo.toml`, so this is kept in
+/// sync by hand with the dependency version declared in `[dependencies]` (see also the `ion-rs`
+/// version pinned for generated code in `commands::generate::write_rust_build_manifest`).
+const ION_RS_VERSION: &str = "0.18";
+
+/// The `ion-schema` version this build was compiled against, kept in sync by hand the same way as
+/// [`ION_RS_VERSION`].
+const ION_SCHEMA_VERSION: &str = "0.15";
+
+/// Reports the Ion versions and feature set this build of `ion-cli` supports, so that scripts
+/// can feature-detect before invoking other subcommands instead of parsing `--version` output.
+pub struct VersionCommand;
+
+impl IonCliCommand for VersionCommand {
+    fn name(&self) -> &'static str {
+        "version"
+    }
+
+    fn about(&self) -> &'static str {
+        "Prints the Ion versions and capabilities supported by this build of ion-cli."
+    }
+
+    fn is_porcelain(&self) -> bool {
+        true
+    }
+
+    fn configure_args(&self, command: Command) -> Command {
+        command.arg(
+            Arg::new("format")
+                .long("format")
+                .value_parser(["text", "json", "ion"])
+                .default_value("text")
+                .action(ArgAction::Set)
+                .help("Output format for the version information."),
+        )
+    }
+
+    fn run(&self, _command_path: &mut Vec<String>, args: &ArgMatches) -> Result<()> {
+        let capabilities = Capabilities::current();
+        match args.get_one::<String>("format").map(String::as_str) {
+            Some("json") => println!("{}", serde_json::to_string_pretty(&capabilities)?),
+            Some("ion") => write_ion_report(&capabilities, std::io::stdout())?,
+            _ => {
+                println!("ion-cli {}", capabilities.cli_version);
+                println!("ion-rs {}", capabilities.ion_rs_version);
+                println!("ion-schema {}", capabilities.ion_schema_version);
+                println!(
+                    "supported Ion versions: {}",
+                    capabilities.ion_versions.join(", ")
+                );
+                println!("supported encodings: {}", capabilities.encodings.join(", "));
+                println!(
+                    "supported Ion Schema versions: {}",
+                    capabilities.ion_schema_versions.join(", ")
+                );
+                println!(
+                    "compression (read): {}",
+                    capabilities.compression.readable.join(", ")
+                );
+                println!(
+                    "compression (write): {}",
+                    capabilities.compression.writable.join(", ")
+                );
+                println!("features: {}", capabilities.features.join(", "));
+                println!("subcommands:");
+                for subcommand in &capabilities.subcommands {
+                    println!(
+                        "  {} (input: {}, output: {})",
+                        subcommand.name, subcommand.input_formats, subcommand.output_formats
+                    );
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Serializes `capabilities` as a single `capabilities::{...}` struct, so downstream tooling can
+/// gate features on what this particular build supports without parsing `--help`, the same way
+/// `stats`' `--format ion` report composes with the rest of the pipeline.
+fn write_ion_report(capabilities: &Capabilities, mut writer: impl std::io::Write) -> Result<()> {
+    let compression = Struct::builder()
+        .with_field(
+            "readable",
+            Element::from(List::from_iter(
+                capabilities
+                    .compression
+                    .readable
+                    .iter()
+                    .map(Element::string),
+            )),
+        )
+        .with_field(
+            "writable",
+            Element::from(List::from_iter(
+                capabilities
+                    .compression
+                    .writable
+                    .iter()
+                    .map(Element::string),
+            )),
+        )
+        .build();
+
+    let subcommands = capabilities.subcommands.iter().map(|subcommand| {
+        Element::from(
+            Struct::builder()
+                .with_field("name", Element::string(subcommand.name))
+                .with_field("input_formats", Element::string(subcommand.input_formats))
+                .with_field("output_formats", Element::string(subcommand.output_formats))
+                .build(),
+        )
+    });
+
+    let report = Struct::builder()
+        .with_field("cli_version", Element::string(capabilities.cli_version))
+        .with_field(
+            "ion_rs_version",
+            Element::string(capabilities.ion_rs_version),
+        )
+        .with_field(
+            "ion_schema_version",
+            Element::string(capabilities.ion_schema_version),
+        )
+        .with_field(
+            "ion_versions",
+            Element::from(List::from_iter(
+                capabilities.ion_versions.iter().map(Element::string),
+            )),
+        )
+        .with_field(
+            "encodings",
+            Element::from(List::from_iter(
+                capabilities.encodings.iter().map(Element::string),
+            )),
+        )
+        .with_field(
+            "ion_schema_versions",
+            Element::from(List::from_iter(
+                capabilities.ion_schema_versions.iter().map(Element::string),
+            )),
+        )
+        .with_field("compression", Element::from(compression))
+        .with_field(
+            "features",
+            Element::from(List::from_iter(
+                capabilities.features.iter().map(Element::string),
+            )),
+        )
+        .with_field("subcommands", Element::from(List::from_iter(subcommands)))
+        .build();
+    let report = Element::from(report).with_annotations(["capabilities"]);
+
+    let mut ion_writer = Writer::new(v1_0::Text, &mut writer)?;
+    ion_writer.write_element(&report)?;
+    ion_writer.flush()?;
+    Ok(())
+}
+
+/// A stable, machine-readable schema describing this build's Ion-version and subcommand support.
+#[derive(Debug, Serialize)]
+struct Capabilities {
+    cli_version: &'static str,
+    ion_rs_version: &'static str,
+    ion_schema_version: &'static str,
+    ion_versions: Vec<&'static str>,
+    encodings: Vec<&'static str>,
+    ion_schema_versions: Vec<&'static str>,
+    compression: CompressionCapability,
+    /// Cargo features compiled into this build that change the set of available subcommands,
+    /// e.g. `experimental-code-gen` gating `beta generate`.
+    features: Vec<&'static str>,
+    subcommands: Vec<SubcommandCapability>,
+}
+
+#[derive(Debug, Serialize)]
+struct CompressionCapability {
+    readable: Vec<&'static str>,
+    writable: Vec<&'static str>,
+}
+
+#[derive(Debug, Serialize)]
+struct SubcommandCapability {
+    name: &'static str,
+    input_formats: &'static str,
+    output_formats: &'static str,
+}
+
+impl Capabilities {
+    fn current() -> Self {
+        Capabilities {
+            cli_version: crate_version!(),
+            ion_rs_version: ION_RS_VERSION,
+            ion_schema_version: ION_SCHEMA_VERSION,
+            ion_versions: vec!["1.0", "1.1"],
+            encodings: vec!["text", "binary_1_0", "binary_1_1"],
+            ion_schema_versions: vec!["1.0", "2.0"],
+            compression: CompressionCapability {
+                readable: CompressionDetected::readable_codecs(),
+                writable: OutputCompression::writable_codecs(),
+            },
+            features: {
+                #[allow(unused_mut)]
+                let mut features = vec![];
+                #[cfg(feature = "experimental-code-gen")]
+                features.push("experimental-code-gen");
+                features
+            },
+            subcommands: vec![
+                SubcommandCapability {
+                    name: "cat",
+                    input_formats: "text, binary",
+                    output_formats: "text, binary",
+                },
+                SubcommandCapability {
+                    name: "head",
+                    input_formats: "text, binary",
+                    output_formats: "text, binary",
+                },
+                SubcommandCapability {
+                    name: "from",
+                    input_formats: "json",
+                    output_formats: "text, binary",
+                },
+                SubcommandCapability {
+                    name: "to",
+                    input_formats: "text, binary",
+                    output_formats: "json",
+                },
+                SubcommandCapability {
+                    name: "schema",
+                    input_formats: "text, binary",
+                    output_formats: "text",
+                },
+                SubcommandCapability {
+                    name: "symtab",
+                    input_formats: "text, binary",
+                    output_formats: "text, binary",
+                },
+            ],
+        }
+    }
+}
@@ -1,55 +1,85 @@
-use termcolor::{ColorSpec, WriteColor};
+use std::fs::File;
 use std::io;
 use std::io::{BufWriter, Write};
-use std::fs::File;
+use termcolor::{Ansi, ColorChoice, ColorSpec, NoColor, WriteColor};
 
-/// A buffered `io::Write` implementation that implements [`WriteColor`] by reporting that it does
-/// not support TTY escape sequences and treating all requests to change or reset the current color
-/// as no-ops.
+/// A buffered `io::Write` implementation that implements [`WriteColor`].
 //
-// When writing to a file instead of a TTY, we don't want to use `termcolor` escape sequences as
-// they would be stored as literal bytes rather than being interpreted. To achieve this, we need an
-// `io::Write` implementation that also implements `termcolor`'s `WriteColor` trait. `WriteColor`
-// allows the type to specify to whether it supports interpreting escape codes.
+// When writing to a file instead of a TTY, we normally don't want to use `termcolor` escape
+// sequences, since they'd be stored as literal bytes rather than being interpreted by anything.
+// To achieve this, we need an `io::Write` implementation that also implements `termcolor`'s
+// `WriteColor` trait. `WriteColor` allows the type to specify whether it supports interpreting
+// escape codes. However, a user who's piping output into `less -R` or a similar ANSI-aware pager
+// may explicitly want those escape codes preserved, so `FileWriter` can also be built to emit
+// them for real via `with_color_choice`.
 //
 // We cannot implement `WriteColor` for `BufWriter<File>` directly due to Rust's coherence rules. Our
 // crate must own the trait, the implementing type, or both. The `FileWriter` type defined below
 // is a simple wrapper around a `BufWriter<File>` that implements both `io::Write` and `termcolor`'s
 // `WriteColor` trait.
 pub struct FileWriter {
-    inner: BufWriter<File>,
+    inner: FileWriterInner,
+}
+
+enum FileWriterInner {
+    // Emits real ANSI escape sequences for color changes.
+    Ansi(Ansi<BufWriter<File>>),
+    // Silently discards all requests to change or reset the current color.
+    Plain(NoColor<BufWriter<File>>),
 }
 
 impl FileWriter {
+    /// Equivalent to `with_color_choice(file, ColorChoice::Never)`.
     pub fn new(file: File) -> Self {
-        Self { inner: BufWriter::new(file) }
+        Self::with_color_choice(file, ColorChoice::Never)
+    }
+
+    /// Creates a `FileWriter` that emits real ANSI escape sequences when `color_choice` is
+    /// `Always`/`AlwaysAnsi`, and otherwise discards color-change requests as before. `Auto`
+    /// behaves like `Never`: a file has no TTY for "auto" to detect in the first place.
+    pub fn with_color_choice(file: File, color_choice: ColorChoice) -> Self {
+        let buffered = BufWriter::new(file);
+        let inner = match color_choice {
+            ColorChoice::Always | ColorChoice::AlwaysAnsi => FileWriterInner::Ansi(Ansi::new(buffered)),
+            ColorChoice::Auto | ColorChoice::Never => FileWriterInner::Plain(NoColor::new(buffered)),
+        };
+        Self { inner }
     }
 }
 
-// Delegates all `io::Write` methods to the nested `BufWriter`.
+// Delegates all `io::Write` methods to the nested sink.
 impl Write for FileWriter {
     fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
-        self.inner.write(buf)
+        match &mut self.inner {
+            FileWriterInner::Ansi(w) => w.write(buf),
+            FileWriterInner::Plain(w) => w.write(buf),
+        }
     }
 
     fn flush(&mut self) -> io::Result<()> {
-        self.inner.flush()
+        match &mut self.inner {
+            FileWriterInner::Ansi(w) => w.flush(),
+            FileWriterInner::Plain(w) => w.flush(),
+        }
     }
 }
 
 impl WriteColor for FileWriter {
     fn supports_color(&self) -> bool {
-        // FileWriter is never used to write to a TTY, so it does not support escape codes.
-        false
+        matches!(self.inner, FileWriterInner::Ansi(_))
     }
 
-    fn set_color(&mut self, _spec: &ColorSpec) -> io::Result<()> {
-        // When asked to change the color spec, do nothing.
-        Ok(())
+    fn set_color(&mut self, spec: &ColorSpec) -> io::Result<()> {
+        match &mut self.inner {
+            FileWriterInner::Ansi(w) => w.set_color(spec),
+            FileWriterInner::Plain(w) => w.set_color(spec),
+        }
     }
 
     fn reset(&mut self) -> io::Result<()> {
-        // When asked to reset the color spec to the default settings, do nothing.
-        Ok(())
+        match &mut self.inner {
+            FileWriterInner::Ansi(w) => w.reset(),
+            FileWriterInner::Plain(w) => w.reset(),
+        }
     }
 }
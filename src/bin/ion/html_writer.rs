@@ -0,0 +1,141 @@
+use std::io;
+use std::io::Write;
+use termcolor::{Color, ColorSpec, WriteColor};
+
+/// A `WriteColor` sink that translates `termcolor` style changes into HTML `<span style="...">`
+/// markup instead of ANSI escape sequences, and HTML-escapes everything else it's asked to write.
+//
+// `inspect`'s `--format html` uses this to wrap whatever destination `--output`/STDOUT would
+// otherwise resolve to (see `CommandOutput::Html`), so the same colorized offset/length/binary/
+// text table it prints to a terminal can instead be saved as a self-contained HTML document and
+// viewed in a browser. Every `with_style`/`write_with_style` call (and `BytesFormatter`'s hex
+// emission, via the blanket `impl<W: WriteColor> BytesSink for W`) already goes through
+// `WriteColor`, so wrapping the destination is enough to cover the whole inspector without
+// touching any of its call sites.
+pub struct HtmlWriter<'a> {
+    inner: Box<dyn Write + 'a>,
+    span_open: bool,
+}
+
+impl<'a> HtmlWriter<'a> {
+    pub fn new(inner: impl Write + 'a) -> Self {
+        Self {
+            inner: Box::new(inner),
+            span_open: false,
+        }
+    }
+
+    fn close_span(&mut self) -> io::Result<()> {
+        if self.span_open {
+            self.inner.write_all(b"</span>")?;
+            self.span_open = false;
+        }
+        Ok(())
+    }
+}
+
+/// Escapes the characters that are meaningful in HTML text content; everything the inspector
+/// writes is plain ASCII (hex digits, column padding, rendered text Ion) so this never needs to
+/// handle anything fancier than `&`, `<`, and `>`.
+fn write_html_escaped(out: &mut dyn Write, bytes: &[u8]) -> io::Result<()> {
+    let mut start = 0;
+    for (index, &byte) in bytes.iter().enumerate() {
+        let escaped: &[u8] = match byte {
+            b'&' => b"&amp;",
+            b'<' => b"&lt;",
+            b'>' => b"&gt;",
+            _ => continue,
+        };
+        out.write_all(&bytes[start..index])?;
+        out.write_all(escaped)?;
+        start = index + 1;
+    }
+    out.write_all(&bytes[start..])
+}
+
+impl Write for HtmlWriter<'_> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        write_html_escaped(&mut self.inner, buf)?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+impl WriteColor for HtmlWriter<'_> {
+    fn supports_color(&self) -> bool {
+        true
+    }
+
+    fn set_color(&mut self, spec: &ColorSpec) -> io::Result<()> {
+        // A fresh `<span>` per `set_color` call (closing whichever one's already open) mirrors
+        // how a terminal handles back-to-back escape codes without an intervening reset, which
+        // `BytesFormatter::write_bytes_from_current_slice` relies on when styling adjacent runs.
+        self.close_span()?;
+        if spec.is_none() {
+            return Ok(());
+        }
+        write!(self.inner, r#"<span style="{}">"#, color_spec_to_css(spec))?;
+        self.span_open = true;
+        Ok(())
+    }
+
+    fn reset(&mut self) -> io::Result<()> {
+        self.close_span()
+    }
+}
+
+/// Renders the subset of `ColorSpec` that `inspect`'s styles actually use (foreground/background
+/// color, bold, underline, and "dimmed") as an inline CSS declaration list.
+fn color_spec_to_css(spec: &ColorSpec) -> String {
+    let mut declarations = Vec::new();
+    if let Some(fg) = spec.fg() {
+        declarations.push(format!("color:{}", color_to_css(fg)));
+    }
+    if let Some(bg) = spec.bg() {
+        declarations.push(format!("background-color:{}", color_to_css(bg)));
+    }
+    if spec.bold() {
+        declarations.push("font-weight:bold".to_owned());
+    }
+    if spec.underline() {
+        declarations.push("text-decoration:underline".to_owned());
+    }
+    if spec.dimmed() {
+        declarations.push("opacity:0.6".to_owned());
+    }
+    declarations.join(";")
+}
+
+fn color_to_css(color: &Color) -> String {
+    match *color {
+        Color::Black => "black".to_owned(),
+        Color::Red => "red".to_owned(),
+        Color::Green => "green".to_owned(),
+        Color::Yellow => "goldenrod".to_owned(),
+        Color::Blue => "blue".to_owned(),
+        Color::Magenta => "magenta".to_owned(),
+        Color::Cyan => "darkcyan".to_owned(),
+        Color::White => "white".to_owned(),
+        Color::Rgb(r, g, b) => format!("rgb({r},{g},{b})"),
+        // `Ansi256` isn't used by any style in this crate today, and there's no lossless way to
+        // turn an arbitrary 256-color palette index into CSS without shipping the whole palette;
+        // fall back to the surrounding text color rather than guessing.
+        _ => "inherit".to_owned(),
+    }
+}
+
+/// The boilerplate that opens a `--format html` document: a dark background matching the color
+/// choices above (most of `inspect`'s named styles assume a dark terminal), monospace body text,
+/// and a `<pre>` so the table's column alignment survives HTML whitespace collapsing.
+pub const HTML_DOCUMENT_HEADER: &str = concat!(
+    "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n",
+    "<title>ion inspect</title>\n",
+    "<style>body { background-color: #1e1e1e; color: #d4d4d4; } ",
+    "pre { font-family: monospace; white-space: pre; }</style>\n",
+    "</head>\n<body>\n<pre>\n",
+);
+
+pub const HTML_DOCUMENT_FOOTER: &str = "</pre>\n</body>\n</html>\n";
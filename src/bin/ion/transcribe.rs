@@ -1,7 +1,137 @@
-use anyhow::{bail, Result};
+use anyhow::{anyhow, Result};
 use ion_rs::*;
 use std::io::Write;
 
+/// A pluggable output syntax that [`write_n_as`] can target. The built-in targets below cover
+/// Ion text (1.0/1.1) and Ion binary (1.0/1.1), but additional perfect- or lossy-fidelity
+/// syntaxes (e.g. JSON, CBOR, or a length-prefixed self-describing encoding, each documenting its
+/// own lossy mappings the way [`crate::commands::to::json`] documents Ion-to-JSON down-conversion)
+/// can be added without touching [`write_n_as`]'s dispatch logic: implement this trait and list
+/// an instance in [`default_targets`].
+trait TranscodeTarget<I: IonInput> {
+    /// Returns `true` if this target is the one `encoding`/`format` select.
+    fn supports(&self, encoding: IonEncoding, format: Format) -> bool;
+
+    /// Writes up to `count` values from `reader` to `output` using `format`, applying `mapper` to
+    /// each element, and returns the number of values written. `format` is re-passed here (rather
+    /// than captured by the target) since text targets need the specific [`TextFormat`] (pretty,
+    /// compact, or lines) the user selected.
+    fn write_stream(
+        &self,
+        reader: &mut Reader<AnyEncoding, I>,
+        output: &mut dyn Write,
+        format: Format,
+        count: usize,
+        mapper: &dyn Fn(Element) -> Result<Element>,
+    ) -> Result<usize>;
+}
+
+#[allow(non_camel_case_types)]
+struct IonTextTarget_1_0;
+#[allow(non_camel_case_types)]
+struct IonTextTarget_1_1;
+#[allow(non_camel_case_types)]
+struct IonBinaryTarget_1_0;
+#[allow(non_camel_case_types)]
+struct IonBinaryTarget_1_1;
+
+impl<I: IonInput> TranscodeTarget<I> for IonTextTarget_1_0 {
+    fn supports(&self, encoding: IonEncoding, format: Format) -> bool {
+        matches!(
+            (encoding, format),
+            (IonEncoding::Text_1_0, Format::Text(_))
+        )
+    }
+
+    fn write_stream(
+        &self,
+        reader: &mut Reader<AnyEncoding, I>,
+        output: &mut dyn Write,
+        format: Format,
+        count: usize,
+        mapper: &dyn Fn(Element) -> Result<Element>,
+    ) -> Result<usize> {
+        let Format::Text(text_format) = format else {
+            unreachable!("checked by `supports`")
+        };
+        let mut writer = Writer::new(v1_0::Text.with_format(text_format), output)?;
+        transcribe_n(&mut writer, reader, count, mapper)
+    }
+}
+
+impl<I: IonInput> TranscodeTarget<I> for IonTextTarget_1_1 {
+    fn supports(&self, encoding: IonEncoding, format: Format) -> bool {
+        matches!(
+            (encoding, format),
+            (IonEncoding::Text_1_1, Format::Text(_))
+        )
+    }
+
+    fn write_stream(
+        &self,
+        reader: &mut Reader<AnyEncoding, I>,
+        output: &mut dyn Write,
+        format: Format,
+        count: usize,
+        mapper: &dyn Fn(Element) -> Result<Element>,
+    ) -> Result<usize> {
+        let Format::Text(text_format) = format else {
+            unreachable!("checked by `supports`")
+        };
+        let mut writer = Writer::new(v1_1::Text.with_format(text_format), output)?;
+        transcribe_n(&mut writer, reader, count, mapper)
+    }
+}
+
+impl<I: IonInput> TranscodeTarget<I> for IonBinaryTarget_1_0 {
+    fn supports(&self, encoding: IonEncoding, format: Format) -> bool {
+        matches!((encoding, format), (IonEncoding::Binary_1_0, Format::Binary))
+    }
+
+    fn write_stream(
+        &self,
+        reader: &mut Reader<AnyEncoding, I>,
+        output: &mut dyn Write,
+        _format: Format,
+        count: usize,
+        mapper: &dyn Fn(Element) -> Result<Element>,
+    ) -> Result<usize> {
+        let mut writer = Writer::new(v1_0::Binary, output)?;
+        transcribe_n(&mut writer, reader, count, mapper)
+    }
+}
+
+impl<I: IonInput> TranscodeTarget<I> for IonBinaryTarget_1_1 {
+    fn supports(&self, encoding: IonEncoding, format: Format) -> bool {
+        matches!((encoding, format), (IonEncoding::Binary_1_1, Format::Binary))
+    }
+
+    fn write_stream(
+        &self,
+        reader: &mut Reader<AnyEncoding, I>,
+        output: &mut dyn Write,
+        _format: Format,
+        count: usize,
+        mapper: &dyn Fn(Element) -> Result<Element>,
+    ) -> Result<usize> {
+        let mut writer = Writer::new(v1_1::Binary, output)?;
+        transcribe_n(&mut writer, reader, count, mapper)
+    }
+}
+
+/// The targets `write_n_as` searches, in order, for one whose [`TranscodeTarget::supports`]
+/// matches the requested `(encoding, format)`. Third-party targets aren't discoverable through
+/// this function (there's no dynamic plugin loading here), but a fork or a future `--format`
+/// value can extend this list without touching `write_n_as` itself.
+fn default_targets<I: IonInput>() -> Vec<Box<dyn TranscodeTarget<I>>> {
+    vec![
+        Box::new(IonTextTarget_1_0),
+        Box::new(IonTextTarget_1_1),
+        Box::new(IonBinaryTarget_1_0),
+        Box::new(IonBinaryTarget_1_1),
+    ]
+}
+
 /// Constructs the appropriate writer for the given format, then writes all values from the
 /// `Reader` to the new `Writer`, applying a mapping function to each element.
 pub(crate) fn write_all_as<I: IonInput, M: Fn(Element) -> Result<Element>>(
@@ -24,35 +154,20 @@ pub(crate) fn write_n_as<I: IonInput, M: Fn(Element) -> Result<Element>>(
     count: usize,
     mapper: M,
 ) -> Result<usize> {
-    let written = match (encoding, format) {
-        (IonEncoding::Text_1_0, Format::Text(text_format)) => {
-            let mut writer = Writer::new(v1_0::Text.with_format(text_format), output)?;
-            transcribe_n(&mut writer, reader, count, mapper)
-        }
-        (IonEncoding::Text_1_1, Format::Text(text_format)) => {
-            let mut writer = Writer::new(v1_1::Text.with_format(text_format), output)?;
-            transcribe_n(&mut writer, reader, count, mapper)
-        }
-        (IonEncoding::Binary_1_0, Format::Binary) => {
-            let mut writer = Writer::new(v1_0::Binary, output)?;
-            transcribe_n(&mut writer, reader, count, mapper)
-        }
-        (IonEncoding::Binary_1_1, Format::Binary) => {
-            let mut writer = Writer::new(v1_1::Binary, output)?;
-            transcribe_n(&mut writer, reader, count, mapper)
-        }
-        unrecognized => bail!("unsupported format '{:?}'", unrecognized),
-    }?;
-    Ok(written)
+    let target = default_targets::<I>()
+        .into_iter()
+        .find(|target| target.supports(encoding, format))
+        .ok_or_else(|| anyhow!("unsupported format '{:?}'", (encoding, format)))?;
+    target.write_stream(reader, output, format, count, &mapper)
 }
 
 /// Writes up to `count` values from the `Reader` to the provided `Writer`,
 /// applying a mapping function to each element.
-fn transcribe_n<M: Fn(Element) -> Result<Element>>(
+fn transcribe_n(
     writer: &mut Writer<impl Encoding, impl Write>,
     reader: &mut Reader<impl Decoder, impl IonInput>,
     count: usize,
-    mapper: M,
+    mapper: &dyn Fn(Element) -> Result<Element>,
 ) -> Result<usize> {
     const FLUSH_EVERY_N: usize = 100;
     let mut values_since_flush: usize = 0;
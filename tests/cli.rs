@@ -218,6 +218,80 @@ fn test_write_all_values(#[case] number: i32, #[case] expected_output: &str) ->
     Ok(())
 }
 
+#[rstest]
+#[case::decimal_plus_decimal("1.10 + 2.20", "3.30")]
+#[case::decimal_plus_int("1.5 + 2", "3.5")]
+#[case::decimal_times_decimal("2.5 * 2.00", "5.000")]
+/// Calls ion-cli jq with a filter exercising decimal arithmetic, and checks the result keeps
+/// Ion's exact decimal semantics (e.g. trailing zeros from the operands are preserved) rather than
+/// going through a lossy float round trip.
+fn test_jq_decimal_arithmetic(#[case] filter: &str, #[case] expected_ion: &str) -> Result<()> {
+    let mut cmd = Command::cargo_bin("ion")?;
+    cmd.args(["-Z", "jq", "jq", filter]);
+    cmd.write_stdin("null");
+    let command_assert = cmd.assert();
+    command_assert.success();
+    let output = command_assert.get_output();
+    let actual = Element::read_one(&output.stdout)?;
+    let expected = Element::read_one(expected_ion)?;
+    assert_eq!(expected, actual);
+    Ok(())
+}
+
+#[rstest]
+#[case::ion_report("ion-report")]
+#[case::json_report("json-report")]
+/// Calls ion-cli schema validate with each of the stable machine-readable report formats and
+/// checks that both a valid and an invalid top-level value are reported.
+fn test_schema_validate_report_formats(#[case] format: &str) -> Result<()> {
+    let mut cmd = Command::cargo_bin("ion")?;
+    cmd.args([
+        "schema",
+        "-Z",
+        "schema-validate",
+        "validate",
+        "-T",
+        "int",
+        "--format",
+        format,
+    ]);
+    cmd.write_stdin("1 foo");
+    let command_assert = cmd.assert();
+    command_assert.success();
+    let output = command_assert.get_output();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    match format {
+        "ion-report" => {
+            let records = Element::read_all(stdout.trim_end())?;
+            assert_eq!(records.len(), 2);
+            assert!(records[0]
+                .as_struct()
+                .unwrap()
+                .get("valid")
+                .unwrap()
+                .as_bool()
+                .unwrap());
+            assert!(!records[1]
+                .as_struct()
+                .unwrap()
+                .get("valid")
+                .unwrap()
+                .as_bool()
+                .unwrap());
+        }
+        "json-report" => {
+            let lines: Vec<&str> = stdout.trim_end().lines().collect();
+            assert_eq!(lines.len(), 2);
+            let first: serde_json::Value = serde_json::from_str(lines[0])?;
+            let second: serde_json::Value = serde_json::from_str(lines[1])?;
+            assert_eq!(first["valid"], serde_json::Value::Bool(true));
+            assert_eq!(second["valid"], serde_json::Value::Bool(false));
+        }
+        _ => unreachable!(),
+    }
+    Ok(())
+}
+
 mod code_gen_tests {
     use super::*;
     use std::fs;
@@ -264,7 +338,8 @@ mod code_gen_tests {
         input_schema_file.write_all(test_schema.as_bytes())?;
         input_schema_file.flush()?;
         cmd.args([
-            "-X",
+            "-Z",
+            "generate",
             "generate",
             "--schema",
             "test_schema.isl",
@@ -133,7 +133,8 @@ fn test_unsupported_schema_types_failures(#[case] test_schema: &str) -> Result<(
     input_schema_file.write_all(test_schema.as_bytes())?;
     input_schema_file.flush()?;
     cmd.args([
-        "-X",
+        "-Z",
+        "generate",
         "generate",
         "--schema",
         "test_schema.isl",